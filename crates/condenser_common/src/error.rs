@@ -0,0 +1,69 @@
+use hdi::prelude::*;
+
+/// Errors returned by the zomes of both the craving and the lobby DNA.
+///
+/// Guest errors and invalid validation results carry the JSON encoding of this enum,
+/// e.g. `{"code":"TooLong","field":"offer","max":300,"actual":412}`, so that clients
+/// can react to them programmatically and localize the messages.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "code")]
+pub enum CondenserError {
+    /// A text field exceeds the maximum number of characters.
+    TooLong { field: String, max: usize, actual: usize },
+    /// A text field is shorter than required, e.g. empty.
+    TooShort { field: String, min: usize, actual: usize },
+    /// A number lies outside of the allowed range.
+    OutOfRange { field: String, min: usize, max: usize, actual: usize },
+    /// Only the author of `what` is allowed to do this.
+    NotAuthor { what: String },
+    /// `what` may only exist once and exists already.
+    AlreadyExists { what: String },
+    /// `what` has been changed in the meantime, `latest` is the hash of its latest version.
+    Outdated { what: String, latest: String },
+    /// `what` could not be found.
+    NotFound { what: String },
+    /// Entries or links of this kind cannot be updated.
+    NotUpdatable { what: String },
+    /// Entries or links of this kind cannot be deleted.
+    NotDeletable { what: String },
+    /// A referenced action does not come with an entry.
+    MissingEntry,
+    /// A referenced action or entry is not of the expected type.
+    WrongType { expected: String },
+    /// A link tag does not contain what it should.
+    InvalidLinkTag { expected: String },
+    /// The DNA properties could not be deserialized.
+    InvalidDnaProperties { reason: String },
+    /// The membrane proof of a joining agent is missing or does not check out.
+    InvalidMembraneProof { reason: String },
+    /// An exported recipe can't be decoded, has an unsupported version or a wrong signature.
+    InvalidRecipeExport { reason: String },
+    /// The operation is not allowed in the current configuration.
+    NotAllowed { reason: String },
+    /// The operation is structurally invalid, e.g. an update whose original is not a create.
+    MalformedOp { reason: String },
+    /// Anything else that should not happen.
+    Unexpected { reason: String },
+}
+
+impl CondenserError {
+    pub fn encode(&self) -> String {
+        serde_json::to_string(self).unwrap_or_else(|err| format!("{{\"code\":\"Unexpected\",\"reason\":\"{}\"}}", err))
+    }
+
+    pub fn decode(message: &str) -> Option<CondenserError> {
+        serde_json::from_str(message).ok()
+    }
+}
+
+impl core::fmt::Display for CondenserError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.encode())
+    }
+}
+
+impl From<CondenserError> for WasmError {
+    fn from(err: CondenserError) -> Self {
+        wasm_error!(WasmErrorInner::Guest(err.encode()))
+    }
+}
@@ -0,0 +1,83 @@
+use hdi::prelude::*;
+use crate::CondenserError;
+
+/// Upper bound for the number of invitations in a proof, to keep validation cheap.
+pub const MAX_INVITATION_CHAIN_LENGTH: usize = 64;
+
+/// An invitation of `invitee` by `inviter` to join the network with the given DNA hash.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Invitation {
+    pub dna_hash: DnaHash,
+    pub inviter: AgentPubKey,
+    pub invitee: AgentPubKey,
+    pub created_at: Timestamp,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SignedInvitation {
+    pub invitation: Invitation,
+    /// Signature of the inviter over the invitation
+    pub signature: Signature,
+}
+
+/// Membrane proof of an invitation-only network: a chain of invitations leading from the
+/// creator of the network to the joining agent. The creator joins with an empty chain.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes, Default)]
+pub struct InvitationProof {
+    pub chain: Vec<SignedInvitation>,
+}
+
+impl InvitationProof {
+    pub fn from_membrane_proof(membrane_proof: &Option<MembraneProof>) -> Result<InvitationProof, CondenserError> {
+        match membrane_proof {
+            Some(membrane_proof) => InvitationProof::try_from(SerializedBytes::clone(membrane_proof))
+                .map_err(|err| CondenserError::InvalidMembraneProof { reason: err.to_string() }),
+            None => Ok(InvitationProof::default()),
+        }
+    }
+
+    pub fn to_membrane_proof(&self) -> ExternResult<MembraneProof> {
+        let bytes = SerializedBytes::try_from(self.clone()).map_err(|err| wasm_error!(err))?;
+        Ok(MembraneProof::new(bytes))
+    }
+
+    /// The agent this proof was issued for, None for the empty proof of the creator.
+    pub fn invitee(&self) -> Option<&AgentPubKey> {
+        self.chain.last().map(|signed| &signed.invitation.invitee)
+    }
+
+    /// Checks that the chain leads from `creator` to `agent` within the network `dna_hash`
+    /// and that every invitation is signed by its inviter. Returns the reason if it doesn't.
+    pub fn verify(
+        &self,
+        dna_hash: &DnaHash,
+        creator: &AgentPubKey,
+        agent: &AgentPubKey,
+    ) -> ExternResult<Option<CondenserError>> {
+        if self.chain.len() > MAX_INVITATION_CHAIN_LENGTH {
+            return Ok(Some(invalid("the invitation chain is too long")));
+        }
+        let mut expected_inviter = creator;
+        for signed in self.chain.iter() {
+            let invitation = &signed.invitation;
+            if &invitation.dna_hash != dna_hash {
+                return Ok(Some(invalid("an invitation is for a different network")));
+            }
+            if &invitation.inviter != expected_inviter {
+                return Ok(Some(invalid("an invitation was not issued by the previous invitee")));
+            }
+            if !verify_signature(invitation.inviter.clone(), signed.signature.clone(), invitation)? {
+                return Ok(Some(invalid("an invitation has an invalid signature")));
+            }
+            expected_inviter = &invitation.invitee;
+        }
+        if expected_inviter != agent {
+            return Ok(Some(invalid("the invitation chain does not end with the joining agent")));
+        }
+        Ok(None)
+    }
+}
+
+fn invalid(reason: &str) -> CondenserError {
+    CondenserError::InvalidMembraneProof { reason: String::from(reason) }
+}
@@ -0,0 +1,6 @@
+pub mod error;
+pub use error::*;
+pub mod invitation;
+pub use invitation::*;
+pub mod registration;
+pub use registration::*;
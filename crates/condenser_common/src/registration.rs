@@ -0,0 +1,17 @@
+use hdi::prelude::*;
+
+/// The deterministic stand-in for a DNA hash as a link base or target. A DNA hash
+/// is not linkable itself, so its core is wrapped into an ExternalHash.
+pub fn dna_hash_anchor(dna_hash: &DnaHash) -> AnyLinkableHash {
+    ExternalHash::from_raw_36(dna_hash.get_raw_36().to_vec()).into()
+}
+
+/// Record in a craving of a lobby that lists it, with what is needed to join the lobby.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, SerializedBytes)]
+pub struct LobbyRegistration {
+    pub lobby_dna_hash: DnaHash,
+    pub lobby_name: String,
+    pub network_seed: String,
+    /// The DNA properties of the lobby, e.g. its creator if it is invitation-only
+    pub properties: SerializedBytes,
+}
@@ -0,0 +1,181 @@
+use std::collections::HashSet;
+
+use hdk::prelude::*;
+use craving_integrity::*;
+use crate::{
+    signal_for_action, Signal, ALL_ANECDOTES_PATH, ALL_ASSOCIATIONS_PATH, ALL_OFFERS_PATH, ALL_REFLECTIONS_PATH,
+};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetActivityFeedInput {
+    /// Only return events that happened after this timestamp
+    pub since: Option<Timestamp>,
+    /// Maximum number of events to return, the most recent ones are kept
+    pub limit: Option<usize>,
+}
+
+/// An action of the feed, either fetched already or only known from the link pointing to it.
+enum FeedAction {
+    Fetched(SignedActionHashed),
+    Linked(Link),
+}
+impl FeedAction {
+    fn timestamp(&self) -> Timestamp {
+        match self {
+            FeedAction::Fetched(action) => action.hashed.content.timestamp(),
+            FeedAction::Linked(link) => link.timestamp,
+        }
+    }
+    fn action_hash(&self) -> Option<ActionHash> {
+        match self {
+            FeedAction::Fetched(action) => Some(action.hashed.hash.clone()),
+            FeedAction::Linked(link) => link.target.clone().into_action_hash(),
+        }
+    }
+}
+
+/// Gets a time-ordered (most recent first) list of everything that happened in this craving,
+/// i.e. creations, updates and deletions of all entry types, comments and resonance changes.
+/// The events have the same shape as the signals emitted in `post_commit`.
+/// Lookups are batched per collection, and updates are only fetched if they are
+/// within `since` and `limit`.
+#[hdk_extern]
+pub fn get_activity_feed(input: GetActivityFeedInput) -> ExternResult<Vec<Signal>> {
+    let mut activity: Vec<FeedAction> = Vec::new();
+    let mut resonated_entries: HashSet<EntryHash> = HashSet::new();
+
+    let collections = [
+        (ALL_ASSOCIATIONS_PATH, LinkTypes::AllAssociations, LinkTypes::AssociationUpdates),
+        (ALL_OFFERS_PATH, LinkTypes::AllOffers, LinkTypes::OfferUpdates),
+        (ALL_REFLECTIONS_PATH, LinkTypes::AllReflections, LinkTypes::ReflectionUpdates),
+        (ALL_ANECDOTES_PATH, LinkTypes::AllAnecdotes, LinkTypes::AnecdoteUpdates),
+    ];
+
+    for (path, all_link_type, updates_link_type) in collections {
+        let original_hashes: Vec<ActionHash> = get_links(Path::from(path).path_entry_hash()?, all_link_type, None)?
+            .into_iter()
+            .filter_map(|link| link.target.into_action_hash())
+            .collect();
+        let records = collect_entry_activity(original_hashes, updates_link_type, &mut activity)?;
+        let entry_hashes: Vec<EntryHash> = records
+            .iter()
+            .filter_map(|record| record.action().entry_hash().cloned())
+            .filter(|entry_hash| resonated_entries.insert(entry_hash.clone()))
+            .collect();
+        collect_resonance_activity(entry_hashes, &mut activity)?;
+        let comments = match all_link_type {
+            LinkTypes::AllReflections => Some((
+                LinkTypes::ReflectionToCommentOnReflections,
+                LinkTypes::CommentOnReflectionUpdates,
+            )),
+            LinkTypes::AllOffers => Some((
+                LinkTypes::OfferToCommentOnOffers,
+                LinkTypes::CommentOnOfferUpdates,
+            )),
+            _ => None,
+        };
+        if let Some((comments_link_type, comment_updates_link_type)) = comments {
+            let comment_hashes: Vec<ActionHash> = get_links_batch(
+                records.iter().map(|record| record.action_address().clone().into()).collect(),
+                comments_link_type,
+            )?
+            .into_iter()
+            .flatten()
+            .filter_map(|link| link.target.into_action_hash())
+            .collect();
+            collect_entry_activity(comment_hashes, comment_updates_link_type, &mut activity)?;
+        }
+    }
+
+    let mut seen: HashSet<ActionHash> = HashSet::new();
+    let mut activity: Vec<FeedAction> = activity
+        .into_iter()
+        .filter(|action| action.action_hash().is_some_and(|hash| seen.insert(hash)))
+        .filter(|action| input.since.is_none_or(|since| action.timestamp() > since))
+        .collect();
+    activity.sort_by_key(|action| std::cmp::Reverse(action.timestamp()));
+    if let Some(limit) = input.limit {
+        activity.truncate(limit);
+    }
+
+    let get_input: Vec<GetInput> = activity
+        .iter()
+        .filter_map(|action| match action {
+            FeedAction::Linked(_) => action.action_hash(),
+            FeedAction::Fetched(_) => None,
+        })
+        .map(|hash| GetInput::new(hash.into(), GetOptions::default()))
+        .collect();
+    let mut linked_records = HDK.with(|hdk| hdk.borrow().get(get_input))?.into_iter();
+    let mut events: Vec<Signal> = Vec::new();
+    for action in activity {
+        let action = match action {
+            FeedAction::Fetched(action) => action,
+            FeedAction::Linked(_) => match linked_records.next().flatten() {
+                Some(record) => record.signed_action().clone(),
+                None => continue,
+            },
+        };
+        // links are created right after the action they point to
+        if input.since.is_some_and(|since| action.hashed.content.timestamp() <= since) {
+            continue;
+        }
+        if let Some(event) = signal_for_action(action)? {
+            events.push(event);
+        }
+    }
+    Ok(events)
+}
+
+fn get_links_batch(bases: Vec<AnyLinkableHash>, link_type: LinkTypes) -> ExternResult<Vec<Vec<Link>>> {
+    let get_links_input: Vec<GetLinksInput> = bases
+        .into_iter()
+        .map(|base| Ok(GetLinksInput::new(base, link_type.try_into_filter()?, None)))
+        .collect::<ExternResult<_>>()?;
+    HDK.with(|hdk| hdk.borrow().get_links(get_links_input))
+}
+
+/// Collects the creations of the given original records together with all their deletes
+/// and the links to their updates. Returns the original records that could be found.
+fn collect_entry_activity(
+    original_hashes: Vec<ActionHash>,
+    updates_link_type: LinkTypes,
+    activity: &mut Vec<FeedAction>,
+) -> ExternResult<Vec<Record>> {
+    let get_input: Vec<GetInput> = original_hashes
+        .into_iter()
+        .map(|hash| GetInput::new(hash.into(), GetOptions::default()))
+        .collect();
+    let mut records: Vec<Record> = Vec::new();
+    for details in HDK.with(|hdk| hdk.borrow().get_details(get_input))?.into_iter().flatten() {
+        if let Details::Record(record_details) = details {
+            activity.push(FeedAction::Fetched(record_details.record.signed_action().clone()));
+            activity.extend(record_details.deletes.into_iter().map(FeedAction::Fetched));
+            records.push(record_details.record);
+        }
+    }
+    let update_links = get_links_batch(
+        records.iter().map(|record| record.action_address().clone().into()).collect(),
+        updates_link_type,
+    )?;
+    activity.extend(update_links.into_iter().flatten().map(FeedAction::Linked));
+    Ok(records)
+}
+
+/// Collects all resonators that have been added to or removed from the given entries.
+fn collect_resonance_activity(
+    entry_hashes: Vec<EntryHash>,
+    activity: &mut Vec<FeedAction>,
+) -> ExternResult<()> {
+    let get_links_input: Vec<GetLinksInput> = entry_hashes
+        .into_iter()
+        .map(|entry_hash| Ok(GetLinksInput::new(entry_hash.into(), LinkTypes::EntryToResonator.try_into_filter()?, None)))
+        .collect::<ExternResult<_>>()?;
+    for link_details in HDK.with(|hdk| hdk.borrow().get_link_details(get_links_input))? {
+        for (create_link, delete_links) in link_details.into_inner() {
+            activity.push(FeedAction::Fetched(create_link));
+            activity.extend(delete_links.into_iter().map(FeedAction::Fetched));
+        }
+    }
+    Ok(())
+}
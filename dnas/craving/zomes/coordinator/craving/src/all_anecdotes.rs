@@ -1,17 +1,16 @@
 use hdk::prelude::*;
 use craving_integrity::*;
+use crate::ALL_ANECDOTES_PATH;
 #[hdk_extern]
 pub fn get_all_anecdotes(_: ()) -> ExternResult<Vec<Record>> {
-    let path = Path::from("all_anecdotes");
+    let path = Path::from(ALL_ANECDOTES_PATH);
     let links = get_links(path.path_entry_hash()?, LinkTypes::AllAnecdotes, None)?;
     let get_input: Vec<GetInput> = links
         .into_iter()
-        .map(|link| GetInput::new(
-            link.target.into_any_dht_hash().unwrap(),
-            GetOptions::default(),
-        ))
+        .filter_map(|link| link.target.into_any_dht_hash())
+        .map(|hash| GetInput::new(hash, GetOptions::default()))
         .collect();
     let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
-    let records: Vec<Record> = records.into_iter().filter_map(|r| r).collect();
+    let records: Vec<Record> = records.into_iter().flatten().collect();
     Ok(records)
 }
@@ -2,19 +2,18 @@ use std::collections::HashMap;
 
 use hdk::prelude::*;
 use craving_integrity::*;
+use crate::ALL_ASSOCIATIONS_PATH;
 
 
 /// Getting all deduplicated associations.
 #[hdk_extern]
 pub fn get_all_associations(_: ()) -> ExternResult<Vec<Record>> {
-    let path = Path::from("all_associations");
+    let path = Path::from(ALL_ASSOCIATIONS_PATH);
     let links = get_links(path.path_entry_hash()?, LinkTypes::AllAssociations, None)?;
     let get_input: Vec<GetInput> = links
         .into_iter()
-        .map(|link| GetInput::new(
-            link.target.into_any_dht_hash().unwrap(),
-            GetOptions::default(),
-        ))
+        .filter_map(|link| link.target.into_any_dht_hash())
+        .map(|hash| GetInput::new(hash, GetOptions::default()))
         .collect();
     let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
 
@@ -24,24 +23,22 @@ pub fn get_all_associations(_: ()) -> ExternResult<Vec<Record>> {
     // first time.
     let mut sorted_and_deduped_records: HashMap<EntryHash, Record> = HashMap::new();
 
-    for r in records {
-        if let Some(record) = r {
-            let maybe_entry_hash = record.action().entry_hash();
-            if let Some(eh) = maybe_entry_hash {
-                let maybe_duplicate_record = sorted_and_deduped_records.get(eh);
-                match maybe_duplicate_record {
-                    Some(duplicate_record) => {
-                        // keep the one with the oldest/earliest timestamp
-                        match record.action().timestamp() > duplicate_record.action().timestamp() {
-                            true => (),
-                            false => {
-                                sorted_and_deduped_records.insert(eh.clone(), record);
-                            }
+    for record in records.into_iter().flatten() {
+        let maybe_entry_hash = record.action().entry_hash();
+        if let Some(eh) = maybe_entry_hash {
+            let maybe_duplicate_record = sorted_and_deduped_records.get(eh);
+            match maybe_duplicate_record {
+                Some(duplicate_record) => {
+                    // keep the one with the oldest/earliest timestamp
+                    match record.action().timestamp() > duplicate_record.action().timestamp() {
+                        true => (),
+                        false => {
+                            sorted_and_deduped_records.insert(eh.clone(), record);
                         }
-                    },
-                    None => {
-                        sorted_and_deduped_records.insert(eh.clone(), record);
                     }
+                },
+                None => {
+                    sorted_and_deduped_records.insert(eh.clone(), record);
                 }
             }
         }
@@ -56,17 +53,15 @@ pub fn get_all_associations(_: ()) -> ExternResult<Vec<Record>> {
 /// times, return all of them
 #[hdk_extern]
 pub fn get_all_association_actions(_: ()) -> ExternResult<Vec<Record>> {
-    let path = Path::from("all_associations");
+    let path = Path::from(ALL_ASSOCIATIONS_PATH);
     let links = get_links(path.path_entry_hash()?, LinkTypes::AllAssociations, None)?;
     let get_input: Vec<GetInput> = links
         .into_iter()
-        .map(|link| GetInput::new(
-            link.target.into_any_dht_hash().unwrap(),
-            GetOptions::default(),
-        ))
+        .filter_map(|link| link.target.into_any_dht_hash())
+        .map(|hash| GetInput::new(hash, GetOptions::default()))
         .collect();
     let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
-    let records: Vec<Record> = records.into_iter().filter_map(|r| r).collect();
+    let records: Vec<Record> = records.into_iter().flatten().collect();
     Ok(records)
 }
 
@@ -1,21 +1,25 @@
 use hdk::prelude::*;
+use condenser_common::CondenserError;
 use craving_integrity::*;
+use crate::ALL_ANECDOTES_PATH;
+use crate::contributions::link_contribution;
 #[hdk_extern]
 pub fn create_anecdote(anecdote: Anecdote) -> ExternResult<Record> {
     let anecdote_hash = create_entry(&EntryTypes::Anecdote(anecdote.clone()))?;
     let record = get(anecdote_hash.clone(), GetOptions::default())?
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Could not find the newly created Anecdote"))
+                WasmErrorInner::Guest(CondenserError::NotFound { what: String::from("Anecdote") }.encode())
             ),
         )?;
-    let path = Path::from("all_anecdotes");
+    let path = Path::from(ALL_ANECDOTES_PATH);
     create_link(
         path.path_entry_hash()?,
         anecdote_hash.clone(),
         LinkTypes::AllAnecdotes,
         (),
     )?;
+    link_contribution(anecdote_hash.clone(), ContributionKind::Anecdote)?;
     Ok(record)
 }
 #[hdk_extern]
@@ -55,7 +59,7 @@ pub fn update_anecdote(input: UpdateAnecdoteInput) -> ExternResult<Record> {
     let record = get(updated_anecdote_hash.clone(), GetOptions::default())?
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Could not find the newly updated Anecdote"))
+                WasmErrorInner::Guest(CondenserError::NotFound { what: String::from("Anecdote") }.encode())
             ),
         )?;
     Ok(record)
@@ -1,21 +1,25 @@
 use hdk::prelude::*;
+use condenser_common::CondenserError;
 use craving_integrity::*;
+use crate::ALL_ASSOCIATIONS_PATH;
+use crate::contributions::link_contribution;
 #[hdk_extern]
 pub fn create_association(association: Association) -> ExternResult<Record> {
     let association_hash = create_entry(&EntryTypes::Association(association.clone()))?;
     let record = get(association_hash.clone(), GetOptions::default())?
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Could not find the newly created Association"))
+                WasmErrorInner::Guest(CondenserError::NotFound { what: String::from("Association") }.encode())
             ),
         )?;
-    let path = Path::from("all_associations");
+    let path = Path::from(ALL_ASSOCIATIONS_PATH);
     create_link(
         path.path_entry_hash()?,
         association_hash.clone(),
         LinkTypes::AllAssociations,
         (),
     )?;
+    link_contribution(association_hash.clone(), ContributionKind::Association)?;
     Ok(record)
 }
 #[hdk_extern]
@@ -66,7 +70,7 @@ pub fn get_association_by_action_hash(
 //     let record = get(updated_association_hash.clone(), GetOptions::default())?
 //         .ok_or(
 //             wasm_error!(
-//                 WasmErrorInner::Guest(String::from("Could not find the newly updated Association"))
+//                 WasmErrorInner::Guest(CondenserError::NotFound { what: String::from("Association") }.encode())
 //             ),
 //         )?;
 //     Ok(record)
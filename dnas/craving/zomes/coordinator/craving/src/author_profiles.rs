@@ -0,0 +1,111 @@
+use std::collections::{BTreeMap, HashMap};
+
+use hdk::prelude::*;
+use crate::all_offers::get_all_offers;
+use crate::all_reflections::get_all_reflections;
+use crate::comment_on_offer::get_comment_on_offers_for_offer;
+use crate::comment_on_reflection::get_comment_on_reflections_for_reflection;
+use crate::registered_lobbies::known_lobby_cells;
+
+/// Mirror of the Profile entry of the profiles zome in the lobby DNA
+#[derive(Serialize, Deserialize, SerializedBytes, Debug, Clone)]
+struct LobbyProfile {
+    nickname: String,
+    fields: BTreeMap<String, String>,
+}
+
+/// What is shown next to a record instead of the raw AgentPubKey of its author
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AuthorProfile {
+    pub nickname: String,
+    pub avatar: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RecordWithAuthor {
+    pub record: Record,
+    /// None if the author has no profile in any of our lobby cells of this craving
+    pub author_profile: Option<AuthorProfile>,
+}
+
+/// Fetches profiles from the lobbies this craving is listed in, asking for every agent
+/// only once.
+struct AuthorProfileCache {
+    lobby_cells: Vec<CellId>,
+    profiles: HashMap<AgentPubKey, Option<AuthorProfile>>,
+}
+
+impl AuthorProfileCache {
+    fn new() -> ExternResult<Self> {
+        Ok(AuthorProfileCache { lobby_cells: known_lobby_cells()?, profiles: HashMap::new() })
+    }
+
+    fn get(&mut self, agent: &AgentPubKey) -> Option<AuthorProfile> {
+        let lobby_cells = &self.lobby_cells;
+        self.profiles
+            .entry(agent.clone())
+            .or_insert_with(|| {
+                lobby_cells
+                    .iter()
+                    .find_map(|lobby_cell| fetch_author_profile(lobby_cell.clone(), agent.clone()))
+            })
+            .clone()
+    }
+
+    fn with_authors(&mut self, records: Vec<Record>) -> Vec<RecordWithAuthor> {
+        records
+            .into_iter()
+            .map(|record| RecordWithAuthor {
+                author_profile: self.get(record.action().author()),
+                record,
+            })
+            .collect()
+    }
+}
+
+fn fetch_author_profile(lobby_cell: CellId, agent: AgentPubKey) -> Option<AuthorProfile> {
+    let response = call(
+        CallTargetCell::OtherCell(lobby_cell),
+        "profiles",
+        "get_agent_profile".into(),
+        None,
+        agent,
+    )
+    .ok()?;
+    let record = match response {
+        ZomeCallResponse::Ok(result) => result.decode::<Option<Record>>().ok()??,
+        _ => return None,
+    };
+    let profile: LobbyProfile = record.entry().to_app_option().ok()??;
+    Some(AuthorProfile {
+        avatar: profile.fields.get("avatar").cloned(),
+        nickname: profile.nickname,
+    })
+}
+
+#[hdk_extern]
+pub fn get_all_reflections_with_authors(_: ()) -> ExternResult<Vec<RecordWithAuthor>> {
+    Ok(AuthorProfileCache::new()?.with_authors(get_all_reflections(())?))
+}
+
+#[hdk_extern]
+pub fn get_all_offers_with_authors(_: ()) -> ExternResult<Vec<RecordWithAuthor>> {
+    Ok(AuthorProfileCache::new()?.with_authors(get_all_offers(())?))
+}
+
+#[hdk_extern]
+pub fn get_comment_on_offers_for_offer_with_authors(
+    offer_hash: ActionHash,
+) -> ExternResult<Vec<RecordWithAuthor>> {
+    Ok(AuthorProfileCache::new()?.with_authors(get_comment_on_offers_for_offer(offer_hash)?))
+}
+
+#[hdk_extern]
+pub fn get_comment_on_reflections_for_reflection_with_authors(
+    reflection_hash: ActionHash,
+) -> ExternResult<Vec<RecordWithAuthor>> {
+    Ok(
+        AuthorProfileCache::new()?
+            .with_authors(get_comment_on_reflections_for_reflection(reflection_hash)?),
+    )
+}
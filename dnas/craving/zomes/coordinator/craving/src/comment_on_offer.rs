@@ -1,5 +1,8 @@
 use hdk::prelude::*;
+use condenser_common::CondenserError;
 use craving_integrity::*;
+use crate::contributions::link_contribution;
+use crate::notifications::notify_author_of;
 #[hdk_extern]
 pub fn create_comment_on_offer(
     comment_on_offer: CommentOnOffer,
@@ -13,12 +16,18 @@ pub fn create_comment_on_offer(
         LinkTypes::OfferToCommentOnOffers,
         (),
     )?;
+    notify_author_of(
+        comment_on_offer.offer_hash,
+        comment_on_offer_hash.clone(),
+        NotificationKind::CommentOnOffer,
+    )?;
     let record = get(comment_on_offer_hash.clone(), GetOptions::default())?
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Could not find the newly created CommentOnOffer"))
+                WasmErrorInner::Guest(CondenserError::NotFound { what: String::from("CommentOnOffer") }.encode())
             ),
         )?;
+    link_contribution(comment_on_offer_hash.clone(), ContributionKind::CommentOnOffer)?;
     Ok(record)
 }
 #[hdk_extern]
@@ -62,7 +71,7 @@ pub fn update_comment_on_offer(
     let record = get(updated_comment_on_offer_hash.clone(), GetOptions::default())?
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Could not find the newly updated CommentOnOffer"))
+                WasmErrorInner::Guest(CondenserError::NotFound { what: String::from("CommentOnOffer") }.encode())
             ),
         )?;
     Ok(record)
@@ -80,15 +89,13 @@ pub fn get_comment_on_offers_for_offer(
     let links = get_links(offer_hash, LinkTypes::OfferToCommentOnOffers, None)?;
     let get_input: Vec<GetInput> = links
         .into_iter()
-        .map(|link| GetInput::new(
-            link.target.into_any_dht_hash().unwrap(),
-            GetOptions::default(),
-        ))
+        .filter_map(|link| link.target.into_any_dht_hash())
+        .map(|hash| GetInput::new(hash, GetOptions::default()))
         .collect();
     let records: Vec<Record> = HDK
         .with(|hdk| hdk.borrow().get(get_input))?
         .into_iter()
-        .filter_map(|r| r)
+        .flatten()
         .collect();
     Ok(records)
 }
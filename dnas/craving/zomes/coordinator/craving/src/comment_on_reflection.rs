@@ -1,5 +1,8 @@
 use hdk::prelude::*;
+use condenser_common::CondenserError;
 use craving_integrity::*;
+use crate::contributions::link_contribution;
+use crate::notifications::notify_author_of;
 #[hdk_extern]
 pub fn create_comment_on_reflection(
     comment_on_reflection: CommentOnReflection,
@@ -13,12 +16,18 @@ pub fn create_comment_on_reflection(
         LinkTypes::ReflectionToCommentOnReflections,
         (),
     )?;
+    notify_author_of(
+        comment_on_reflection.reflection_hash,
+        comment_on_reflection_hash.clone(),
+        NotificationKind::CommentOnReflection,
+    )?;
     let record = get(comment_on_reflection_hash.clone(), GetOptions::default())?
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Could not find the newly created CommentOnReflection"))
+                WasmErrorInner::Guest(CondenserError::NotFound { what: String::from("CommentOnReflection") }.encode())
             ),
         )?;
+    link_contribution(comment_on_reflection_hash.clone(), ContributionKind::CommentOnReflection)?;
     Ok(record)
 }
 #[hdk_extern]
@@ -62,7 +71,7 @@ pub fn update_comment_on_reflection(
     let record = get(updated_comment_on_reflection_hash.clone(), GetOptions::default())?
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Could not find the newly updated CommentOnReflection"))
+                WasmErrorInner::Guest(CondenserError::NotFound { what: String::from("CommentOnReflection") }.encode())
             ),
         )?;
     Ok(record)
@@ -84,15 +93,13 @@ pub fn get_comment_on_reflections_for_reflection(
     )?;
     let get_input: Vec<GetInput> = links
         .into_iter()
-        .map(|link| GetInput::new(
-            link.target.into_any_dht_hash().unwrap(),
-            GetOptions::default(),
-        ))
+        .filter_map(|link| link.target.into_any_dht_hash())
+        .map(|hash| GetInput::new(hash, GetOptions::default()))
         .collect();
     let records: Vec<Record> = HDK
         .with(|hdk| hdk.borrow().get(get_input))?
         .into_iter()
-        .filter_map(|r| r)
+        .flatten()
         .collect();
     Ok(records)
 }
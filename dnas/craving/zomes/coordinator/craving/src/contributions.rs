@@ -0,0 +1,59 @@
+use hdk::prelude::*;
+use craving_integrity::*;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Contribution {
+    pub kind: ContributionKind,
+    /// For resonance this is the record of the CreateLink action
+    pub record: Record,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetContributionsByAgentInput {
+    pub agent: AgentPubKey,
+    /// The kinds of contributions to return. All kinds are returned if not specified.
+    pub kinds: Option<Vec<ContributionKind>>,
+}
+
+/// Links the given action to oneself as a contribution of the given kind.
+pub fn link_contribution(action_hash: ActionHash, kind: ContributionKind) -> ExternResult<()> {
+    let my_pubkey = agent_info()?.agent_initial_pubkey;
+    create_link(my_pubkey, action_hash, kind.link_type(), ())?;
+    Ok(())
+}
+
+/// Removes the contribution link pointing to the given action, e.g. if a resonance is withdrawn.
+pub fn unlink_contribution(action_hash: ActionHash, kind: ContributionKind) -> ExternResult<()> {
+    let my_pubkey = agent_info()?.agent_initial_pubkey;
+    let links = get_links(my_pubkey, kind.link_type(), None)?;
+    for link in links {
+        if link.target == action_hash.clone().into() {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+    Ok(())
+}
+
+/// Gets everything the given agent contributed to this craving, oldest first.
+#[hdk_extern]
+pub fn get_contributions_by_agent(input: GetContributionsByAgentInput) -> ExternResult<Vec<Contribution>> {
+    let kinds = input.kinds.unwrap_or_else(ContributionKind::all);
+    let mut contributions: Vec<Contribution> = Vec::new();
+    for kind in kinds {
+        let links = get_links(input.agent.clone(), kind.link_type(), None)?;
+        let get_input: Vec<GetInput> = links
+            .into_iter()
+            .filter_map(|link| link.target.into_any_dht_hash())
+            .map(|hash| GetInput::new(hash, GetOptions::default()))
+            .collect();
+        let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
+        contributions.extend(
+            records
+                .into_iter()
+                .flatten()
+                .map(|record| Contribution { kind, record }),
+        );
+    }
+    contributions.sort_by_key(|contribution| contribution.record.action().timestamp());
+    Ok(contributions)
+}
@@ -1,5 +1,7 @@
 use hdk::prelude::*;
 use craving_integrity::*;
+use crate::contributions::{link_contribution, unlink_contribution};
+use crate::notifications::notify_resonance_for_entry;
 #[hdk_extern]
 pub fn add_resonator_for_entry(entry_hash: EntryHash) -> ExternResult<()> {
     let pubkey = agent_info()?.agent_initial_pubkey;
@@ -12,10 +14,12 @@ pub fn add_resonator_for_entry(entry_hash: EntryHash) -> ExternResult<()> {
         .into_iter()
         .filter(|link| link.target == pubkey.clone().into())
         .collect();
-    if my_links.len() != 0 {
+    if !my_links.is_empty() {
         return Ok(());
     }
-    create_link(entry_hash, pubkey, LinkTypes::EntryToResonator, ())?;
+    let resonance_hash = create_link(entry_hash.clone(), pubkey, LinkTypes::EntryToResonator, ())?;
+    link_contribution(resonance_hash.clone(), ContributionKind::Resonance)?;
+    notify_resonance_for_entry(entry_hash, resonance_hash)?;
     Ok(())
 }
 #[hdk_extern]
@@ -25,7 +29,7 @@ pub fn get_resonators_for_entry(
     let links = get_links(entry_hash, LinkTypes::EntryToResonator, None)?;
     let agents: Vec<AgentPubKey> = links
         .into_iter()
-        .map(|link| link.target.into_agent_pub_key().unwrap())
+        .filter_map(|link| link.target.into_agent_pub_key())
         .collect();
     Ok(agents)
 }
@@ -38,7 +42,8 @@ pub fn remove_resonator_for_entry(entry_hash: EntryHash) -> ExternResult<()> {
         .filter(|link| link.target == pubkey.clone().into())
         .collect();
     for link in my_links {
-        delete_link(link.create_link_hash)?;
+        delete_link(link.create_link_hash.clone())?;
+        unlink_contribution(link.create_link_hash, ContributionKind::Resonance)?;
     }
     Ok(())
 }
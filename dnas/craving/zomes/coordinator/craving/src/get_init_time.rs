@@ -1,4 +1,5 @@
 use hdk::prelude::*;
+use condenser_common::CondenserError;
 
 #[hdk_extern]
 pub fn get_init_time(_: ()) -> ExternResult<Timestamp> {
@@ -8,6 +9,6 @@ pub fn get_init_time(_: ()) -> ExternResult<Timestamp> {
     let query_result = query(filter)?;
     match query_result.first() {
         Some(record) => Ok(record.action().timestamp()),
-        None => Err(wasm_error!(WasmErrorInner::Guest("Failed to get the DNA Record.".into())))
+        None => Err(wasm_error!(WasmErrorInner::Guest(CondenserError::NotFound { what: String::from("DnaRecipe") }.encode())))
     }
 }
\ No newline at end of file
@@ -0,0 +1,62 @@
+use hdk::prelude::*;
+use hdk::prelude::holo_hash::DnaHash;
+use condenser_common::CondenserError;
+use crate::offer::get_offer;
+use crate::registered_lobbies::known_lobby_cells;
+
+/// Mirror of the input of `publish_glossary_entry` of the cravings zome in the lobby DNA
+#[derive(Serialize, Deserialize, Debug)]
+struct PublishGlossaryEntryInput {
+    definition: String,
+    craving_dna_hash: DnaHash,
+    offer_hash: ActionHash,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PublishOfferToGlossaryInput {
+    pub offer_hash: ActionHash,
+    pub definition: String,
+    /// The lobby to publish to, defaults to the lobby the craving was created in or,
+    /// if that isn't known, the first lobby it was registered in
+    #[serde(default)]
+    pub lobby_dna_hash: Option<DnaHash>,
+}
+/// Publishes the word of an offer to the glossary of a lobby and returns the
+/// GlossaryEntry record created there. The lobby reads the word from this cell itself.
+#[hdk_extern]
+pub fn publish_offer_to_glossary(input: PublishOfferToGlossaryInput) -> ExternResult<Record> {
+    if get_offer(input.offer_hash.clone())?.is_none() {
+        return Err(
+            wasm_error!(
+                WasmErrorInner::Guest(CondenserError::NotFound { what: String::from("Offer") }.encode())
+            ),
+        );
+    }
+    let lobby_cell = match input.lobby_dna_hash {
+        Some(lobby_dna_hash) => CellId::new(lobby_dna_hash, agent_info()?.agent_initial_pubkey),
+        None => known_lobby_cells()?.into_iter().next().ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(CondenserError::NotFound { what: String::from("Lobby") }.encode())
+            ),
+        )?,
+    };
+    let response = call(
+        CallTargetCell::OtherCell(lobby_cell),
+        "cravings",
+        "publish_glossary_entry".into(),
+        None,
+        PublishGlossaryEntryInput {
+            definition: input.definition,
+            craving_dna_hash: dna_info()?.hash,
+            offer_hash: input.offer_hash,
+        },
+    )?;
+    match response {
+        ZomeCallResponse::Ok(result) => result.decode().map_err(|err| wasm_error!(err)),
+        response => Err(
+            wasm_error!(
+                WasmErrorInner::Guest(CondenserError::Unexpected { reason: format!("Could not publish to the glossary: {:?}", response) }.encode())
+            ),
+        ),
+    }
+}
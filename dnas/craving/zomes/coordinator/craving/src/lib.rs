@@ -9,11 +9,39 @@ pub mod anecdote;
 pub mod association;
 pub mod reflection;
 pub mod offer;
+pub mod participants;
+pub mod notifications;
+pub mod activity_feed;
+pub mod contributions;
+pub mod stats;
+pub mod presence;
 pub mod get_init_time;
+pub mod author_profiles;
+pub mod registered_lobbies;
+pub mod glossary;
+pub mod preview;
 use hdk::prelude::*;
+use condenser_common::CondenserError;
 use craving_integrity::*;
+use participants::*;
+/// Anchor paths linking to every entry of each type in this craving
+pub const ALL_ASSOCIATIONS_PATH: &str = "all_associations";
+pub const ALL_OFFERS_PATH: &str = "all_offers";
+pub const ALL_REFLECTIONS_PATH: &str = "all_reflections";
+pub const ALL_ANECDOTES_PATH: &str = "all_anecdotes";
 #[hdk_extern]
 pub fn init(_: ()) -> ExternResult<InitCallbackResult> {
+    // Allow any other participant to send us remote signals and probe our presence
+    let mut functions = BTreeSet::new();
+    functions.insert((zome_info()?.name, "recv_remote_signal".into()));
+    functions.insert((zome_info()?.name, "presence_probe".into()));
+    create_cap_grant(CapGrantEntry {
+        tag: "remote_signals".into(),
+        access: CapAccess::Unrestricted,
+        functions: GrantedFunctions::Listed(functions),
+    })?;
+    register_participant()?;
+    schedule("publish_craving_preview")?;
     Ok(InitCallbackResult::Pass)
 }
 #[derive(Serialize, Deserialize, Debug)]
@@ -26,20 +54,93 @@ pub enum Signal {
         action: SignedActionHashed,
         record: Record,
         app_entry: EntryTypes,
-        original_record: Record,
+        original_record: Box<Record>,
         original_app_entry: EntryTypes,
     },
     EntryDeleted { action: SignedActionHashed, original_app_entry: EntryTypes },
+    /// A signal that has been sent by another participant of this craving
+    Remote { provenance: AgentPubKey, signal: RemoteSignal },
+}
+/// Compact version of a `Signal` that gets sent to the other participants of the craving.
+/// Records are left out since the action together with the app entry is sufficient
+/// for the receiving UI to update its state.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(tag = "type")]
+pub enum RemoteSignal {
+    LinkCreated { action: SignedActionHashed, link_type: LinkTypes },
+    LinkDeleted { action: SignedActionHashed, link_type: LinkTypes },
+    EntryCreated { action: SignedActionHashed, app_entry: EntryTypes },
+    EntryUpdated { action: SignedActionHashed, app_entry: EntryTypes },
+    EntryDeleted { action: SignedActionHashed },
+    /// Ephemeral heartbeat of a participant that is currently looking at the craving
+    Presence { expires_at: Timestamp },
+}
+impl RemoteSignal {
+    fn from_signal(signal: &Signal) -> Option<RemoteSignal> {
+        match signal {
+            Signal::LinkCreated { action, link_type } => Some(RemoteSignal::LinkCreated {
+                action: action.clone(),
+                link_type: *link_type,
+            }),
+            Signal::LinkDeleted { action, link_type } => Some(RemoteSignal::LinkDeleted {
+                action: action.clone(),
+                link_type: *link_type,
+            }),
+            Signal::EntryCreated { action, app_entry, .. } => Some(RemoteSignal::EntryCreated {
+                action: action.clone(),
+                app_entry: app_entry.clone(),
+            }),
+            Signal::EntryUpdated { action, app_entry, .. } => Some(RemoteSignal::EntryUpdated {
+                action: action.clone(),
+                app_entry: app_entry.clone(),
+            }),
+            Signal::EntryDeleted { action, .. } => Some(RemoteSignal::EntryDeleted {
+                action: action.clone(),
+            }),
+            Signal::Remote { .. } => None,
+        }
+    }
 }
 #[hdk_extern(infallible)]
 pub fn post_commit(committed_actions: Vec<SignedActionHashed>) {
+    let mut remote_signals: Vec<RemoteSignal> = Vec::new();
     for action in committed_actions {
-        if let Err(err) = signal_action(action) {
-            error!("Error signaling new action: {:?}", err);
+        match signal_for_action(action) {
+            Ok(Some(signal)) => {
+                if let Some(remote_signal) = RemoteSignal::from_signal(&signal) {
+                    remote_signals.push(remote_signal);
+                }
+                if let Err(err) = emit_signal(signal) {
+                    error!("Error signaling new action: {:?}", err);
+                }
+            }
+            Ok(None) => (),
+            Err(err) => error!("Error signaling new action: {:?}", err),
+        }
+    }
+    if !remote_signals.is_empty() {
+        if let Err(err) = send_remote_signals(remote_signals) {
+            error!("Error sending remote signals: {:?}", err);
         }
     }
 }
-fn signal_action(action: SignedActionHashed) -> ExternResult<()> {
+/// Sends the signals to all other participants of this craving.
+fn send_remote_signals(remote_signals: Vec<RemoteSignal>) -> ExternResult<()> {
+    let other_participants = get_other_participants()?;
+    if other_participants.is_empty() {
+        return Ok(());
+    }
+    for signal in remote_signals {
+        remote_signal(signal, other_participants.clone())?;
+    }
+    Ok(())
+}
+#[hdk_extern]
+pub fn recv_remote_signal(signal: RemoteSignal) -> ExternResult<()> {
+    let provenance = call_info()?.provenance;
+    emit_signal(Signal::Remote { provenance, signal })
+}
+fn signal_for_action(action: SignedActionHashed) -> ExternResult<Option<Signal>> {
     match action.hashed.content.clone() {
         Action::CreateLink(create_link) => {
             let link_type = LinkTypes::from_type(
@@ -48,14 +149,13 @@ fn signal_action(action: SignedActionHashed) -> ExternResult<()> {
                 )?
                 .ok_or(
                     wasm_error!(
-                        WasmErrorInner::Guest("Link type should be exist".to_string())
+                        WasmErrorInner::Guest(CondenserError::MalformedOp { reason: String::from("Link type should be exist") }.encode())
                     ),
                 )?;
-            emit_signal(Signal::LinkCreated {
+            Ok(Some(Signal::LinkCreated {
                 action,
                 link_type,
-            })?;
-            Ok(())
+            }))
         }
         Action::DeleteLink(delete_link) => {
             let record = get(
@@ -64,7 +164,7 @@ fn signal_action(action: SignedActionHashed) -> ExternResult<()> {
                 )?
                 .ok_or(
                     wasm_error!(
-                        WasmErrorInner::Guest("Create Link should exist".to_string())
+                        WasmErrorInner::Guest(CondenserError::MalformedOp { reason: String::from("Create Link should exist") }.encode())
                     ),
                 )?;
             match record.action() {
@@ -75,22 +175,20 @@ fn signal_action(action: SignedActionHashed) -> ExternResult<()> {
                         )?
                         .ok_or(
                             wasm_error!(
-                                WasmErrorInner::Guest("Link type should be exist"
-                                .to_string())
+                                WasmErrorInner::Guest(CondenserError::MalformedOp { reason: String::from("Link type should be exist") }.encode())
                             ),
                         )?;
-                    emit_signal(Signal::LinkDeleted {
+                    Ok(Some(Signal::LinkDeleted {
                         action,
                         link_type,
-                    })?;
-                    Ok(())
+                    }))
                 }
                 _ => {
-                    return Err(
+                    Err(
                         wasm_error!(
-                            WasmErrorInner::Guest("Create Link should exist".to_string())
+                            WasmErrorInner::Guest(CondenserError::MalformedOp { reason: String::from("Create Link should exist") }.encode())
                         ),
-                    );
+                    )
                 }
             }
         }
@@ -101,17 +199,16 @@ fn signal_action(action: SignedActionHashed) -> ExternResult<()> {
                 (Some(record), Some(app_entry)) => (record, app_entry),
                 _ => return Err(
                     wasm_error!(
-                        WasmErrorInner::Guest("Create should carry an entry".to_string())
+                        WasmErrorInner::Guest(CondenserError::MalformedOp { reason: String::from("Create should carry an entry") }.encode())
                     )
                 )
             };
 
-            emit_signal(Signal::EntryCreated {
+            Ok(Some(Signal::EntryCreated {
                 action,
                 record,
                 app_entry,
-            })?;
-            Ok(())
+            }))
         }
         Action::Update(update) => {
 
@@ -121,7 +218,7 @@ fn signal_action(action: SignedActionHashed) -> ExternResult<()> {
                 (Some(record), Some(app_entry)) => (record, app_entry),
                 _ => return Err(
                     wasm_error!(
-                        WasmErrorInner::Guest("Update should carry an entry".to_string())
+                        WasmErrorInner::Guest(CondenserError::MalformedOp { reason: String::from("Update should carry an entry") }.encode())
                     )
                 )
             };
@@ -132,35 +229,32 @@ fn signal_action(action: SignedActionHashed) -> ExternResult<()> {
                 (Some(record), Some(app_entry)) => (record, app_entry),
                 _ => return Err(
                     wasm_error!(
-                        WasmErrorInner::Guest("Update should carry an entry".to_string())
+                        WasmErrorInner::Guest(CondenserError::MalformedOp { reason: String::from("Update should carry an entry") }.encode())
                     )
                 )
             };
 
-            emit_signal(Signal::EntryUpdated {
+            Ok(Some(Signal::EntryUpdated {
                 action,
                 record,
                 app_entry,
-                original_record,
+                original_record: Box::new(original_record),
                 original_app_entry,
-            })?;
-            Ok(())
+            }))
         }
         Action::Delete(delete) => {
             let original_app_entry = get_entry_for_action(&delete.deletes_address)?
                 .ok_or(
                     wasm_error!(
-                        WasmErrorInner::Guest("Deleted action should carry an entry"
-                        .to_string())
+                        WasmErrorInner::Guest(CondenserError::MalformedOp { reason: String::from("Deleted action should carry an entry") }.encode())
                     ),
                 )?;
-            emit_signal(Signal::EntryDeleted {
+            Ok(Some(Signal::EntryDeleted {
                 action,
                 original_app_entry,
-            })?;
-            Ok(())
+            }))
         }
-        _ => Ok(()),
+        _ => Ok(None),
     }
 }
 fn get_entry_for_action(action_hash: &ActionHash) -> ExternResult<Option<EntryTypes>> {
@@ -184,12 +278,10 @@ fn get_entry_for_action(action_hash: &ActionHash) -> ExternResult<Option<EntryTy
             return Ok(None);
         }
     };
-    Ok(
-        EntryTypes::deserialize_from_type(
-            zome_index.clone(),
-            entry_index.clone(),
-            entry,
-        )?,
+    EntryTypes::deserialize_from_type(
+        *zome_index,
+        *entry_index,
+        entry,
     )
 }
 
@@ -219,8 +311,8 @@ fn get_entry_and_record_for_action(action_hash: &ActionHash) -> ExternResult<(Op
         (
             Some(record_clone),
             EntryTypes::deserialize_from_type(
-                zome_index.clone(),
-                entry_index.clone(),
+                *zome_index,
+                *entry_index,
                 entry,
             )?
         ),
@@ -0,0 +1,141 @@
+use std::collections::HashSet;
+
+use hdk::prelude::*;
+use condenser_common::CondenserError;
+use craving_integrity::*;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Notification {
+    /// Hash of the CreateLink action of the notification, used to mark it as read
+    pub notification_hash: ActionHash,
+    pub kind: NotificationKind,
+    /// The comment or, for resonance, the resonated action the notification is about
+    pub source_hash: ActionHash,
+    pub sender: AgentPubKey,
+    pub timestamp: Timestamp,
+    pub read: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetMyNotificationsInput {
+    /// Only return notifications that are newer than this timestamp
+    pub since: Option<Timestamp>,
+    /// Resonance notifications are opt-in since they can be numerous
+    pub include_resonance: bool,
+}
+
+/// Notifies the given agent about the given action. Notifying oneself is a no-op.
+pub fn notify(recipient: AgentPubKey, source_hash: ActionHash, kind: NotificationKind) -> ExternResult<()> {
+    if recipient == agent_info()?.agent_initial_pubkey {
+        return Ok(());
+    }
+    create_link(recipient, source_hash, LinkTypes::AgentToNotifications, kind.to_link_tag()?)?;
+    Ok(())
+}
+
+/// Notifies the author of the record with the given action hash.
+pub fn notify_author_of(parent_hash: ActionHash, source_hash: ActionHash, kind: NotificationKind) -> ExternResult<()> {
+    let parent_record = get(parent_hash, GetOptions::default())?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(CondenserError::NotFound { what: String::from("notified record") }.encode())
+            ),
+        )?;
+    notify(parent_record.action().author().clone(), source_hash, kind)
+}
+
+/// Notifies every author that has created the given entry that we resonate with it,
+/// `resonance_hash` being our EntryToResonator link.
+pub fn notify_resonance_for_entry(entry_hash: EntryHash, resonance_hash: ActionHash) -> ExternResult<()> {
+    let creation_actions = match get_details(entry_hash, GetOptions::default())? {
+        Some(Details::Entry(entry_details)) => entry_details.actions,
+        _ => return Ok(()),
+    };
+    let mut notified_authors: HashSet<AgentPubKey> = HashSet::new();
+    for action in creation_actions {
+        let author = action.hashed.author().clone();
+        if notified_authors.insert(author.clone()) {
+            notify(
+                author,
+                action.hashed.hash,
+                NotificationKind::Resonance { resonance_hash: resonance_hash.clone() },
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn get_read_notification_hashes() -> ExternResult<HashSet<ActionHash>> {
+    let my_pubkey = agent_info()?.agent_initial_pubkey;
+    let links = get_links(my_pubkey, LinkTypes::AgentToReadNotifications, None)?;
+    Ok(
+        links
+            .into_iter()
+            .filter_map(|link| link.target.into_action_hash())
+            .collect(),
+    )
+}
+
+#[hdk_extern]
+pub fn get_my_notifications(input: GetMyNotificationsInput) -> ExternResult<Vec<Notification>> {
+    let my_pubkey = agent_info()?.agent_initial_pubkey;
+    let links = get_links(my_pubkey, LinkTypes::AgentToNotifications, None)?;
+    let read_notification_hashes = get_read_notification_hashes()?;
+    let mut notifications: Vec<Notification> = Vec::new();
+    for link in links {
+        if let Some(since) = input.since {
+            if link.timestamp <= since {
+                continue;
+            }
+        }
+        let kind = match NotificationKind::from_link_tag(link.tag.clone()) {
+            Ok(kind) => kind,
+            Err(_) => continue,
+        };
+        if matches!(kind, NotificationKind::Resonance { .. }) && !input.include_resonance {
+            continue;
+        }
+        let source_hash = match link.target.into_action_hash() {
+            Some(hash) => hash,
+            None => continue,
+        };
+        notifications.push(Notification {
+            read: read_notification_hashes.contains(&link.create_link_hash),
+            notification_hash: link.create_link_hash,
+            kind,
+            source_hash,
+            sender: link.author,
+            timestamp: link.timestamp,
+        });
+    }
+    notifications.sort_by_key(|n| std::cmp::Reverse(n.timestamp));
+    Ok(notifications)
+}
+
+/// Marks the notifications with the given notification hashes as read.
+#[hdk_extern]
+pub fn mark_notifications_read(notification_hashes: Vec<ActionHash>) -> ExternResult<()> {
+    let my_pubkey = agent_info()?.agent_initial_pubkey;
+    let read_notification_hashes = get_read_notification_hashes()?;
+    for notification_hash in notification_hashes {
+        if read_notification_hashes.contains(&notification_hash) {
+            continue;
+        }
+        create_link(
+            my_pubkey.clone(),
+            notification_hash,
+            LinkTypes::AgentToReadNotifications,
+            (),
+        )?;
+    }
+    Ok(())
+}
+
+#[hdk_extern]
+pub fn get_unread_notifications_count(include_resonance: bool) -> ExternResult<usize> {
+    let notifications = get_my_notifications(GetMyNotificationsInput {
+        since: None,
+        include_resonance,
+    })?;
+    Ok(notifications.into_iter().filter(|n| !n.read).count())
+}
@@ -1,16 +1,20 @@
 use hdk::prelude::*;
+use condenser_common::CondenserError;
 use craving_integrity::*;
+use crate::ALL_OFFERS_PATH;
+use crate::contributions::link_contribution;
 #[hdk_extern]
 pub fn create_offer(offer: Offer) -> ExternResult<Record> {
     let offer_hash = create_entry(&EntryTypes::Offer(offer.clone()))?;
     let record = get(offer_hash.clone(), GetOptions::default())?
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Could not find the newly created Offer"))
+                WasmErrorInner::Guest(CondenserError::NotFound { what: String::from("Offer") }.encode())
             ),
         )?;
-    let path = Path::from("all_offers");
+    let path = Path::from(ALL_OFFERS_PATH);
     create_link(path.path_entry_hash()?, offer_hash.clone(), LinkTypes::AllOffers, ())?;
+    link_contribution(offer_hash.clone(), ContributionKind::Offer)?;
     Ok(record)
 }
 #[hdk_extern]
@@ -46,7 +50,7 @@ pub fn update_offer(input: UpdateOfferInput) -> ExternResult<Record> {
     let record = get(updated_offer_hash.clone(), GetOptions::default())?
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Could not find the newly updated Offer"))
+                WasmErrorInner::Guest(CondenserError::NotFound { what: String::from("Offer") }.encode())
             ),
         )?;
     Ok(record)
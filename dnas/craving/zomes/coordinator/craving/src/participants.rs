@@ -0,0 +1,34 @@
+use hdk::prelude::*;
+use craving_integrity::*;
+
+/// Registers oneself as a participant of this craving. Called once from `init`.
+pub fn register_participant() -> ExternResult<()> {
+    let my_pubkey = agent_info()?.agent_initial_pubkey;
+    let path = Path::from("all_participants");
+    create_link(path.path_entry_hash()?, my_pubkey, LinkTypes::AllParticipants, ())?;
+    Ok(())
+}
+
+/// Gets all agents that have joined this craving.
+#[hdk_extern]
+pub fn get_all_participants(_: ()) -> ExternResult<Vec<AgentPubKey>> {
+    let path = Path::from("all_participants");
+    let links = get_links(path.path_entry_hash()?, LinkTypes::AllParticipants, None)?;
+    let mut participants: Vec<AgentPubKey> = links
+        .into_iter()
+        .filter_map(|link| link.target.into_agent_pub_key())
+        .collect();
+    participants.sort();
+    participants.dedup();
+    Ok(participants)
+}
+
+/// Gets all participants except oneself, i.e. the recipients of remote signals.
+pub fn get_other_participants() -> ExternResult<Vec<AgentPubKey>> {
+    let my_pubkey = agent_info()?.agent_initial_pubkey;
+    let participants = get_all_participants(())?
+        .into_iter()
+        .filter(|agent| agent != &my_pubkey)
+        .collect();
+    Ok(participants)
+}
@@ -0,0 +1,63 @@
+use hdk::prelude::*;
+use crate::RemoteSignal;
+use crate::participants::get_other_participants;
+
+/// Number of seconds after which a presence heartbeat expires if no interval is specified.
+pub const DEFAULT_PRESENCE_INTERVAL_SECS: u64 = 30;
+
+/// Lets the other participants know that we are currently looking at this craving.
+/// Nothing gets committed, the heartbeat is only sent as an ephemeral remote signal
+/// which expires after `interval_secs` seconds (defaults to DEFAULT_PRESENCE_INTERVAL_SECS).
+/// The UI is expected to call this periodically, at least once per interval, and can
+/// keep track of the heartbeats it receives itself or ask `get_online_agents`.
+#[hdk_extern]
+pub fn ping_presence(interval_secs: Option<u64>) -> ExternResult<()> {
+    let interval = std::time::Duration::from_secs(
+        interval_secs.unwrap_or(DEFAULT_PRESENCE_INTERVAL_SECS),
+    );
+    let expires_at = sys_time()?.saturating_add(&interval);
+    let other_participants = get_other_participants()?;
+    if other_participants.is_empty() {
+        return Ok(());
+    }
+    remote_signal(RemoteSignal::Presence { expires_at }, other_participants)
+}
+
+/// Gets the participants that are currently online in this craving, i.e. the ones whose
+/// craving cell responds to a presence probe, oneself included.
+/// Agents whose clock deviates from ours by more than `interval_secs` seconds are not
+/// considered online since their heartbeats could not be expired reliably.
+#[hdk_extern]
+pub fn get_online_agents(interval_secs: Option<u64>) -> ExternResult<Vec<AgentPubKey>> {
+    let interval = std::time::Duration::from_secs(
+        interval_secs.unwrap_or(DEFAULT_PRESENCE_INTERVAL_SECS),
+    );
+    let now = sys_time()?;
+    let earliest = now.saturating_sub(&interval);
+    let latest = now.saturating_add(&interval);
+
+    let mut online_agents = vec![agent_info()?.agent_initial_pubkey];
+    for agent in get_other_participants()? {
+        // unreachable agents are simply considered offline
+        let response = call_remote(
+            agent.clone(),
+            zome_info()?.name,
+            "presence_probe".into(),
+            None,
+            (),
+        );
+        if let Ok(ZomeCallResponse::Ok(result)) = response {
+            let their_time: Timestamp = result.decode().map_err(|err| wasm_error!(err))?;
+            if their_time >= earliest && their_time <= latest {
+                online_agents.push(agent);
+            }
+        }
+    }
+    Ok(online_agents)
+}
+
+/// Called remotely by other participants via `get_online_agents`. Returns our current time.
+#[hdk_extern]
+pub fn presence_probe(_: ()) -> ExternResult<Timestamp> {
+    sys_time()
+}
@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+
+use hdk::prelude::*;
+use hdk::prelude::holo_hash::DnaHash;
+use craving_integrity::*;
+use crate::participants::get_all_participants;
+use crate::registered_lobbies::known_lobby_cells;
+use crate::{ALL_ANECDOTES_PATH, ALL_ASSOCIATIONS_PATH, ALL_OFFERS_PATH, ALL_REFLECTIONS_PATH};
+
+const PREVIEW_ASSOCIATIONS_COUNT: usize = 5;
+/// Every hour, on the hour
+const PREVIEW_SCHEDULE: &str = "0 0 * * * * *";
+
+/// Mirror of PreviewAssociation of the cravings zome in the lobby DNA
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PreviewAssociation {
+    pub association: String,
+    pub resonators: usize,
+}
+
+/// Mirror of the input of `publish_craving_preview` of the cravings zome in the lobby DNA
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CravingPreviewInput {
+    pub craving_dna_hash: DnaHash,
+    pub top_associations: Vec<PreviewAssociation>,
+    pub association_count: usize,
+    pub offer_count: usize,
+    pub reflection_count: usize,
+    pub anecdote_count: usize,
+    pub participant_count: usize,
+    pub last_activity: Option<Timestamp>,
+}
+
+/// Computes the snapshot of this craving that gets published to the lobbies.
+#[hdk_extern]
+pub fn get_craving_preview(_: ()) -> ExternResult<CravingPreviewInput> {
+    let count_links = |path: &str, link_type: LinkTypes| -> ExternResult<(usize, Option<Timestamp>)> {
+        let links = get_links(Path::from(path).path_entry_hash()?, link_type, None)?;
+        Ok((links.len(), links.iter().map(|link| link.timestamp).max()))
+    };
+    let (association_count, last_association) = count_links(ALL_ASSOCIATIONS_PATH, LinkTypes::AllAssociations)?;
+    let (offer_count, last_offer) = count_links(ALL_OFFERS_PATH, LinkTypes::AllOffers)?;
+    let (reflection_count, last_reflection) = count_links(ALL_REFLECTIONS_PATH, LinkTypes::AllReflections)?;
+    let (anecdote_count, last_anecdote) = count_links(ALL_ANECDOTES_PATH, LinkTypes::AllAnecdotes)?;
+    let last_activity = [last_association, last_offer, last_reflection, last_anecdote]
+        .into_iter()
+        .flatten()
+        .max();
+
+    Ok(CravingPreviewInput {
+        craving_dna_hash: dna_info()?.hash,
+        top_associations: get_top_associations()?,
+        association_count,
+        offer_count,
+        reflection_count,
+        anecdote_count,
+        participant_count: get_all_participants(())?.len(),
+        last_activity,
+    })
+}
+
+/// The associations with the most resonators, most resonated first.
+fn get_top_associations() -> ExternResult<Vec<PreviewAssociation>> {
+    let links = get_links(Path::from(ALL_ASSOCIATIONS_PATH).path_entry_hash()?, LinkTypes::AllAssociations, None)?;
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .filter_map(|link| link.target.into_any_dht_hash())
+        .map(|hash| GetInput::new(hash, GetOptions::default()))
+        .collect();
+    let records: Vec<Record> = HDK
+        .with(|hdk| hdk.borrow().get(get_input))?
+        .into_iter()
+        .flatten()
+        .collect();
+    let mut associations: HashMap<EntryHash, PreviewAssociation> = HashMap::new();
+    for record in records {
+        let entry_hash = match record.action().entry_hash() {
+            Some(entry_hash) if !associations.contains_key(entry_hash) => entry_hash.clone(),
+            _ => continue,
+        };
+        let association: Association = match record.entry().to_app_option().map_err(|e| wasm_error!(e))? {
+            Some(association) => association,
+            None => continue,
+        };
+        let resonators = get_links(entry_hash.clone(), LinkTypes::EntryToResonator, None)?.len();
+        associations.insert(entry_hash, PreviewAssociation {
+            association: association.association,
+            resonators,
+        });
+    }
+    let mut top_associations: Vec<PreviewAssociation> = associations.into_values().collect();
+    top_associations.sort_by(|a, b| {
+        b.resonators
+            .cmp(&a.resonators)
+            .then_with(|| a.association.cmp(&b.association))
+    });
+    top_associations.truncate(PREVIEW_ASSOCIATIONS_COUNT);
+    Ok(top_associations)
+}
+
+/// The lobbies this craving is listed in as far as we know, none if we don't know any.
+fn preview_targets() -> ExternResult<Vec<CallTargetCell>> {
+    Ok(known_lobby_cells()?.into_iter().map(CallTargetCell::OtherCell).collect())
+}
+
+/// Publishes the preview of this craving to the lobbies it is listed in. Lobbies that
+/// can't be reached or don't list the craving are skipped.
+#[hdk_extern]
+pub fn publish_craving_preview_now(_: ()) -> ExternResult<()> {
+    let craving_preview = get_craving_preview(())?;
+    for target in preview_targets()? {
+        match call(
+            target,
+            "cravings",
+            "publish_craving_preview".into(),
+            None,
+            &craving_preview,
+        ) {
+            Ok(ZomeCallResponse::Ok(_)) => {}
+            Ok(response) => debug!("Could not publish the craving preview: {:?}", response),
+            Err(err) => debug!("Could not publish the craving preview: {:?}", err),
+        }
+    }
+    Ok(())
+}
+
+/// Scheduled in init. Publishing an unchanged preview is a noop in the lobby, so it
+/// doesn't matter if someone triggers this at another time.
+#[hdk_extern(infallible)]
+pub fn publish_craving_preview(_: Option<Schedule>) -> Option<Schedule> {
+    if let Err(err) = publish_craving_preview_now(()) {
+        error!("Error publishing the craving preview: {:?}", err);
+    }
+    Some(Schedule::Persisted(String::from(PREVIEW_SCHEDULE)))
+}
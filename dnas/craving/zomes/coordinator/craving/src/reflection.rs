@@ -1,21 +1,25 @@
 use hdk::prelude::*;
+use condenser_common::CondenserError;
 use craving_integrity::*;
+use crate::ALL_REFLECTIONS_PATH;
+use crate::contributions::link_contribution;
 #[hdk_extern]
 pub fn create_reflection(reflection: Reflection) -> ExternResult<Record> {
     let reflection_hash = create_entry(&EntryTypes::Reflection(reflection.clone()))?;
     let record = get(reflection_hash.clone(), GetOptions::default())?
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Could not find the newly created Reflection"))
+                WasmErrorInner::Guest(CondenserError::NotFound { what: String::from("Reflection") }.encode())
             ),
         )?;
-    let path = Path::from("all_reflections");
+    let path = Path::from(ALL_REFLECTIONS_PATH);
     create_link(
         path.path_entry_hash()?,
         reflection_hash.clone(),
         LinkTypes::AllReflections,
         (),
     )?;
+    link_contribution(reflection_hash.clone(), ContributionKind::Reflection)?;
     Ok(record)
 }
 #[hdk_extern]
@@ -57,7 +61,7 @@ pub fn update_reflection(input: UpdateReflectionInput) -> ExternResult<Record> {
     let record = get(updated_reflection_hash.clone(), GetOptions::default())?
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Could not find the newly updated Reflection"))
+                WasmErrorInner::Guest(CondenserError::NotFound { what: String::from("Reflection") }.encode())
             ),
         )?;
     Ok(record)
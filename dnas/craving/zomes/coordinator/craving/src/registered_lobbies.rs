@@ -0,0 +1,85 @@
+use hdk::prelude::*;
+use hdk::prelude::holo_hash::DnaHash;
+use condenser_common::LobbyRegistration;
+use craving_integrity::*;
+use craving_integrity::types::CravingDnaProperties;
+
+const REGISTERED_LOBBIES: &str = "registered_lobbies";
+
+/// Records that this craving is listed in the given lobby. Called by the lobby's
+/// `create_dna_recipe` via a cross-cell call, so only by ourselves.
+#[hdk_extern]
+pub fn register_lobby(lobby_registration: LobbyRegistration) -> ExternResult<()> {
+    let already_registered = get_registered_lobbies(())?
+        .iter()
+        .any(|registered| registered.lobby_dna_hash == lobby_registration.lobby_dna_hash);
+    if already_registered {
+        return Ok(());
+    }
+    let registered_lobby_hash = create_entry(&EntryTypes::RegisteredLobby(RegisteredLobby {
+        registration: lobby_registration,
+    }))?;
+    create_link(
+        Path::from(REGISTERED_LOBBIES).path_entry_hash()?,
+        registered_lobby_hash,
+        LinkTypes::RegisteredLobbies,
+        (),
+    )?;
+    Ok(())
+}
+
+/// Gets all lobbies this craving is registered in, the earliest registration of each lobby.
+#[hdk_extern]
+pub fn get_registered_lobbies(_: ()) -> ExternResult<Vec<LobbyRegistration>> {
+    let links = get_links(
+        Path::from(REGISTERED_LOBBIES).path_entry_hash()?,
+        LinkTypes::RegisteredLobbies,
+        None,
+    )?;
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .filter_map(|link| link.target.into_any_dht_hash())
+        .map(|hash| GetInput::new(hash, GetOptions::default()))
+        .collect();
+    let mut records: Vec<Record> = HDK
+        .with(|hdk| hdk.borrow().get(get_input))?
+        .into_iter()
+        .flatten()
+        .collect();
+    records.sort_by_key(|record| record.action().timestamp());
+    let mut lobbies: Vec<LobbyRegistration> = Vec::new();
+    for record in records {
+        let registered_lobby: RegisteredLobby = match record.entry().to_app_option().ok().flatten() {
+            Some(registered_lobby) => registered_lobby,
+            None => continue,
+        };
+        let lobby_registration = registered_lobby.registration;
+        if !lobbies.iter().any(|lobby| lobby.lobby_dna_hash == lobby_registration.lobby_dna_hash) {
+            lobbies.push(lobby_registration);
+        }
+    }
+    Ok(lobbies)
+}
+
+/// Our cells of the lobbies this craving is listed in as far as we know: the lobby it was
+/// created in first, if known, then the registered ones.
+pub fn known_lobby_cells() -> ExternResult<Vec<CellId>> {
+    // the base cell of the craving role has no properties
+    let mut lobby_dna_hashes: Vec<DnaHash> = CravingDnaProperties::get()
+        .ok()
+        .and_then(|craving_dna_properties| craving_dna_properties.lobby_dna_hash)
+        .into_iter()
+        .collect();
+    for lobby in get_registered_lobbies(())? {
+        if !lobby_dna_hashes.contains(&lobby.lobby_dna_hash) {
+            lobby_dna_hashes.push(lobby.lobby_dna_hash);
+        }
+    }
+    let my_pubkey = agent_info()?.agent_initial_pubkey;
+    Ok(
+        lobby_dna_hashes
+            .into_iter()
+            .map(|lobby_dna_hash| CellId::new(lobby_dna_hash, my_pubkey.clone()))
+            .collect(),
+    )
+}
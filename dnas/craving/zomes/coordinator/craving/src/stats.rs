@@ -0,0 +1,215 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use hdk::prelude::*;
+use craving_integrity::*;
+use crate::{ALL_ANECDOTES_PATH, ALL_ASSOCIATIONS_PATH, ALL_OFFERS_PATH, ALL_REFLECTIONS_PATH};
+
+const MICROS_PER_DAY: i64 = 86_400_000_000;
+const MOST_DISCUSSED_REFLECTIONS_COUNT: usize = 5;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TimeWindow {
+    pub start: Option<Timestamp>,
+    pub end: Option<Timestamp>,
+}
+impl TimeWindow {
+    fn contains(&self, timestamp: Timestamp) -> bool {
+        self.start.is_none_or(|start| timestamp >= start)
+            && self.end.is_none_or(|end| timestamp < end)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DailyActivity {
+    /// Start of the day (UTC)
+    pub day: Timestamp,
+    pub associations: usize,
+    pub offers: usize,
+    pub reflections: usize,
+    pub anecdotes: usize,
+    pub comments: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ResonanceBucket {
+    pub resonators: usize,
+    /// Number of entries that have exactly `resonators` resonators
+    pub entries: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DiscussedReflection {
+    pub reflection_hash: ActionHash,
+    pub title: String,
+    pub comments: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CravingStats {
+    pub distinct_contributors: usize,
+    /// Number of created entries per type for every day with activity, oldest first
+    pub daily_activity: Vec<DailyActivity>,
+    /// How many entries have how many resonators, ordered by number of resonators
+    pub resonance_distribution: Vec<ResonanceBucket>,
+    pub most_discussed_reflections: Vec<DiscussedReflection>,
+    /// Median over all contributors of the time in microseconds between their first
+    /// association and their first offer. None if no contributor has done both.
+    pub median_micros_to_first_offer: Option<i64>,
+}
+
+/// Computes statistics about this craving, optionally only considering what happened
+/// within the given time window.
+#[hdk_extern]
+pub fn get_craving_stats(window: Option<TimeWindow>) -> ExternResult<CravingStats> {
+    let window = window.unwrap_or(TimeWindow { start: None, end: None });
+
+    let mut contributors: HashSet<AgentPubKey> = HashSet::new();
+    let mut daily_activity: BTreeMap<i64, DailyActivity> = BTreeMap::new();
+    let mut first_associations: HashMap<AgentPubKey, Timestamp> = HashMap::new();
+    let mut first_offers: HashMap<AgentPubKey, Timestamp> = HashMap::new();
+    let mut resonated_entries: HashMap<EntryHash, usize> = HashMap::new();
+    let mut discussed_reflections: Vec<DiscussedReflection> = Vec::new();
+
+    let collections = [
+        (ALL_ASSOCIATIONS_PATH, LinkTypes::AllAssociations),
+        (ALL_OFFERS_PATH, LinkTypes::AllOffers),
+        (ALL_REFLECTIONS_PATH, LinkTypes::AllReflections),
+        (ALL_ANECDOTES_PATH, LinkTypes::AllAnecdotes),
+    ];
+
+    for (path, link_type) in collections {
+        let links = get_links(Path::from(path).path_entry_hash()?, link_type, None)?;
+        let get_input: Vec<GetInput> = links
+            .iter()
+            .filter_map(|link| link.target.clone().into_any_dht_hash())
+            .map(|hash| GetInput::new(hash, GetOptions::default()))
+            .collect();
+        let records: Vec<Record> = HDK
+            .with(|hdk| hdk.borrow().get(get_input))?
+            .into_iter()
+            .flatten()
+            .collect();
+
+        for record in records {
+            let author = record.action().author().clone();
+            let timestamp = record.action().timestamp();
+            let record_hash = record.action_address().clone();
+
+            let comment_links = match link_type {
+                LinkTypes::AllReflections => {
+                    get_links(record_hash.clone(), LinkTypes::ReflectionToCommentOnReflections, None)?
+                }
+                LinkTypes::AllOffers => {
+                    get_links(record_hash.clone(), LinkTypes::OfferToCommentOnOffers, None)?
+                }
+                _ => vec![],
+            };
+            let comment_links: Vec<Link> = comment_links
+                .into_iter()
+                .filter(|link| window.contains(link.timestamp))
+                .collect();
+            for comment_link in comment_links.iter() {
+                contributors.insert(comment_link.author.clone());
+                day_entry(&mut daily_activity, comment_link.timestamp).comments += 1;
+            }
+            if link_type == LinkTypes::AllReflections && !comment_links.is_empty() {
+                let reflection: Option<Reflection> = record
+                    .entry()
+                    .to_app_option()
+                    .map_err(|e| wasm_error!(e))?;
+                discussed_reflections.push(DiscussedReflection {
+                    reflection_hash: record_hash.clone(),
+                    title: reflection.map(|r| r.title).unwrap_or_default(),
+                    comments: comment_links.len(),
+                });
+            }
+
+            if !window.contains(timestamp) {
+                continue;
+            }
+            contributors.insert(author.clone());
+            if let Some(entry_hash) = record.action().entry_hash() {
+                if !resonated_entries.contains_key(entry_hash) {
+                    let resonators = get_links(entry_hash.clone(), LinkTypes::EntryToResonator, None)?
+                        .into_iter()
+                        .filter(|link| window.contains(link.timestamp))
+                        .count();
+                    resonated_entries.insert(entry_hash.clone(), resonators);
+                }
+            }
+            let day = day_entry(&mut daily_activity, timestamp);
+            match link_type {
+                LinkTypes::AllAssociations => {
+                    day.associations += 1;
+                    keep_earliest(&mut first_associations, author, timestamp);
+                }
+                LinkTypes::AllOffers => {
+                    day.offers += 1;
+                    keep_earliest(&mut first_offers, author, timestamp);
+                }
+                LinkTypes::AllReflections => day.reflections += 1,
+                _ => day.anecdotes += 1,
+            }
+        }
+    }
+
+    let mut resonance_distribution: BTreeMap<usize, usize> = BTreeMap::new();
+    for resonators in resonated_entries.into_values() {
+        *resonance_distribution.entry(resonators).or_insert(0) += 1;
+    }
+
+    discussed_reflections.sort_by_key(|reflection| std::cmp::Reverse(reflection.comments));
+    discussed_reflections.truncate(MOST_DISCUSSED_REFLECTIONS_COUNT);
+
+    let mut micros_to_first_offer: Vec<i64> = first_associations
+        .iter()
+        .filter_map(|(agent, first_association)| {
+            first_offers
+                .get(agent)
+                .map(|first_offer| first_offer.as_micros() - first_association.as_micros())
+        })
+        .filter(|micros| *micros >= 0)
+        .collect();
+    micros_to_first_offer.sort();
+
+    Ok(CravingStats {
+        distinct_contributors: contributors.len(),
+        daily_activity: daily_activity.into_values().collect(),
+        resonance_distribution: resonance_distribution
+            .into_iter()
+            .map(|(resonators, entries)| ResonanceBucket { resonators, entries })
+            .collect(),
+        most_discussed_reflections: discussed_reflections,
+        median_micros_to_first_offer: median(&micros_to_first_offer),
+    })
+}
+
+fn day_entry(daily_activity: &mut BTreeMap<i64, DailyActivity>, timestamp: Timestamp) -> &mut DailyActivity {
+    let day = timestamp.as_micros().div_euclid(MICROS_PER_DAY);
+    daily_activity.entry(day).or_insert_with(|| DailyActivity {
+        day: Timestamp::from_micros(day * MICROS_PER_DAY),
+        associations: 0,
+        offers: 0,
+        reflections: 0,
+        anecdotes: 0,
+        comments: 0,
+    })
+}
+
+fn keep_earliest(firsts: &mut HashMap<AgentPubKey, Timestamp>, agent: AgentPubKey, timestamp: Timestamp) {
+    let first = firsts.entry(agent).or_insert(timestamp);
+    if timestamp < *first {
+        *first = timestamp;
+    }
+}
+
+fn median(sorted_values: &[i64]) -> Option<i64> {
+    if sorted_values.is_empty() {
+        return None;
+    }
+    let middle = sorted_values.len() / 2;
+    match sorted_values.len() % 2 {
+        0 => Some((sorted_values[middle - 1] + sorted_values[middle]) / 2),
+        _ => Some(sorted_values[middle]),
+    }
+}
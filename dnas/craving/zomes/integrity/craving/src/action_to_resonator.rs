@@ -1,4 +1,5 @@
 use hdi::prelude::*;
+use condenser_common::CondenserError;
 pub fn validate_create_link_action_to_resonator(
     action: CreateLink,
     base_address: AnyLinkableHash,
@@ -10,21 +11,18 @@ pub fn validate_create_link_action_to_resonator(
     if target_pubkey != author_pubkey {
         return Ok(
             ValidateCallbackResult::Invalid(
-                String::from(
-                    "Resonators can only be created for oneself. Author public key of the CreateLink does not match the link's target.",
-                ),
+                CondenserError::NotAuthor { what: String::from("resonator link") }.encode(),
             ),
         );
     }
     let action_hash = ActionHash::try_from(base_address).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
     let record = must_get_valid_record(action_hash)?;
-    let _reflection = record
+    let _entry = record
         .entry()
-        .to_app_option()
-        .map_err(|e| wasm_error!(e))?
+        .as_option()
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+                WasmErrorInner::Guest(CondenserError::MissingEntry.encode())
             ),
         )?;
     Ok(ValidateCallbackResult::Valid)
@@ -41,9 +39,7 @@ pub fn validate_delete_link_action_to_resonator(
     if target_pubkey != author_pubkey {
         return Ok(
             ValidateCallbackResult::Invalid(
-                String::from(
-                    "Only own resonators can be deleted. Author public key of the DeleteLink does not match the link's target.",
-                ),
+                CondenserError::NotAuthor { what: String::from("resonator link") }.encode(),
             ),
         );
     }
@@ -0,0 +1,270 @@
+use hdi::prelude::*;
+use condenser_common::CondenserError;
+use crate::{EntryTypes, LinkTypes};
+
+/// The kinds of contributions an agent can make to a craving.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub enum ContributionKind {
+    Association,
+    Offer,
+    Reflection,
+    Anecdote,
+    CommentOnOffer,
+    CommentOnReflection,
+    Resonance,
+}
+impl ContributionKind {
+    pub fn all() -> Vec<ContributionKind> {
+        vec![
+            ContributionKind::Association,
+            ContributionKind::Offer,
+            ContributionKind::Reflection,
+            ContributionKind::Anecdote,
+            ContributionKind::CommentOnOffer,
+            ContributionKind::CommentOnReflection,
+            ContributionKind::Resonance,
+        ]
+    }
+    /// The link type pointing from an agent to its contributions of this kind.
+    pub fn link_type(&self) -> LinkTypes {
+        match self {
+            ContributionKind::Association => LinkTypes::AgentToAssociations,
+            ContributionKind::Offer => LinkTypes::AgentToOffers,
+            ContributionKind::Reflection => LinkTypes::AgentToReflections,
+            ContributionKind::Anecdote => LinkTypes::AgentToAnecdotes,
+            ContributionKind::CommentOnOffer => LinkTypes::AgentToCommentOnOffers,
+            ContributionKind::CommentOnReflection => LinkTypes::AgentToCommentOnReflections,
+            ContributionKind::Resonance => LinkTypes::AgentToResonances,
+        }
+    }
+}
+/// Checks that the link is created by the agent it is based on, that this agent authored
+/// the target and that the target is of the given contribution kind.
+fn validate_create_link_agent_to_contribution(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    kind: ContributionKind,
+) -> ExternResult<ValidateCallbackResult> {
+    let base_pubkey = AgentPubKey::try_from(base_address).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
+    if base_pubkey != action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("contribution link") }.encode(),
+            ),
+        );
+    }
+    let action_hash = ActionHash::try_from(target_address).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
+    let record = must_get_valid_record(action_hash)?;
+    if record.action().author() != &base_pubkey {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("contribution") }.encode(),
+            ),
+        );
+    }
+    let is_expected_kind = match kind {
+        ContributionKind::Resonance => match record.action() {
+            Action::CreateLink(create_link) => {
+                LinkTypes::from_type(create_link.zome_index, create_link.link_type)?
+                    == Some(LinkTypes::EntryToResonator)
+            }
+            _ => false,
+        },
+        _ => {
+            let app_entry = match (record.action().entry_type(), record.entry().as_option()) {
+                (Some(EntryType::App(app_entry_def)), Some(entry)) => EntryTypes::deserialize_from_type(
+                    app_entry_def.zome_index,
+                    app_entry_def.entry_index,
+                    entry,
+                )?,
+                _ => None,
+            };
+            matches!(
+                (kind, app_entry),
+                (ContributionKind::Association, Some(EntryTypes::Association(_)))
+                    | (ContributionKind::Offer, Some(EntryTypes::Offer(_)))
+                    | (ContributionKind::Reflection, Some(EntryTypes::Reflection(_)))
+                    | (ContributionKind::Anecdote, Some(EntryTypes::Anecdote(_)))
+                    | (ContributionKind::CommentOnOffer, Some(EntryTypes::CommentOnOffer(_)))
+                    | (ContributionKind::CommentOnReflection, Some(EntryTypes::CommentOnReflection(_)))
+            )
+        }
+    };
+    if !is_expected_kind {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::WrongType { expected: format!("{:?}", kind) }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+fn validate_delete_link_agent_to_contribution(
+    action: DeleteLink,
+    base: AnyLinkableHash,
+) -> ExternResult<ValidateCallbackResult> {
+    let base_pubkey = AgentPubKey::try_from(base).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
+    if base_pubkey != action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("contribution link") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_create_link_agent_to_associations(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_create_link_agent_to_contribution(
+        action,
+        base_address,
+        target_address,
+        ContributionKind::Association,
+    )
+}
+pub fn validate_delete_link_agent_to_associations(
+    action: DeleteLink,
+    _original_action: CreateLink,
+    base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_delete_link_agent_to_contribution(action, base)
+}
+pub fn validate_create_link_agent_to_offers(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_create_link_agent_to_contribution(
+        action,
+        base_address,
+        target_address,
+        ContributionKind::Offer,
+    )
+}
+pub fn validate_delete_link_agent_to_offers(
+    action: DeleteLink,
+    _original_action: CreateLink,
+    base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_delete_link_agent_to_contribution(action, base)
+}
+pub fn validate_create_link_agent_to_reflections(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_create_link_agent_to_contribution(
+        action,
+        base_address,
+        target_address,
+        ContributionKind::Reflection,
+    )
+}
+pub fn validate_delete_link_agent_to_reflections(
+    action: DeleteLink,
+    _original_action: CreateLink,
+    base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_delete_link_agent_to_contribution(action, base)
+}
+pub fn validate_create_link_agent_to_anecdotes(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_create_link_agent_to_contribution(
+        action,
+        base_address,
+        target_address,
+        ContributionKind::Anecdote,
+    )
+}
+pub fn validate_delete_link_agent_to_anecdotes(
+    action: DeleteLink,
+    _original_action: CreateLink,
+    base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_delete_link_agent_to_contribution(action, base)
+}
+pub fn validate_create_link_agent_to_comment_on_offers(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_create_link_agent_to_contribution(
+        action,
+        base_address,
+        target_address,
+        ContributionKind::CommentOnOffer,
+    )
+}
+pub fn validate_delete_link_agent_to_comment_on_offers(
+    action: DeleteLink,
+    _original_action: CreateLink,
+    base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_delete_link_agent_to_contribution(action, base)
+}
+pub fn validate_create_link_agent_to_comment_on_reflections(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_create_link_agent_to_contribution(
+        action,
+        base_address,
+        target_address,
+        ContributionKind::CommentOnReflection,
+    )
+}
+pub fn validate_delete_link_agent_to_comment_on_reflections(
+    action: DeleteLink,
+    _original_action: CreateLink,
+    base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_delete_link_agent_to_contribution(action, base)
+}
+pub fn validate_create_link_agent_to_resonances(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_create_link_agent_to_contribution(
+        action,
+        base_address,
+        target_address,
+        ContributionKind::Resonance,
+    )
+}
+pub fn validate_delete_link_agent_to_resonances(
+    action: DeleteLink,
+    _original_action: CreateLink,
+    base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_delete_link_agent_to_contribution(action, base)
+}
@@ -1,4 +1,5 @@
 use hdi::prelude::*;
+use condenser_common::CondenserError;
 
 use crate::types::{CravingDnaProperties, DEFAULT_MAX_ANECDOTE_CHARS};
 #[hdk_entry_helper]
@@ -12,17 +13,17 @@ pub fn validate_create_anecdote(
 ) -> ExternResult<ValidateCallbackResult> {
     let dna_info = dna_info()?;
     let craving_dna_properties = CravingDnaProperties::try_from(dna_info.modifiers.properties)
-        .map_err(|err| wasm_error!(WasmErrorInner::Guest(format!("Failed to convert dna properties into CravingDnaProperties during validation of anecdote creation: {}", err.to_string()))))?;
+        .map_err(|err| wasm_error!(WasmErrorInner::Guest(CondenserError::InvalidDnaProperties { reason: err.to_string() }.encode())))?;
 
     match craving_dna_properties.max_anecdote_chars {
         Some(max) => {
-            if anecdote.anecdote.len() > max {
-                return Ok(ValidateCallbackResult::Invalid(format!("Anecdote is longer than allowed. Max characters: {}", max)));
+            if anecdote.anecdote.chars().count() > max {
+                return Ok(ValidateCallbackResult::Invalid(CondenserError::TooLong { field: String::from("anecdote"), max, actual: anecdote.anecdote.chars().count() }.encode()));
             }
         },
         None => {
-            if anecdote.anecdote.len() > DEFAULT_MAX_ANECDOTE_CHARS {
-                return Ok(ValidateCallbackResult::Invalid(format!("Anecdote is longer than allowed. Max characters: {} (default value)", DEFAULT_MAX_ANECDOTE_CHARS)));
+            if anecdote.anecdote.chars().count() > DEFAULT_MAX_ANECDOTE_CHARS {
+                return Ok(ValidateCallbackResult::Invalid(CondenserError::TooLong { field: String::from("anecdote"), max: DEFAULT_MAX_ANECDOTE_CHARS, actual: anecdote.anecdote.chars().count() }.encode()));
             }
         },
     }
@@ -57,7 +58,7 @@ pub fn validate_create_link_anecdote_updates(
         .map_err(|e| wasm_error!(e))?
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+                WasmErrorInner::Guest(CondenserError::MissingEntry.encode())
             ),
         )?;
     let action_hash = ActionHash::try_from(target_address).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
@@ -68,7 +69,7 @@ pub fn validate_create_link_anecdote_updates(
         .map_err(|e| wasm_error!(e))?
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+                WasmErrorInner::Guest(CondenserError::MissingEntry.encode())
             ),
         )?;
     Ok(ValidateCallbackResult::Valid)
@@ -82,7 +83,7 @@ pub fn validate_delete_link_anecdote_updates(
 ) -> ExternResult<ValidateCallbackResult> {
     Ok(
         ValidateCallbackResult::Invalid(
-            String::from("AnecdoteUpdates links cannot be deleted"),
+            CondenserError::NotDeletable { what: String::from("AnecdoteUpdates link") }.encode(),
         ),
     )
 }
@@ -101,7 +102,7 @@ pub fn validate_create_link_all_anecdotes(
         .map_err(|e| wasm_error!(e))?
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+                WasmErrorInner::Guest(CondenserError::MissingEntry.encode())
             ),
         )?;
     // TODO: add the appropriate validation rules
@@ -116,7 +117,7 @@ pub fn validate_delete_link_all_anecdotes(
 ) -> ExternResult<ValidateCallbackResult> {
     Ok(
         ValidateCallbackResult::Invalid(
-            String::from("AllAnecdotes links cannot be deleted"),
+            CondenserError::NotDeletable { what: String::from("AllAnecdotes link") }.encode(),
         ),
     )
 }
@@ -1,4 +1,5 @@
 use hdi::prelude::*;
+use condenser_common::CondenserError;
 use crate::types::*;
 
 #[hdk_entry_helper]
@@ -12,17 +13,17 @@ pub fn validate_create_association(
 ) -> ExternResult<ValidateCallbackResult> {
     let dna_info = dna_info()?;
     let craving_dna_properties = CravingDnaProperties::try_from(dna_info.modifiers.properties)
-        .map_err(|err| wasm_error!(WasmErrorInner::Guest(format!("Failed to convert dna properties into CravingDnaProperties during validation of association creation: {}", err.to_string()))))?;
+        .map_err(|err| wasm_error!(WasmErrorInner::Guest(CondenserError::InvalidDnaProperties { reason: err.to_string() }.encode())))?;
 
     match craving_dna_properties.max_association_chars {
         Some(max) => {
-            if association.association.len() > max {
-                return Ok(ValidateCallbackResult::Invalid(format!("Association is longer than allowed. Max characters: {}", max)));
+            if association.association.chars().count() > max {
+                return Ok(ValidateCallbackResult::Invalid(CondenserError::TooLong { field: String::from("association"), max, actual: association.association.chars().count() }.encode()));
             }
         },
         None => {
-            if association.association.len() > DEFAULT_MAX_ASSOCIATION_CHARS {
-                return Ok(ValidateCallbackResult::Invalid(format!("Association is longer than allowed. Max characters: {} (default value)", DEFAULT_MAX_ASSOCIATION_CHARS)));
+            if association.association.chars().count() > DEFAULT_MAX_ASSOCIATION_CHARS {
+                return Ok(ValidateCallbackResult::Invalid(CondenserError::TooLong { field: String::from("association"), max: DEFAULT_MAX_ASSOCIATION_CHARS, actual: association.association.chars().count() }.encode()));
             }
         },
     }
@@ -35,14 +36,14 @@ pub fn validate_update_association(
     _original_action: EntryCreationAction,
     _original_association: Association,
 ) -> ExternResult<ValidateCallbackResult> {
-    Ok(ValidateCallbackResult::Invalid(String::from("Associations cannot be updated")))
+    Ok(ValidateCallbackResult::Invalid(CondenserError::NotUpdatable { what: String::from("Association") }.encode()))
 }
 pub fn validate_delete_association(
     _action: Delete,
     _original_action: EntryCreationAction,
     _original_association: Association,
 ) -> ExternResult<ValidateCallbackResult> {
-    Ok(ValidateCallbackResult::Invalid(String::from("Associations cannot be deleted")))
+    Ok(ValidateCallbackResult::Invalid(CondenserError::NotDeletable { what: String::from("Association") }.encode()))
 }
 pub fn validate_create_link_association_updates(
     _action: CreateLink,
@@ -58,7 +59,7 @@ pub fn validate_create_link_association_updates(
     //     .map_err(|e| wasm_error!(e))?
     //     .ok_or(
     //         wasm_error!(
-    //             WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+    //             WasmErrorInner::Guest(CondenserError::MissingEntry.encode())
     //         ),
     //     )?;
     // let action_hash = ActionHash::from(target_address);
@@ -69,13 +70,13 @@ pub fn validate_create_link_association_updates(
     //     .map_err(|e| wasm_error!(e))?
     //     .ok_or(
     //         wasm_error!(
-    //             WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+    //             WasmErrorInner::Guest(CondenserError::MissingEntry.encode())
     //         ),
     //     )?;
     // Ok(ValidateCallbackResult::Valid)
     Ok(
         ValidateCallbackResult::Invalid(
-            String::from("AssociationUpdates links cannot be updated"),
+            CondenserError::NotUpdatable { what: String::from("AssociationUpdates link") }.encode(),
         ),
     )
 }
@@ -88,7 +89,7 @@ pub fn validate_delete_link_association_updates(
 ) -> ExternResult<ValidateCallbackResult> {
     Ok(
         ValidateCallbackResult::Invalid(
-            String::from("AssociationUpdates links cannot be deleted"),
+            CondenserError::NotDeletable { what: String::from("AssociationUpdates link") }.encode(),
         ),
     )
 }
@@ -106,7 +107,7 @@ pub fn validate_create_link_all_associations(
         .map_err(|e| wasm_error!(e))?
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+                WasmErrorInner::Guest(CondenserError::MissingEntry.encode())
             ),
         )?;
     Ok(ValidateCallbackResult::Valid)
@@ -120,7 +121,7 @@ pub fn validate_delete_link_all_associations(
 ) -> ExternResult<ValidateCallbackResult> {
     Ok(
         ValidateCallbackResult::Invalid(
-            String::from("AllAssociations links cannot be deleted"),
+            CondenserError::NotDeletable { what: String::from("AllAssociations link") }.encode(),
         ),
     )
 }
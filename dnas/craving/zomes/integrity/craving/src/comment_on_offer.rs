@@ -1,5 +1,5 @@
 use hdi::prelude::*;
-use crate::types::*;
+use condenser_common::CondenserError;
 
 #[hdk_entry_helper]
 #[derive(Clone)]
@@ -18,14 +18,14 @@ pub fn validate_create_comment_on_offer(
         .map_err(|e| wasm_error!(e))?
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+                WasmErrorInner::Guest(CondenserError::MissingEntry.encode())
             ),
         )?;
 
     // comments on offers are limited to a fixed max size of 800 characters
     let max_chars: usize = 800;
-    if comment_on_offer.comment.len() > max_chars {
-        return Ok(ValidateCallbackResult::Invalid(format!("Comment on Offer is longer than allowed. Max characters: {} (hard-coded)", max_chars)));
+    if comment_on_offer.comment.chars().count() > max_chars {
+        return Ok(ValidateCallbackResult::Invalid(CondenserError::TooLong { field: String::from("comment"), max: max_chars, actual: comment_on_offer.comment.chars().count() }.encode()));
     }
 
     Ok(ValidateCallbackResult::Valid)
@@ -59,7 +59,7 @@ pub fn validate_create_link_offer_to_comment_on_offers(
         .map_err(|e| wasm_error!(e))?
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+                WasmErrorInner::Guest(CondenserError::MissingEntry.encode())
             ),
         )?;
     let action_hash = ActionHash::try_from(target_address).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
@@ -70,7 +70,7 @@ pub fn validate_create_link_offer_to_comment_on_offers(
         .map_err(|e| wasm_error!(e))?
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+                WasmErrorInner::Guest(CondenserError::MissingEntry.encode())
             ),
         )?;
     Ok(ValidateCallbackResult::Valid)
@@ -84,7 +84,7 @@ pub fn validate_delete_link_offer_to_comment_on_offers(
 ) -> ExternResult<ValidateCallbackResult> {
     Ok(
         ValidateCallbackResult::Invalid(
-            String::from("OfferToCommentOnOffers links cannot be deleted"),
+            CondenserError::NotDeletable { what: String::from("OfferToCommentOnOffers link") }.encode(),
         ),
     )
 }
@@ -102,7 +102,7 @@ pub fn validate_create_link_comment_on_offer_updates(
         .map_err(|e| wasm_error!(e))?
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+                WasmErrorInner::Guest(CondenserError::MissingEntry.encode())
             ),
         )?;
     let action_hash = ActionHash::try_from(target_address).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
@@ -113,7 +113,7 @@ pub fn validate_create_link_comment_on_offer_updates(
         .map_err(|e| wasm_error!(e))?
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+                WasmErrorInner::Guest(CondenserError::MissingEntry.encode())
             ),
         )?;
     Ok(ValidateCallbackResult::Valid)
@@ -127,7 +127,7 @@ pub fn validate_delete_link_comment_on_offer_updates(
 ) -> ExternResult<ValidateCallbackResult> {
     Ok(
         ValidateCallbackResult::Invalid(
-            String::from("CommentOnOfferUpdates links cannot be deleted"),
+            CondenserError::NotDeletable { what: String::from("CommentOnOfferUpdates link") }.encode(),
         ),
     )
 }
@@ -1,4 +1,5 @@
 use hdi::prelude::*;
+use condenser_common::CondenserError;
 use crate::types::*;
 
 #[hdk_entry_helper]
@@ -18,25 +19,25 @@ pub fn validate_create_comment_on_reflection(
         .map_err(|e| wasm_error!(e))?
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Dependant action must be accompanied by an entry"))
+                WasmErrorInner::Guest(CondenserError::MissingEntry.encode())
             ),
         )?;
 
     // comments on reflections are limited to the same size as a reflection itself
     let dna_info = dna_info()?;
     let craving_dna_properties = CravingDnaProperties::try_from(dna_info.modifiers.properties)
-        .map_err(|err| wasm_error!(WasmErrorInner::Guest(format!("Failed to convert dna properties into CravingDnaProperties during validation of offer creation: {}", err.to_string()))))?;
+        .map_err(|err| wasm_error!(WasmErrorInner::Guest(CondenserError::InvalidDnaProperties { reason: err.to_string() }.encode())))?;
 
 
     match craving_dna_properties.max_reflection_chars {
         Some(max) => {
-            if comment_on_reflection.comment.len() > max {
-                return Ok(ValidateCallbackResult::Invalid(format!("Comment on Reflection is longer than allowed. Max characters: {} (same as Reflection max charachters)", max)));
+            if comment_on_reflection.comment.chars().count() > max {
+                return Ok(ValidateCallbackResult::Invalid(CondenserError::TooLong { field: String::from("comment"), max, actual: comment_on_reflection.comment.chars().count() }.encode()));
             }
         },
         None => {
-            if comment_on_reflection.comment.len() > DEFAULT_MAX_REFLECTION_CHARS {
-                return Ok(ValidateCallbackResult::Invalid(format!("Comment on Reflection is longer than allowed. Max characters: {} (default value)", DEFAULT_MAX_REFLECTION_CHARS)));
+            if comment_on_reflection.comment.chars().count() > DEFAULT_MAX_REFLECTION_CHARS {
+                return Ok(ValidateCallbackResult::Invalid(CondenserError::TooLong { field: String::from("comment"), max: DEFAULT_MAX_REFLECTION_CHARS, actual: comment_on_reflection.comment.chars().count() }.encode()));
             }
         },
     }
@@ -72,7 +73,7 @@ pub fn validate_create_link_reflection_to_comment_on_reflections(
         .map_err(|e| wasm_error!(e))?
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+                WasmErrorInner::Guest(CondenserError::MissingEntry.encode())
             ),
         )?;
     let action_hash = ActionHash::try_from(target_address).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
@@ -83,7 +84,7 @@ pub fn validate_create_link_reflection_to_comment_on_reflections(
         .map_err(|e| wasm_error!(e))?
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+                WasmErrorInner::Guest(CondenserError::MissingEntry.encode())
             ),
         )?;
     Ok(ValidateCallbackResult::Valid)
@@ -97,7 +98,7 @@ pub fn validate_delete_link_reflection_to_comment_on_reflections(
 ) -> ExternResult<ValidateCallbackResult> {
     Ok(
         ValidateCallbackResult::Invalid(
-            String::from("ReflectionToCommentOnReflections links cannot be deleted"),
+            CondenserError::NotDeletable { what: String::from("ReflectionToCommentOnReflections link") }.encode(),
         ),
     )
 }
@@ -115,7 +116,7 @@ pub fn validate_create_link_comment_on_reflection_updates(
         .map_err(|e| wasm_error!(e))?
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+                WasmErrorInner::Guest(CondenserError::MissingEntry.encode())
             ),
         )?;
     let action_hash = ActionHash::try_from(target_address).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
@@ -126,7 +127,7 @@ pub fn validate_create_link_comment_on_reflection_updates(
         .map_err(|e| wasm_error!(e))?
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+                WasmErrorInner::Guest(CondenserError::MissingEntry.encode())
             ),
         )?;
     Ok(ValidateCallbackResult::Valid)
@@ -140,7 +141,7 @@ pub fn validate_delete_link_comment_on_reflection_updates(
 ) -> ExternResult<ValidateCallbackResult> {
     Ok(
         ValidateCallbackResult::Invalid(
-            String::from("CommentOnReflectionUpdates links cannot be deleted"),
+            CondenserError::NotDeletable { what: String::from("CommentOnReflectionUpdates link") }.encode(),
         ),
     )
 }
@@ -1,4 +1,5 @@
 use hdi::prelude::*;
+use condenser_common::CondenserError;
 pub fn validate_create_link_entry_to_resonator(
     action: CreateLink,
     base_address: AnyLinkableHash,
@@ -14,9 +15,7 @@ pub fn validate_create_link_entry_to_resonator(
     if target_pubkey != author_pubkey {
         return Ok(
             ValidateCallbackResult::Invalid(
-                String::from(
-                    "Resonators can only be created for oneself. Author public key of the CreateLink does not match the link's target.",
-                ),
+                CondenserError::NotAuthor { what: String::from("resonator link") }.encode(),
             ),
         );
     }
@@ -39,9 +38,7 @@ pub fn validate_delete_link_entry_to_resonator(
     if target_pubkey != author_pubkey {
         return Ok(
             ValidateCallbackResult::Invalid(
-                String::from(
-                    "Resonators can only be created for oneself. Author public key of the CreateLink does not match the link's target.",
-                ),
+                CondenserError::NotAuthor { what: String::from("resonator link") }.encode(),
             ),
         );
     }
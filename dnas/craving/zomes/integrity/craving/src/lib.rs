@@ -14,9 +14,18 @@ pub mod reflection;
 pub use reflection::*;
 pub mod offer;
 pub use offer::*;
+pub mod participant;
+pub use participant::*;
+pub mod notification;
+pub use notification::*;
+pub mod agent_to_contributions;
+pub use agent_to_contributions::*;
+pub mod registered_lobby;
+pub use registered_lobby::*;
 pub mod types;
 use hdi::prelude::*;
-#[derive(Serialize, Deserialize)]
+use condenser_common::{CondenserError, InvitationProof};
+#[derive(Serialize, Deserialize, Clone)]
 #[serde(tag = "type")]
 #[hdk_entry_defs]
 #[unit_enum(UnitEntryTypes)]
@@ -27,6 +36,7 @@ pub enum EntryTypes {
     Anecdote(Anecdote),
     CommentOnOffer(CommentOnOffer),
     CommentOnReflection(CommentOnReflection),
+    RegisteredLobby(RegisteredLobby),
 }
 #[derive(Serialize, Deserialize)]
 #[hdk_link_types]
@@ -45,23 +55,54 @@ pub enum LinkTypes {
     AllAssociations,
     AllReflections,
     AllAnecdotes,
+    AllParticipants,
+    AgentToNotifications,
+    AgentToReadNotifications,
+    AgentToAssociations,
+    AgentToOffers,
+    AgentToReflections,
+    AgentToAnecdotes,
+    AgentToCommentOnOffers,
+    AgentToCommentOnReflections,
+    AgentToResonances,
+    RegisteredLobbies,
 }
 #[hdk_extern]
 pub fn genesis_self_check(
-    _data: GenesisSelfCheckData,
+    data: GenesisSelfCheckData,
 ) -> ExternResult<ValidateCallbackResult> {
-    Ok(ValidateCallbackResult::Valid)
+    validate_agent_joining(data.agent_key, &data.membrane_proof)
 }
+/// Cravings of invitation-only lobbies can only be joined by members of the lobby. Their
+/// membrane proof is the same InvitationProof they joined the lobby with. Cravings
+/// without properties, like the base cell of the craving role, are open.
 pub fn validate_agent_joining(
-    _agent_pub_key: AgentPubKey,
-    _membrane_proof: &Option<MembraneProof>,
+    agent_pub_key: AgentPubKey,
+    membrane_proof: &Option<MembraneProof>,
 ) -> ExternResult<ValidateCallbackResult> {
-    Ok(ValidateCallbackResult::Valid)
+    let Ok(craving_dna_properties) = types::CravingDnaProperties::get() else {
+        return Ok(ValidateCallbackResult::Valid);
+    };
+    let (lobby_dna_hash, lobby_creator) = match (
+        craving_dna_properties.lobby_dna_hash,
+        craving_dna_properties.lobby_creator,
+    ) {
+        (Some(lobby_dna_hash), Some(lobby_creator)) => (lobby_dna_hash, lobby_creator),
+        _ => return Ok(ValidateCallbackResult::Valid),
+    };
+    let invitation_proof = match InvitationProof::from_membrane_proof(membrane_proof) {
+        Ok(invitation_proof) => invitation_proof,
+        Err(err) => return Ok(ValidateCallbackResult::Invalid(err.encode())),
+    };
+    match invitation_proof.verify(&lobby_dna_hash, &lobby_creator, &agent_pub_key)? {
+        Some(err) => Ok(ValidateCallbackResult::Invalid(err.encode())),
+        None => Ok(ValidateCallbackResult::Valid),
+    }
 }
 #[hdk_extern]
 pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
-    match op.to_type::<EntryTypes, LinkTypes>()? {
-        OpType::StoreEntry(store_entry) => {
+    match op.flattened::<EntryTypes, LinkTypes>()? {
+        FlatOp::StoreEntry(store_entry) => {
             match store_entry {
                 OpEntry::CreateEntry { app_entry, action } => {
                     match app_entry {
@@ -101,6 +142,12 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 comment_on_reflection,
                             )
                         }
+                        EntryTypes::RegisteredLobby(registered_lobby) => {
+                            validate_create_registered_lobby(
+                                EntryCreationAction::Create(action),
+                                registered_lobby,
+                            )
+                        }
                     }
                 }
                 OpEntry::UpdateEntry { app_entry, action, .. } => {
@@ -141,12 +188,18 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 comment_on_reflection,
                             )
                         }
+                        EntryTypes::RegisteredLobby(registered_lobby) => {
+                            validate_create_registered_lobby(
+                                EntryCreationAction::Update(action),
+                                registered_lobby,
+                            )
+                        }
                     }
                 }
                 _ => Ok(ValidateCallbackResult::Valid),
             }
         }
-        OpType::RegisterUpdate(update_entry) => {
+        FlatOp::RegisterUpdate(update_entry) => {
             match update_entry {
                 OpUpdate::Entry {
                     original_action,
@@ -223,8 +276,7 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         _ => {
                             Ok(
                                 ValidateCallbackResult::Invalid(
-                                    "Original and updated entry types must be the same"
-                                        .to_string(),
+                                    CondenserError::MalformedOp { reason: String::from("Original and updated entry types must be the same") }.encode(),
                                 ),
                             )
                         }
@@ -233,7 +285,7 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                 _ => Ok(ValidateCallbackResult::Valid),
             }
         }
-        OpType::RegisterDelete(delete_entry) => {
+        FlatOp::RegisterDelete(delete_entry) => {
             match delete_entry {
                 OpDelete::Entry { original_action, original_app_entry, action } => {
                     match original_app_entry {
@@ -271,12 +323,19 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 comment_on_reflection,
                             )
                         }
+                        EntryTypes::RegisteredLobby(registered_lobby) => {
+                            validate_delete_registered_lobby(
+                                action,
+                                original_action,
+                                registered_lobby,
+                            )
+                        }
                     }
                 }
                 _ => Ok(ValidateCallbackResult::Valid),
             }
         }
-        OpType::RegisterCreateLink {
+        FlatOp::RegisterCreateLink {
             link_type,
             base_address,
             target_address,
@@ -396,9 +455,97 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         tag,
                     )
                 }
+                LinkTypes::AllParticipants => {
+                    validate_create_link_all_participants(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AgentToNotifications => {
+                    validate_create_link_agent_to_notifications(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AgentToReadNotifications => {
+                    validate_create_link_agent_to_read_notifications(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AgentToAssociations => {
+                    validate_create_link_agent_to_associations(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AgentToOffers => {
+                    validate_create_link_agent_to_offers(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AgentToReflections => {
+                    validate_create_link_agent_to_reflections(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AgentToAnecdotes => {
+                    validate_create_link_agent_to_anecdotes(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AgentToCommentOnOffers => {
+                    validate_create_link_agent_to_comment_on_offers(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AgentToCommentOnReflections => {
+                    validate_create_link_agent_to_comment_on_reflections(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AgentToResonances => {
+                    validate_create_link_agent_to_resonances(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::RegisteredLobbies => {
+                    validate_create_link_registered_lobbies(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
             }
         }
-        OpType::RegisterDeleteLink {
+        FlatOp::RegisterDeleteLink {
             link_type,
             base_address,
             target_address,
@@ -533,9 +680,108 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         tag,
                     )
                 }
+                LinkTypes::AllParticipants => {
+                    validate_delete_link_all_participants(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AgentToNotifications => {
+                    validate_delete_link_agent_to_notifications(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AgentToReadNotifications => {
+                    validate_delete_link_agent_to_read_notifications(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AgentToAssociations => {
+                    validate_delete_link_agent_to_associations(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AgentToOffers => {
+                    validate_delete_link_agent_to_offers(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AgentToReflections => {
+                    validate_delete_link_agent_to_reflections(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AgentToAnecdotes => {
+                    validate_delete_link_agent_to_anecdotes(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AgentToCommentOnOffers => {
+                    validate_delete_link_agent_to_comment_on_offers(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AgentToCommentOnReflections => {
+                    validate_delete_link_agent_to_comment_on_reflections(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AgentToResonances => {
+                    validate_delete_link_agent_to_resonances(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::RegisteredLobbies => {
+                    validate_delete_link_registered_lobbies(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
             }
         }
-        OpType::StoreRecord(store_record) => {
+        FlatOp::StoreRecord(store_record) => {
             match store_record {
                 OpRecord::CreateEntry { app_entry, action } => {
                     match app_entry {
@@ -575,6 +821,12 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 comment_on_reflection,
                             )
                         }
+                        EntryTypes::RegisteredLobby(registered_lobby) => {
+                            validate_create_registered_lobby(
+                                EntryCreationAction::Create(action),
+                                registered_lobby,
+                            )
+                        }
                     }
                 }
                 OpRecord::UpdateEntry {
@@ -591,8 +843,7 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         _ => {
                             return Ok(
                                 ValidateCallbackResult::Invalid(
-                                    "Original action for an update must be a Create or Update action"
-                                        .to_string(),
+                                    CondenserError::MalformedOp { reason: String::from("Original action for an update must be a Create or Update action") }.encode(),
                                 ),
                             );
                         }
@@ -613,8 +864,7 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                     None => {
                                         return Ok(
                                             ValidateCallbackResult::Invalid(
-                                                "The updated entry type must be the same as the original entry type"
-                                                    .to_string(),
+                                                CondenserError::MalformedOp { reason: String::from("The updated entry type must be the same as the original entry type") }.encode(),
                                             ),
                                         );
                                     }
@@ -644,8 +894,7 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                     None => {
                                         return Ok(
                                             ValidateCallbackResult::Invalid(
-                                                "The updated entry type must be the same as the original entry type"
-                                                    .to_string(),
+                                                CondenserError::MalformedOp { reason: String::from("The updated entry type must be the same as the original entry type") }.encode(),
                                             ),
                                         );
                                     }
@@ -675,8 +924,7 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                     None => {
                                         return Ok(
                                             ValidateCallbackResult::Invalid(
-                                                "The updated entry type must be the same as the original entry type"
-                                                    .to_string(),
+                                                CondenserError::MalformedOp { reason: String::from("The updated entry type must be the same as the original entry type") }.encode(),
                                             ),
                                         );
                                     }
@@ -706,8 +954,7 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                     None => {
                                         return Ok(
                                             ValidateCallbackResult::Invalid(
-                                                "The updated entry type must be the same as the original entry type"
-                                                    .to_string(),
+                                                CondenserError::MalformedOp { reason: String::from("The updated entry type must be the same as the original entry type") }.encode(),
                                             ),
                                         );
                                     }
@@ -737,8 +984,7 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                     None => {
                                         return Ok(
                                             ValidateCallbackResult::Invalid(
-                                                "The updated entry type must be the same as the original entry type"
-                                                    .to_string(),
+                                                CondenserError::MalformedOp { reason: String::from("The updated entry type must be the same as the original entry type") }.encode(),
                                             ),
                                         );
                                     }
@@ -770,8 +1016,7 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                     None => {
                                         return Ok(
                                             ValidateCallbackResult::Invalid(
-                                                "The updated entry type must be the same as the original entry type"
-                                                    .to_string(),
+                                                CondenserError::MalformedOp { reason: String::from("The updated entry type must be the same as the original entry type") }.encode(),
                                             ),
                                         );
                                     }
@@ -786,6 +1031,38 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 Ok(result)
                             }
                         }
+                        EntryTypes::RegisteredLobby(registered_lobby) => {
+                            let result = validate_create_registered_lobby(
+                                EntryCreationAction::Update(action.clone()),
+                                registered_lobby.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_registered_lobby: Option<
+                                    RegisteredLobby,
+                                > = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_registered_lobby = match original_registered_lobby {
+                                    Some(registered_lobby) => registered_lobby,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                CondenserError::MalformedOp { reason: String::from("The updated entry type must be the same as the original entry type") }.encode(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_registered_lobby(
+                                    action,
+                                    registered_lobby,
+                                    original_action,
+                                    original_registered_lobby,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
                     }
                 }
                 OpRecord::DeleteEntry { original_action_hash, action, .. } => {
@@ -797,8 +1074,7 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         _ => {
                             return Ok(
                                 ValidateCallbackResult::Invalid(
-                                    "Original action for a delete must be a Create or Update action"
-                                        .to_string(),
+                                    CondenserError::MalformedOp { reason: String::from("Original action for a delete must be a Create or Update action") }.encode(),
                                 ),
                             );
                         }
@@ -815,8 +1091,7 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                             if original_action.entry_type().visibility().is_public() {
                                 return Ok(
                                     ValidateCallbackResult::Invalid(
-                                        "Original record for a delete of a public entry must contain an entry"
-                                            .to_string(),
+                                        CondenserError::MalformedOp { reason: String::from("Original record for a delete of a public entry must contain an entry") }.encode(),
                                     ),
                                 );
                             } else {
@@ -825,16 +1100,15 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         }
                     };
                     let original_app_entry = match EntryTypes::deserialize_from_type(
-                        app_entry_type.zome_index.clone(),
-                        app_entry_type.entry_index.clone(),
-                        &entry,
+                        app_entry_type.zome_index,
+                        app_entry_type.entry_index,
+                        entry,
                     )? {
                         Some(app_entry) => app_entry,
                         None => {
                             return Ok(
                                 ValidateCallbackResult::Invalid(
-                                    "Original app entry must be one of the defined entry types for this zome"
-                                        .to_string(),
+                                    CondenserError::MalformedOp { reason: String::from("Original app entry must be one of the defined entry types for this zome") }.encode(),
                                 ),
                             );
                         }
@@ -884,6 +1158,13 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 original_comment_on_reflection,
                             )
                         }
+                        EntryTypes::RegisteredLobby(original_registered_lobby) => {
+                            validate_delete_registered_lobby(
+                                action,
+                                original_action,
+                                original_registered_lobby,
+                            )
+                        }
                     }
                 }
                 OpRecord::CreateLink {
@@ -1006,6 +1287,94 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 tag,
                             )
                         }
+                        LinkTypes::AllParticipants => {
+                            validate_create_link_all_participants(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::AgentToNotifications => {
+                            validate_create_link_agent_to_notifications(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::AgentToReadNotifications => {
+                            validate_create_link_agent_to_read_notifications(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::AgentToAssociations => {
+                            validate_create_link_agent_to_associations(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::AgentToOffers => {
+                            validate_create_link_agent_to_offers(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::AgentToReflections => {
+                            validate_create_link_agent_to_reflections(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::AgentToAnecdotes => {
+                            validate_create_link_agent_to_anecdotes(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::AgentToCommentOnOffers => {
+                            validate_create_link_agent_to_comment_on_offers(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::AgentToCommentOnReflections => {
+                            validate_create_link_agent_to_comment_on_reflections(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::AgentToResonances => {
+                            validate_create_link_agent_to_resonances(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::RegisteredLobbies => {
+                            validate_create_link_registered_lobbies(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
                     }
                 }
                 OpRecord::DeleteLink { original_action_hash, base_address, action } => {
@@ -1015,15 +1384,14 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         _ => {
                             return Ok(
                                 ValidateCallbackResult::Invalid(
-                                    "The action that a DeleteLink deletes must be a CreateLink"
-                                        .to_string(),
+                                    CondenserError::MalformedOp { reason: String::from("The action that a DeleteLink deletes must be a CreateLink") }.encode(),
                                 ),
                             );
                         }
                     };
                     let link_type = match LinkTypes::from_type(
-                        create_link.zome_index.clone(),
-                        create_link.link_type.clone(),
+                        create_link.zome_index,
+                        create_link.link_type,
                     )? {
                         Some(lt) => lt,
                         None => {
@@ -1157,6 +1525,105 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 create_link.tag,
                             )
                         }
+                        LinkTypes::AllParticipants => {
+                            validate_delete_link_all_participants(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::AgentToNotifications => {
+                            validate_delete_link_agent_to_notifications(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::AgentToReadNotifications => {
+                            validate_delete_link_agent_to_read_notifications(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::AgentToAssociations => {
+                            validate_delete_link_agent_to_associations(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::AgentToOffers => {
+                            validate_delete_link_agent_to_offers(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::AgentToReflections => {
+                            validate_delete_link_agent_to_reflections(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::AgentToAnecdotes => {
+                            validate_delete_link_agent_to_anecdotes(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::AgentToCommentOnOffers => {
+                            validate_delete_link_agent_to_comment_on_offers(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::AgentToCommentOnReflections => {
+                            validate_delete_link_agent_to_comment_on_reflections(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::AgentToResonances => {
+                            validate_delete_link_agent_to_resonances(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::RegisteredLobbies => {
+                            validate_delete_link_registered_lobbies(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
                     }
                 }
                 OpRecord::CreatePrivateEntry { .. } => Ok(ValidateCallbackResult::Valid),
@@ -1172,7 +1639,7 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                 _ => Ok(ValidateCallbackResult::Valid),
             }
         }
-        OpType::RegisterAgentActivity(agent_activity) => {
+        FlatOp::RegisterAgentActivity(agent_activity) => {
             match agent_activity {
                 OpActivity::CreateAgent { agent, action } => {
                     let previous_action = must_get_action(action.prev_action)?;
@@ -1183,8 +1650,7 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         _ => {
                             Ok(
                                 ValidateCallbackResult::Invalid(
-                                    "The previous action for a `CreateAgent` action must be an `AgentValidationPkg`"
-                                        .to_string(),
+                                    CondenserError::MalformedOp { reason: String::from("The previous action for a `CreateAgent` action must be an `AgentValidationPkg`") }.encode(),
                                 ),
                             )
                         }
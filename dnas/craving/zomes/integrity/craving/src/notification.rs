@@ -0,0 +1,199 @@
+use hdi::prelude::*;
+use condenser_common::CondenserError;
+use crate::LinkTypes;
+
+/// What a notification is about. Stored in the tag of AgentToNotifications links.
+#[derive(Clone, Serialize, Deserialize, Debug, SerializedBytes, PartialEq)]
+pub enum NotificationKind {
+    /// Someone commented on a reflection of the recipient. The link targets the comment.
+    CommentOnReflection,
+    /// Someone commented on an offer of the recipient. The link targets the comment.
+    CommentOnOffer,
+    /// Someone resonated with an entry of the recipient. The link targets the recipient's
+    /// action that created the entry, `resonance_hash` is the sender's EntryToResonator link.
+    Resonance { resonance_hash: ActionHash },
+}
+impl NotificationKind {
+    pub fn to_link_tag(&self) -> ExternResult<LinkTag> {
+        let bytes = SerializedBytes::try_from(self.clone()).map_err(|err| wasm_error!(err))?;
+        Ok(LinkTag::new(bytes.bytes().clone()))
+    }
+    pub fn from_link_tag(tag: LinkTag) -> ExternResult<NotificationKind> {
+        NotificationKind::try_from(SerializedBytes::from(UnsafeBytes::from(tag.into_inner())))
+            .map_err(|err| wasm_error!(err))
+    }
+}
+pub fn validate_create_link_agent_to_notifications(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let recipient = AgentPubKey::try_from(base_address).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
+    let kind = match NotificationKind::from_link_tag(tag) {
+        Ok(kind) => kind,
+        Err(_) => {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    CondenserError::InvalidLinkTag { expected: String::from("NotificationKind") }.encode(),
+                ),
+            );
+        }
+    };
+    let action_hash = ActionHash::try_from(target_address).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
+    let record = must_get_valid_record(action_hash)?;
+    let parent_author = match kind {
+        NotificationKind::CommentOnReflection => {
+            let comment_on_reflection: crate::CommentOnReflection = record
+                .entry()
+                .to_app_option()
+                .map_err(|e| wasm_error!(e))?
+                .ok_or(
+                    wasm_error!(
+                        WasmErrorInner::Guest(CondenserError::MissingEntry.encode())
+                    ),
+                )?;
+            if record.action().author() != &action.author {
+                return Ok(
+                    ValidateCallbackResult::Invalid(
+                        CondenserError::NotAuthor { what: String::from("comment") }.encode(),
+                    ),
+                );
+            }
+            must_get_valid_record(comment_on_reflection.reflection_hash)?
+                .action()
+                .author()
+                .clone()
+        }
+        NotificationKind::CommentOnOffer => {
+            let comment_on_offer: crate::CommentOnOffer = record
+                .entry()
+                .to_app_option()
+                .map_err(|e| wasm_error!(e))?
+                .ok_or(
+                    wasm_error!(
+                        WasmErrorInner::Guest(CondenserError::MissingEntry.encode())
+                    ),
+                )?;
+            if record.action().author() != &action.author {
+                return Ok(
+                    ValidateCallbackResult::Invalid(
+                        CondenserError::NotAuthor { what: String::from("comment") }.encode(),
+                    ),
+                );
+            }
+            must_get_valid_record(comment_on_offer.offer_hash)?
+                .action()
+                .author()
+                .clone()
+        }
+        NotificationKind::Resonance { resonance_hash } => {
+            let entry_hash = record.action().entry_hash().cloned().map(AnyLinkableHash::from);
+            let is_senders_resonance = match must_get_action(resonance_hash)?.action() {
+                Action::CreateLink(create_link) => {
+                    create_link.author == action.author
+                        && Some(&create_link.base_address) == entry_hash.as_ref()
+                        && matches!(
+                            LinkTypes::from_type(create_link.zome_index, create_link.link_type)?,
+                            Some(LinkTypes::EntryToResonator)
+                        )
+                }
+                _ => false,
+            };
+            if !is_senders_resonance {
+                return Ok(
+                    ValidateCallbackResult::Invalid(
+                        CondenserError::InvalidLinkTag { expected: String::from("resonance of the sender with the notified entry") }.encode(),
+                    ),
+                );
+            }
+            record.action().author().clone()
+        }
+    };
+    if parent_author != recipient {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("notified entry") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_agent_to_notifications(
+    action: DeleteLink,
+    original_action: CreateLink,
+    base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let recipient = AgentPubKey::try_from(base).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
+    if action.author != recipient && action.author != original_action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("notification") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_create_link_agent_to_read_notifications(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let base_pubkey = AgentPubKey::try_from(base_address).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
+    if base_pubkey != action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("notification") }.encode(),
+            ),
+        );
+    }
+    let action_hash = ActionHash::try_from(target_address).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
+    let notification_action = must_get_action(action_hash)?;
+    let notification_link = match notification_action.action() {
+        Action::CreateLink(create_link) => create_link.clone(),
+        _ => {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    CondenserError::WrongType { expected: String::from("AgentToNotifications link") }.encode(),
+                ),
+            );
+        }
+    };
+    match LinkTypes::from_type(notification_link.zome_index, notification_link.link_type)? {
+        Some(LinkTypes::AgentToNotifications) => (),
+        _ => {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    CondenserError::WrongType { expected: String::from("AgentToNotifications link") }.encode(),
+                ),
+            );
+        }
+    }
+    if notification_link.base_address != action.author.into() {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("notification") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_agent_to_read_notifications(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("read notification link") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
@@ -1,4 +1,5 @@
 use hdi::prelude::*;
+use condenser_common::CondenserError;
 
 use crate::types::{CravingDnaProperties, DEFAULT_MAX_OFFER_CHARS};
 #[hdk_entry_helper]
@@ -13,17 +14,17 @@ pub fn validate_create_offer(
 ) -> ExternResult<ValidateCallbackResult> {
     let dna_info = dna_info()?;
     let craving_dna_properties = CravingDnaProperties::try_from(dna_info.modifiers.properties)
-        .map_err(|err| wasm_error!(WasmErrorInner::Guest(format!("Failed to convert dna properties into CravingDnaProperties during validation of offer creation: {}", err.to_string()))))?;
+        .map_err(|err| wasm_error!(WasmErrorInner::Guest(CondenserError::InvalidDnaProperties { reason: err.to_string() }.encode())))?;
 
     match craving_dna_properties.max_offer_chars {
         Some(max) => {
-            if offer.offer.len() > max {
-                return Ok(ValidateCallbackResult::Invalid(format!("Offer is longer than allowed. Max characters: {}", max)));
+            if offer.offer.chars().count() > max {
+                return Ok(ValidateCallbackResult::Invalid(CondenserError::TooLong { field: String::from("offer"), max, actual: offer.offer.chars().count() }.encode()));
             }
         },
         None => {
-            if offer.offer.len() > DEFAULT_MAX_OFFER_CHARS {
-                return Ok(ValidateCallbackResult::Invalid(format!("Offer is longer than allowed. Max characters: {} (default value)", DEFAULT_MAX_OFFER_CHARS)));
+            if offer.offer.chars().count() > DEFAULT_MAX_OFFER_CHARS {
+                return Ok(ValidateCallbackResult::Invalid(CondenserError::TooLong { field: String::from("offer"), max: DEFAULT_MAX_OFFER_CHARS, actual: offer.offer.chars().count() }.encode()));
             }
         },
     }
@@ -58,7 +59,7 @@ pub fn validate_create_link_offer_updates(
         .map_err(|e| wasm_error!(e))?
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+                WasmErrorInner::Guest(CondenserError::MissingEntry.encode())
             ),
         )?;
     let action_hash = ActionHash::try_from(target_address).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
@@ -69,7 +70,7 @@ pub fn validate_create_link_offer_updates(
         .map_err(|e| wasm_error!(e))?
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+                WasmErrorInner::Guest(CondenserError::MissingEntry.encode())
             ),
         )?;
     Ok(ValidateCallbackResult::Valid)
@@ -83,7 +84,7 @@ pub fn validate_delete_link_offer_updates(
 ) -> ExternResult<ValidateCallbackResult> {
     Ok(
         ValidateCallbackResult::Invalid(
-            String::from("OfferUpdates links cannot be deleted"),
+            CondenserError::NotDeletable { what: String::from("OfferUpdates link") }.encode(),
         ),
     )
 }
@@ -101,7 +102,7 @@ pub fn validate_create_link_all_offers(
         .map_err(|e| wasm_error!(e))?
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+                WasmErrorInner::Guest(CondenserError::MissingEntry.encode())
             ),
         )?;
     Ok(ValidateCallbackResult::Valid)
@@ -115,7 +116,7 @@ pub fn validate_delete_link_all_offers(
 ) -> ExternResult<ValidateCallbackResult> {
     Ok(
         ValidateCallbackResult::Invalid(
-            String::from("AllOffers links cannot be deleted"),
+            CondenserError::NotDeletable { what: String::from("AllOffers link") }.encode(),
         ),
     )
 }
@@ -0,0 +1,31 @@
+use hdi::prelude::*;
+use condenser_common::CondenserError;
+pub fn validate_create_link_all_participants(
+    action: CreateLink,
+    _base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let target_pubkey = AgentPubKey::try_from(target_address).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
+    if target_pubkey != action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("participant link") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_all_participants(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            CondenserError::NotDeletable { what: String::from("AllParticipants link") }.encode(),
+        ),
+    )
+}
@@ -1,4 +1,5 @@
 use hdi::prelude::*;
+use condenser_common::CondenserError;
 use crate::types::*;
 
 #[hdk_entry_helper]
@@ -13,24 +14,24 @@ pub fn validate_create_reflection(
 ) -> ExternResult<ValidateCallbackResult> {
     let dna_info = dna_info()?;
     let craving_dna_properties = CravingDnaProperties::try_from(dna_info.modifiers.properties)
-        .map_err(|err| wasm_error!(WasmErrorInner::Guest(format!("Failed to convert dna properties into CravingDnaProperties during validation of reflection creation: {}", err.to_string()))))?;
+        .map_err(|err| wasm_error!(WasmErrorInner::Guest(CondenserError::InvalidDnaProperties { reason: err.to_string() }.encode())))?;
 
     match craving_dna_properties.max_reflection_chars {
         Some(max) => {
-            if reflection.reflection.len() > max {
-                return Ok(ValidateCallbackResult::Invalid(format!("Reflection is longer than allowed. Max characters: {}", max)));
+            if reflection.reflection.chars().count() > max {
+                return Ok(ValidateCallbackResult::Invalid(CondenserError::TooLong { field: String::from("reflection"), max, actual: reflection.reflection.chars().count() }.encode()));
             }
         },
         None => {
-            if reflection.reflection.len() > DEFAULT_MAX_REFLECTION_CHARS {
-                return Ok(ValidateCallbackResult::Invalid(format!("Reflection is longer than allowed. Max characters: {} (default value)", DEFAULT_MAX_REFLECTION_CHARS)));
+            if reflection.reflection.chars().count() > DEFAULT_MAX_REFLECTION_CHARS {
+                return Ok(ValidateCallbackResult::Invalid(CondenserError::TooLong { field: String::from("reflection"), max: DEFAULT_MAX_REFLECTION_CHARS, actual: reflection.reflection.chars().count() }.encode()));
             }
         },
     }
 
     // max title length is hardcoded at 80 chars
-    if reflection.title.len() > 80 {
-        return Ok(ValidateCallbackResult::Invalid(format!("Reflection title is longer than allowed. Max characters: 80")));
+    if reflection.title.chars().count() > 80 {
+        return Ok(ValidateCallbackResult::Invalid(CondenserError::TooLong { field: String::from("title"), max: 80, actual: reflection.title.chars().count() }.encode()));
     }
 
     Ok(ValidateCallbackResult::Valid)
@@ -64,7 +65,7 @@ pub fn validate_create_link_reflection_updates(
         .map_err(|e| wasm_error!(e))?
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+                WasmErrorInner::Guest(CondenserError::MissingEntry.encode())
             ),
         )?;
     let action_hash = ActionHash::try_from(target_address).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
@@ -75,7 +76,7 @@ pub fn validate_create_link_reflection_updates(
         .map_err(|e| wasm_error!(e))?
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+                WasmErrorInner::Guest(CondenserError::MissingEntry.encode())
             ),
         )?;
     Ok(ValidateCallbackResult::Valid)
@@ -89,7 +90,7 @@ pub fn validate_delete_link_reflection_updates(
 ) -> ExternResult<ValidateCallbackResult> {
     Ok(
         ValidateCallbackResult::Invalid(
-            String::from("ReflectionUpdates links cannot be deleted"),
+            CondenserError::NotDeletable { what: String::from("ReflectionUpdates link") }.encode(),
         ),
     )
 }
@@ -107,7 +108,7 @@ pub fn validate_create_link_all_reflections(
         .map_err(|e| wasm_error!(e))?
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+                WasmErrorInner::Guest(CondenserError::MissingEntry.encode())
             ),
         )?;
     Ok(ValidateCallbackResult::Valid)
@@ -121,7 +122,7 @@ pub fn validate_delete_link_all_reflections(
 ) -> ExternResult<ValidateCallbackResult> {
     Ok(
         ValidateCallbackResult::Invalid(
-            String::from("AllReflections links cannot be deleted"),
+            CondenserError::NotDeletable { what: String::from("AllReflections link") }.encode(),
         ),
     )
 }
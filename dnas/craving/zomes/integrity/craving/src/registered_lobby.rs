@@ -0,0 +1,80 @@
+use hdi::prelude::*;
+use condenser_common::{CondenserError, LobbyRegistration};
+/// A lobby this craving is listed in. The lobby itself lives in another DNA, so the
+/// registration can't be checked against it here. It is stored as an entry rather than
+/// in the link tag since the lobby properties can exceed the size limit of link tags.
+#[hdk_entry_helper]
+#[derive(Clone)]
+pub struct RegisteredLobby {
+    pub registration: LobbyRegistration,
+}
+pub fn validate_create_registered_lobby(
+    _action: EntryCreationAction,
+    _registered_lobby: RegisteredLobby,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_registered_lobby(
+    _action: Update,
+    _registered_lobby: RegisteredLobby,
+    _original_action: EntryCreationAction,
+    _original_registered_lobby: RegisteredLobby,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(CondenserError::NotUpdatable { what: String::from("RegisteredLobby") }.encode()))
+}
+pub fn validate_delete_registered_lobby(
+    action: Delete,
+    original_action: EntryCreationAction,
+    _original_registered_lobby: RegisteredLobby,
+) -> ExternResult<ValidateCallbackResult> {
+    if &action.author != original_action.author() {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("RegisteredLobby") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+/// Links from the registered_lobbies path to a RegisteredLobby of the link author.
+pub fn validate_create_link_registered_lobbies(
+    action: CreateLink,
+    _base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = ActionHash::try_from(target_address).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
+    let record = must_get_valid_record(action_hash)?;
+    let registered_lobby: Option<RegisteredLobby> = record.entry().to_app_option().ok().flatten();
+    if registered_lobby.is_none() {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::WrongType { expected: String::from("RegisteredLobby") }.encode(),
+            ),
+        );
+    }
+    if record.action().author() != &action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("RegisteredLobby") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_registered_lobbies(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("RegisteredLobbies link") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
@@ -1,4 +1,5 @@
 use hdi::prelude::*;
+use condenser_common::CondenserError;
 
 pub const DEFAULT_MAX_ASSOCIATION_CHARS: usize = 70;
 pub const DEFAULT_MAX_REFLECTION_CHARS: usize = 4000;
@@ -14,4 +15,17 @@ pub struct CravingDnaProperties {
     pub max_reflection_chars: Option<usize>,
     pub max_offer_chars: Option<usize>,
     pub max_anecdote_chars: Option<usize>,
+    /// DNA hash of the lobby the craving was created in. If the lobby is invitation-only,
+    /// i.e. lobby_creator is set as well, only members of the lobby can join the craving.
+    #[serde(default)]
+    pub lobby_dna_hash: Option<DnaHash>,
+    /// Creator of the lobby, the root of its invitation chains
+    #[serde(default)]
+    pub lobby_creator: Option<AgentPubKey>,
+}
+impl CravingDnaProperties {
+    pub fn get() -> ExternResult<CravingDnaProperties> {
+        CravingDnaProperties::try_from(dna_info()?.modifiers.properties)
+            .map_err(|err| wasm_error!(WasmErrorInner::Guest(CondenserError::InvalidDnaProperties { reason: err.to_string() }.encode())))
+    }
 }
\ No newline at end of file
@@ -1,17 +1,52 @@
+use std::collections::HashMap;
+
 use hdk::prelude::*;
+use hdk::prelude::holo_hash::DnaHash;
 use cravings_integrity::*;
+use crate::dna_recipe::canonical_dna_recipe;
+use crate::recipe_status::get_recipe_states;
+/// The states of the recipes that are listed in the lobby, i.e. neither archived nor superseded.
+pub const LISTED_RECIPE_STATES: [RecipeState; 2] = [RecipeState::Active, RecipeState::Closed];
+/// Gets all craving recipes, or only the ones in one of the given states. Pass
+/// `LISTED_RECIPE_STATES` to leave out archived and superseded recipes. If several recipes
+/// claim the same resulting dna hash, only the canonical one is returned.
 #[hdk_extern]
-pub fn get_all_craving_recipes(_: ()) -> ExternResult<Vec<Record>> {
+pub fn get_all_craving_recipes(states: Option<Vec<RecipeState>>) -> ExternResult<Vec<Record>> {
+    let records = get_all_craving_recipes_in_any_state()?;
+    let states = match states {
+        Some(states) => states,
+        None => return Ok(records),
+    };
+    let recipe_states = get_recipe_states(records.iter().map(|record| record.action_address().clone()).collect())?;
+    Ok(
+        records
+            .into_iter()
+            .zip(recipe_states)
+            .filter(|(_, recipe_state)| states.contains(recipe_state))
+            .map(|(record, _)| record)
+            .collect(),
+    )
+}
+/// Gets all craving recipes regardless of their status.
+pub fn get_all_craving_recipes_in_any_state() -> ExternResult<Vec<Record>> {
     let path = Path::from("all_craving_recipes");
     let links = get_links(path.path_entry_hash()?, LinkTypes::AllCravingRecipes, None)?;
     let get_input: Vec<GetInput> = links
         .into_iter()
-        .map(|link| GetInput::new(
-            link.target.into_any_dht_hash().unwrap(),
-            GetOptions::default(),
-        ))
+        .filter_map(|link| link.target.into_any_dht_hash())
+        .map(|hash| GetInput::new(hash, GetOptions::default()))
         .collect();
     let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
-    let records: Vec<Record> = records.into_iter().filter_map(|r| r).collect();
+
+    let mut records_by_dna_hash: HashMap<DnaHash, Vec<Record>> = HashMap::new();
+    for record in records.into_iter().flatten() {
+        let dna_recipe: DnaRecipe = match record.entry().to_app_option().map_err(|e| wasm_error!(e))? {
+            Some(dna_recipe) => dna_recipe,
+            None => continue,
+        };
+        records_by_dna_hash.entry(dna_recipe.resulting_dna_hash).or_default().push(record);
+    }
+    let mut records: Vec<Record> = records_by_dna_hash.into_values().filter_map(canonical_dna_recipe).collect();
+    records.sort_by_key(|record| record.action().timestamp());
     Ok(records)
 }
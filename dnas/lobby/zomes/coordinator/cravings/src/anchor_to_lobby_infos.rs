@@ -1,4 +1,5 @@
 use hdk::prelude::*;
+use condenser_common::CondenserError;
 use cravings_integrity::*;
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -6,8 +7,16 @@ pub struct AddLobbyInfoForAnchorInput {
     anchor: AgentPubKey,
     lobby_info_hash: ActionHash,
 }
+/// Links a LobbyInfo from my own public key, the only agent anchor I may link from.
 #[hdk_extern]
 pub fn add_lobby_info_for_anchor(input: AddLobbyInfoForAnchorInput) -> ExternResult<()> {
+    if input.anchor != agent_info()?.agent_initial_pubkey {
+        return Err(
+            wasm_error!(
+                WasmErrorInner::Guest(CondenserError::NotAuthor { what: String::from("AnchorToLobbyInfo link") }.encode())
+            ),
+        );
+    }
     create_link(input.anchor.clone(), input.lobby_info_hash.clone(), LinkTypes::AnchorToLobbyInfo, ())?;
 
 
@@ -20,13 +29,14 @@ pub fn get_lobby_infos_for_anchor(anchor: AgentPubKey) -> ExternResult<Vec<Recor
 
     let get_input: Vec<GetInput> = links
         .into_iter()
-        .map(|link| GetInput::new(link.target.into_any_dht_hash().unwrap(), GetOptions::default()))
+        .filter_map(|link| link.target.into_any_dht_hash())
+        .map(|hash| GetInput::new(hash, GetOptions::default()))
         .collect();
 
     // Get the records to filter out the deleted ones
     let records: Vec<Record> = HDK.with(|hdk| hdk.borrow().get(get_input))?
         .into_iter()
-        .filter_map(|r| r)
+        .flatten()
         .collect();
 
     Ok(records)
@@ -0,0 +1,126 @@
+use hdk::prelude::*;
+use condenser_common::CondenserError;
+use cravings_integrity::*;
+use crate::lobby_members::get_other_lobby_members;
+use crate::RemoteSignal;
+
+const ALL_ANNOUNCEMENTS: &str = "all_announcements";
+
+#[hdk_extern]
+pub fn create_announcement(announcement: Announcement) -> ExternResult<Record> {
+    let announcement_hash = create_entry(&EntryTypes::Announcement(announcement))?;
+    create_link(
+        Path::from(ALL_ANNOUNCEMENTS).path_entry_hash()?,
+        announcement_hash.clone(),
+        LinkTypes::AllAnnouncements,
+        (),
+    )?;
+    get(announcement_hash, GetOptions::default())?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(CondenserError::NotFound { what: String::from("Announcement") }.encode())
+            ),
+        )
+}
+
+/// Gets the latest version of the announcement, None if it has been deleted.
+#[hdk_extern]
+pub fn get_announcement(original_announcement_hash: ActionHash) -> ExternResult<Option<Record>> {
+    match get_details(original_announcement_hash.clone(), GetOptions::default())? {
+        Some(Details::Record(details)) if details.deletes.is_empty() => {}
+        _ => return Ok(None),
+    }
+    let latest_link = get_links(original_announcement_hash.clone(), LinkTypes::AnnouncementUpdates, None)?
+        .into_iter()
+        .max_by_key(|link| link.timestamp);
+    let latest_announcement_hash = match latest_link.and_then(|link| link.target.into_action_hash()) {
+        Some(latest_announcement_hash) => latest_announcement_hash,
+        None => original_announcement_hash,
+    };
+    get(latest_announcement_hash, GetOptions::default())
+}
+
+/// Gets the latest versions of all announcements that have neither been deleted nor
+/// expired yet, newest first.
+#[hdk_extern]
+pub fn get_all_announcements(_: ()) -> ExternResult<Vec<Record>> {
+    let links = get_links(
+        Path::from(ALL_ANNOUNCEMENTS).path_entry_hash()?,
+        LinkTypes::AllAnnouncements,
+        None,
+    )?;
+    let now = sys_time()?;
+    let mut announcements: Vec<(Timestamp, Record)> = Vec::new();
+    for link in links {
+        let original_announcement_hash = match link.target.into_action_hash() {
+            Some(original_announcement_hash) => original_announcement_hash,
+            None => continue,
+        };
+        let record = match get_announcement(original_announcement_hash)? {
+            Some(record) => record,
+            None => continue,
+        };
+        let announcement: Announcement = match record.entry().to_app_option().map_err(|e| wasm_error!(e))? {
+            Some(announcement) => announcement,
+            None => continue,
+        };
+        if announcement.expires_at.is_some_and(|expires_at| expires_at <= now) {
+            continue;
+        }
+        announcements.push((link.timestamp, record));
+    }
+    announcements.sort_by_key(|(posted_at, _)| std::cmp::Reverse(*posted_at));
+    Ok(announcements.into_iter().map(|(_, record)| record).collect())
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpdateAnnouncementInput {
+    pub original_announcement_hash: ActionHash,
+    pub previous_announcement_hash: ActionHash,
+    pub updated_announcement: Announcement,
+}
+
+#[hdk_extern]
+pub fn update_announcement(input: UpdateAnnouncementInput) -> ExternResult<Record> {
+    let updated_announcement_hash = update_entry(
+        input.previous_announcement_hash,
+        &input.updated_announcement,
+    )?;
+    create_link(
+        input.original_announcement_hash,
+        updated_announcement_hash.clone(),
+        LinkTypes::AnnouncementUpdates,
+        (),
+    )?;
+    get(updated_announcement_hash, GetOptions::default())?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(CondenserError::NotFound { what: String::from("Announcement") }.encode())
+            ),
+        )
+}
+
+/// Deletes the announcement and removes it from the list of all announcements.
+#[hdk_extern]
+pub fn delete_announcement(original_announcement_hash: ActionHash) -> ExternResult<ActionHash> {
+    let links = get_links(
+        Path::from(ALL_ANNOUNCEMENTS).path_entry_hash()?,
+        LinkTypes::AllAnnouncements,
+        None,
+    )?;
+    for link in links {
+        if link.target.clone().into_action_hash().as_ref() == Some(&original_announcement_hash) {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+    delete_entry(original_announcement_hash)
+}
+
+/// Tells the other members of the lobby about a new announcement. Called from `post_commit`.
+pub fn broadcast_announcement(record: Record) -> ExternResult<()> {
+    let other_members = get_other_lobby_members()?;
+    if other_members.is_empty() {
+        return Ok(());
+    }
+    remote_signal(RemoteSignal::AnnouncementPosted { record }, other_members)
+}
@@ -0,0 +1,115 @@
+use hdk::prelude::*;
+use hdk::prelude::holo_hash::DnaHash;
+use condenser_common::CondenserError;
+use cravings_integrity::*;
+use crate::all_craving_recipes::get_all_craving_recipes;
+use crate::dna_recipe::get_dna_recipe_for_dna_hash;
+use crate::joined_recipes::get_recipe_joiners;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PublishCravingPreviewInput {
+    /// The craving the preview is about, it needs to be listed in this lobby
+    pub craving_dna_hash: DnaHash,
+    pub top_associations: Vec<PreviewAssociation>,
+    pub association_count: usize,
+    pub offer_count: usize,
+    pub reflection_count: usize,
+    pub anecdote_count: usize,
+    pub participant_count: usize,
+    pub last_activity: Option<Timestamp>,
+}
+/// Publishes a snapshot of a craving. Called periodically by the craving's scheduled
+/// `publish_craving_preview` via a cross-role call. Returns None if the latest preview
+/// is still up to date, otherwise replaces our own earlier previews of the craving.
+#[hdk_extern]
+pub fn publish_craving_preview(input: PublishCravingPreviewInput) -> ExternResult<Option<Record>> {
+    let dna_recipe_record = get_dna_recipe_for_dna_hash(input.craving_dna_hash)?.ok_or(
+        wasm_error!(
+            WasmErrorInner::Guest(CondenserError::NotFound { what: String::from("DnaRecipe") }.encode())
+        ),
+    )?;
+    let dna_recipe_hash = dna_recipe_record.action_address().clone();
+    let craving_preview = CravingPreview {
+        dna_recipe_hash: dna_recipe_hash.clone(),
+        top_associations: input.top_associations,
+        association_count: input.association_count,
+        offer_count: input.offer_count,
+        reflection_count: input.reflection_count,
+        anecdote_count: input.anecdote_count,
+        participant_count: input.participant_count,
+        last_activity: input.last_activity,
+    };
+    let latest_preview = get_craving_preview(dna_recipe_hash.clone())?
+        .and_then(|record| record.entry().to_app_option::<CravingPreview>().ok().flatten());
+    if latest_preview.as_ref() == Some(&craving_preview) {
+        return Ok(None);
+    }
+
+    let my_pubkey = agent_info()?.agent_initial_pubkey;
+    let my_links: Vec<Link> = get_links(dna_recipe_hash.clone(), LinkTypes::DnaRecipeToCravingPreviews, None)?
+        .into_iter()
+        .filter(|link| link.author == my_pubkey)
+        .collect();
+    let craving_preview_hash = create_entry(&EntryTypes::CravingPreview(craving_preview))?;
+    create_link(
+        dna_recipe_hash,
+        craving_preview_hash.clone(),
+        LinkTypes::DnaRecipeToCravingPreviews,
+        (),
+    )?;
+    for link in my_links {
+        delete_link(link.create_link_hash)?;
+        if let Some(old_preview_hash) = link.target.into_action_hash() {
+            delete_entry(old_preview_hash)?;
+        }
+    }
+    get(craving_preview_hash, GetOptions::default())?
+        .map(Some)
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(CondenserError::NotFound { what: String::from("CravingPreview") }.encode())
+            ),
+        )
+}
+
+/// Gets the most recently published preview of the craving, if any. Anyone in the lobby
+/// can publish a preview, so previews by members who joined the craving win over newer
+/// ones by members who didn't. The author of the preview is in its action.
+#[hdk_extern]
+pub fn get_craving_preview(dna_recipe_hash: ActionHash) -> ExternResult<Option<Record>> {
+    let joiners = get_recipe_joiners(dna_recipe_hash.clone())?;
+    let latest_link = get_links(dna_recipe_hash, LinkTypes::DnaRecipeToCravingPreviews, None)?
+        .into_iter()
+        .max_by(|a, b| {
+            joiners
+                .contains(&a.author)
+                .cmp(&joiners.contains(&b.author))
+                .then_with(|| a.timestamp.cmp(&b.timestamp))
+                .then_with(|| a.create_link_hash.cmp(&b.create_link_hash))
+        });
+    match latest_link.and_then(|link| link.target.into_action_hash()) {
+        Some(craving_preview_hash) => get(craving_preview_hash, GetOptions::default()),
+        None => Ok(None),
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CravingRecipeWithPreview {
+    pub record: Record,
+    /// None if no participant of the craving has published a preview yet
+    pub preview: Option<Record>,
+}
+/// Like `get_all_craving_recipes`, together with the latest preview of each craving.
+#[hdk_extern]
+pub fn get_all_craving_recipes_with_previews(
+    states: Option<Vec<RecipeState>>,
+) -> ExternResult<Vec<CravingRecipeWithPreview>> {
+    let mut recipes = Vec::new();
+    for record in get_all_craving_recipes(states)? {
+        recipes.push(CravingRecipeWithPreview {
+            preview: get_craving_preview(record.action_address().clone())?,
+            record,
+        });
+    }
+    Ok(recipes)
+}
@@ -0,0 +1,173 @@
+use hdk::prelude::*;
+use condenser_common::CondenserError;
+use cravings_integrity::*;
+use crate::dna_recipe::{create_dna_recipe, CreateDnaRecipeInput};
+
+const ALL_CRAVING_PROPOSALS: &str = "all_craving_proposals";
+
+#[hdk_extern]
+pub fn create_craving_proposal(craving_proposal: CravingProposal) -> ExternResult<Record> {
+    let craving_proposal_hash = create_entry(&EntryTypes::CravingProposal(craving_proposal))?;
+    create_link(
+        Path::from(ALL_CRAVING_PROPOSALS).path_entry_hash()?,
+        craving_proposal_hash.clone(),
+        LinkTypes::AllCravingProposals,
+        (),
+    )?;
+    get(craving_proposal_hash, GetOptions::default())?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(CondenserError::NotFound { what: String::from("CravingProposal") }.encode())
+            ),
+        )
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CravingProposalWithResonance {
+    pub record: Record,
+    pub resonators: Vec<AgentPubKey>,
+    /// The DnaRecipe the proposal has been promoted to, if any
+    pub dna_recipe_hash: Option<ActionHash>,
+}
+
+/// Gets all proposals that haven't been deleted, newest first.
+#[hdk_extern]
+pub fn get_all_craving_proposals(_: ()) -> ExternResult<Vec<CravingProposalWithResonance>> {
+    let mut links = get_links(
+        Path::from(ALL_CRAVING_PROPOSALS).path_entry_hash()?,
+        LinkTypes::AllCravingProposals,
+        None,
+    )?;
+    links.sort_by_key(|link| std::cmp::Reverse(link.timestamp));
+    let mut proposals: Vec<CravingProposalWithResonance> = Vec::new();
+    for link in links {
+        let craving_proposal_hash = match link.target.into_action_hash() {
+            Some(craving_proposal_hash) => craving_proposal_hash,
+            None => continue,
+        };
+        let record = match get(craving_proposal_hash.clone(), GetOptions::default())? {
+            Some(record) => record,
+            None => continue,
+        };
+        proposals.push(CravingProposalWithResonance {
+            record,
+            resonators: get_craving_proposal_resonators(craving_proposal_hash.clone())?,
+            dna_recipe_hash: get_promoted_dna_recipe(craving_proposal_hash)?,
+        });
+    }
+    Ok(proposals)
+}
+
+/// The earliest resonance of each member that still resonates with the proposal.
+fn get_resonance_links(craving_proposal_hash: ActionHash) -> ExternResult<Vec<Link>> {
+    let mut links = get_links(craving_proposal_hash, LinkTypes::CravingProposalToResonators, None)?;
+    links.sort_by(|a, b| {
+        a.timestamp
+            .cmp(&b.timestamp)
+            .then_with(|| a.create_link_hash.cmp(&b.create_link_hash))
+    });
+    let mut resonance_links: Vec<Link> = Vec::new();
+    for link in links {
+        if !resonance_links.iter().any(|l| l.author == link.author) {
+            resonance_links.push(link);
+        }
+    }
+    Ok(resonance_links)
+}
+
+#[hdk_extern]
+pub fn get_craving_proposal_resonators(craving_proposal_hash: ActionHash) -> ExternResult<Vec<AgentPubKey>> {
+    Ok(
+        get_resonance_links(craving_proposal_hash)?
+            .into_iter()
+            .map(|link| link.author)
+            .collect(),
+    )
+}
+
+#[hdk_extern]
+pub fn resonate_with_craving_proposal(craving_proposal_hash: ActionHash) -> ExternResult<()> {
+    let my_pubkey = agent_info()?.agent_initial_pubkey;
+    if get_craving_proposal_resonators(craving_proposal_hash.clone())?.contains(&my_pubkey) {
+        return Ok(());
+    }
+    create_link(craving_proposal_hash, my_pubkey, LinkTypes::CravingProposalToResonators, ())?;
+    Ok(())
+}
+
+#[hdk_extern]
+pub fn unresonate_with_craving_proposal(craving_proposal_hash: ActionHash) -> ExternResult<()> {
+    let my_pubkey = agent_info()?.agent_initial_pubkey;
+    let links = get_links(craving_proposal_hash, LinkTypes::CravingProposalToResonators, None)?;
+    for link in links {
+        if link.author == my_pubkey {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+    Ok(())
+}
+
+/// Gets the DnaRecipe the proposal has been promoted to. If it has been promoted
+/// concurrently, the earliest promotion wins.
+#[hdk_extern]
+pub fn get_promoted_dna_recipe(craving_proposal_hash: ActionHash) -> ExternResult<Option<ActionHash>> {
+    let mut links = get_links(craving_proposal_hash, LinkTypes::CravingProposalToDnaRecipe, None)?;
+    links.sort_by(|a, b| {
+        a.timestamp
+            .cmp(&b.timestamp)
+            .then_with(|| a.create_link_hash.cmp(&b.create_link_hash))
+    });
+    Ok(links.into_iter().find_map(|link| link.target.into_action_hash()))
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PromoteCravingProposalInput {
+    pub craving_proposal_hash: ActionHash,
+    /// The recipe of the craving, its properties need to match the proposal
+    pub dna_recipe: DnaRecipe,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// Creates the DnaRecipe of a proposal that enough members resonated with.
+#[hdk_extern]
+pub fn promote_craving_proposal(input: PromoteCravingProposalInput) -> ExternResult<Record> {
+    if get_promoted_dna_recipe(input.craving_proposal_hash.clone())?.is_some() {
+        return Err(
+            wasm_error!(
+                WasmErrorInner::Guest(CondenserError::AlreadyExists { what: String::from("DnaRecipe for the CravingProposal") }.encode())
+            ),
+        );
+    }
+    let threshold = LobbyDnaProperties::get()?.proposal_threshold();
+    // only the earliest resonances that reach the threshold go into the tag
+    let resonance_hashes: Vec<ActionHash> = get_resonance_links(input.craving_proposal_hash.clone())?
+        .into_iter()
+        .take(threshold)
+        .map(|link| link.create_link_hash)
+        .collect();
+    if resonance_hashes.len() < threshold {
+        return Err(
+            wasm_error!(
+                WasmErrorInner::Guest(
+                    CondenserError::NotAllowed {
+                        reason: format!("the proposal needs {} resonators but has {}", threshold, resonance_hashes.len()),
+                    }.encode(),
+                )
+            ),
+        );
+    }
+    let tag = SerializedBytes::try_from(CravingProposalPromotion { resonance_hashes })
+        .map_err(|err| wasm_error!(err))?;
+    let record = create_dna_recipe(CreateDnaRecipeInput {
+        dna_recipe: input.dna_recipe,
+        tags: input.tags,
+    })?;
+    create_link(
+        input.craving_proposal_hash,
+        record.action_address().clone(),
+        LinkTypes::CravingProposalToDnaRecipe,
+        LinkTag::new(tag.bytes().clone()),
+    )?;
+    Ok(record)
+}
@@ -0,0 +1,185 @@
+use std::collections::{BTreeSet, HashSet};
+
+use hdk::prelude::*;
+use hdk::hash_path::path::Component;
+use condenser_common::CondenserError;
+use cravings_integrity::*;
+use crate::all_craving_recipes::{get_all_craving_recipes, LISTED_RECIPE_STATES};
+use crate::joined_recipes::get_recipe_join_count;
+
+const DEFAULT_PAGE_SIZE: usize = 20;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default)]
+pub enum RecipeSort {
+    #[default]
+    Newest,
+    Oldest,
+    /// Most members that installed the craving first
+    MostJoined,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct PageInput {
+    pub sort: Option<RecipeSort>,
+    pub offset: Option<usize>,
+    /// Defaults to 20
+    pub limit: Option<usize>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CravingRecipesPage {
+    pub records: Vec<Record>,
+    /// Number of matching recipes over all pages
+    pub total: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetCravingRecipesByTagInput {
+    pub tag: String,
+    #[serde(default)]
+    pub page: PageInput,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SearchCravingRecipesInput {
+    /// Every whitespace separated term must occur in the title or description (case-insensitive)
+    pub query: String,
+    /// Only search recipes with this tag
+    pub tag: Option<String>,
+    #[serde(default)]
+    pub page: PageInput,
+}
+
+fn craving_tag_path(tag: &str) -> ExternResult<TypedPath> {
+    Path::from(vec![Component::from(CRAVING_TAGS), Component::from(tag)]).typed(LinkTypes::CravingTags)
+}
+
+/// Links the given recipe from the paths of the given tags.
+pub fn tag_craving_recipe(dna_recipe_hash: ActionHash, tags: Vec<String>) -> ExternResult<()> {
+    let tags: BTreeSet<String> = tags.iter().map(|tag| normalize_craving_tag(tag)).collect();
+    if tags.len() > MAX_CRAVING_TAGS_PER_RECIPE {
+        return Err(
+            wasm_error!(
+                WasmErrorInner::Guest(
+                    CondenserError::OutOfRange {
+                        field: String::from("tags"),
+                        min: 0,
+                        max: MAX_CRAVING_TAGS_PER_RECIPE,
+                        actual: tags.len(),
+                    }.encode(),
+                )
+            ),
+        );
+    }
+    for tag in tags {
+        if let Some(err) = validate_craving_tag(&tag) {
+            return Err(wasm_error!(WasmErrorInner::Guest(err.encode())));
+        }
+        let path = craving_tag_path(&tag)?;
+        path.ensure()?;
+        create_link(
+            path.path_entry_hash()?,
+            dna_recipe_hash.clone(),
+            LinkTypes::TagToCravingRecipes,
+            LinkTag::new(tag.into_bytes()),
+        )?;
+    }
+    Ok(())
+}
+
+#[hdk_extern]
+pub fn get_all_craving_tags(_: ()) -> ExternResult<Vec<String>> {
+    let root = Path::from(CRAVING_TAGS).typed(LinkTypes::CravingTags)?;
+    let mut tags: Vec<String> = root
+        .children_paths()?
+        .into_iter()
+        .filter_map(|path| path.leaf().and_then(|component| String::try_from(component).ok()))
+        .collect();
+    tags.sort();
+    Ok(tags)
+}
+
+#[hdk_extern]
+pub fn get_craving_recipes_by_tag(input: GetCravingRecipesByTagInput) -> ExternResult<CravingRecipesPage> {
+    let records = get_craving_recipes_with_tag(&input.tag)?;
+    paginate(records, &input.page)
+}
+
+#[hdk_extern]
+pub fn search_craving_recipes(input: SearchCravingRecipesInput) -> ExternResult<CravingRecipesPage> {
+    let records = match input.tag {
+        Some(tag) => get_craving_recipes_with_tag(&tag)?,
+        None => get_all_craving_recipes(Some(LISTED_RECIPE_STATES.to_vec()))?,
+    };
+    let terms: Vec<String> = input
+        .query
+        .split_whitespace()
+        .map(|term| term.to_lowercase())
+        .collect();
+    let mut matching_records: Vec<Record> = Vec::new();
+    for record in records {
+        let dna_recipe: DnaRecipe = match record.entry().to_app_option().map_err(|e| wasm_error!(e))? {
+            Some(dna_recipe) => dna_recipe,
+            None => continue,
+        };
+        let text = format!(
+            "{} {} {}",
+            dna_recipe.title,
+            dna_recipe.properties.title,
+            dna_recipe.properties.description,
+        )
+        .to_lowercase();
+        if terms.iter().all(|term| text.contains(term.as_str())) {
+            matching_records.push(record);
+        }
+    }
+    paginate(matching_records, &input.page)
+}
+
+/// Gets the recipes with the given tag, leaving out recipes that lost the race for
+/// their resulting dna hash.
+fn get_craving_recipes_with_tag(tag: &str) -> ExternResult<Vec<Record>> {
+    let tag = normalize_craving_tag(tag);
+    if validate_craving_tag(&tag).is_some() {
+        return Ok(vec![]);
+    }
+    let links = get_links(
+        craving_tag_path(&tag)?.path_entry_hash()?,
+        LinkTypes::TagToCravingRecipes,
+        None,
+    )?;
+    let tagged_hashes: HashSet<ActionHash> = links
+        .into_iter()
+        .filter_map(|link| link.target.into_action_hash())
+        .collect();
+    Ok(
+        get_all_craving_recipes(Some(LISTED_RECIPE_STATES.to_vec()))?
+            .into_iter()
+            .filter(|record| tagged_hashes.contains(record.action_address()))
+            .collect(),
+    )
+}
+
+fn paginate(mut records: Vec<Record>, page: &PageInput) -> ExternResult<CravingRecipesPage> {
+    match page.sort.unwrap_or_default() {
+        RecipeSort::Newest => records.sort_by_key(|record| std::cmp::Reverse(record.action().timestamp())),
+        RecipeSort::Oldest => records.sort_by_key(|record| record.action().timestamp()),
+        RecipeSort::MostJoined => {
+            let mut counted: Vec<(usize, Record)> = Vec::new();
+            for record in records {
+                counted.push((get_recipe_join_count(record.action_address().clone())?, record));
+            }
+            counted.sort_by_key(|(join_count, record)| {
+                (std::cmp::Reverse(*join_count), std::cmp::Reverse(record.action().timestamp()))
+            });
+            records = counted.into_iter().map(|(_, record)| record).collect();
+        }
+    }
+    let total = records.len();
+    let records = records
+        .into_iter()
+        .skip(page.offset.unwrap_or(0))
+        .take(page.limit.unwrap_or(DEFAULT_PAGE_SIZE))
+        .collect();
+    Ok(CravingRecipesPage { records, total })
+}
@@ -1,21 +1,44 @@
 use hdk::prelude::*;
+use hdk::prelude::holo_hash::DnaHash;
+use condenser_common::{CondenserError, LobbyRegistration, dna_hash_anchor};
 use cravings_integrity::*;
+use crate::craving_tags::tag_craving_recipe;
+use crate::joined_recipes::join_recipe;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CreateDnaRecipeInput {
+    pub dna_recipe: DnaRecipe,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
 #[hdk_extern]
-pub fn create_dna_recipe(dna_recipe: DnaRecipe) -> ExternResult<Record> {
-    // check that there is not already an entry for this recipe
-    let dna_recipe_entry_hash = hash_entry(&EntryTypes::DnaRecipe(dna_recipe.clone()))?;
-    let maybe_record = get(dna_recipe_entry_hash, GetOptions::default())?;
-    if let Some(_record) = maybe_record {
-        return Err(wasm_error!(WasmErrorInner::Guest(String::from("An entry for this DnaRecipe exists already."))));
+pub fn create_dna_recipe(input: CreateDnaRecipeInput) -> ExternResult<Record> {
+    let record = list_dna_recipe(input)?;
+    // whoever creates a craving has it installed
+    join_recipe(record.action_address().clone())?;
+    Ok(record)
+}
+/// Lists the recipe in this lobby without joining it, e.g. when importing it from another lobby.
+pub fn list_dna_recipe(input: CreateDnaRecipeInput) -> ExternResult<Record> {
+    let dna_recipe = input.dna_recipe;
+    // check that there is not already a recipe for the resulting dna hash
+    if get_dna_recipe_for_dna_hash(dna_recipe.resulting_dna_hash.clone())?.is_some() {
+        return Err(wasm_error!(WasmErrorInner::Guest(CondenserError::AlreadyExists { what: String::from("DnaRecipe") }.encode())));
     }
 
     let dna_recipe_hash = create_entry(&EntryTypes::DnaRecipe(dna_recipe.clone()))?;
     let record = get(dna_recipe_hash.clone(), GetOptions::default())?
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Could not find the newly created DnaRecipe"))
+                WasmErrorInner::Guest(CondenserError::NotFound { what: String::from("DnaRecipe") }.encode())
             ),
         )?;
+    create_link(
+        dna_hash_anchor(&dna_recipe.resulting_dna_hash),
+        dna_recipe_hash.clone(),
+        LinkTypes::DnaHashToDnaRecipe,
+        (),
+    )?;
     let path = Path::from("all_craving_recipes");
     create_link(
         path.path_entry_hash()?,
@@ -23,9 +46,54 @@ pub fn create_dna_recipe(dna_recipe: DnaRecipe) -> ExternResult<Record> {
         LinkTypes::AllCravingRecipes,
         (),
     )?;
+    tag_craving_recipe(dna_recipe_hash, input.tags)?;
+    register_lobby_in_craving(dna_recipe.resulting_dna_hash)?;
     Ok(record)
 }
+/// Lets the craving know that it is listed in this lobby. That only works if we have
+/// the craving installed ourselves, otherwise the craving simply doesn't learn about it.
+fn register_lobby_in_craving(craving_dna_hash: DnaHash) -> ExternResult<()> {
+    let dna_info = dna_info()?;
+    let lobby_registration = LobbyRegistration {
+        lobby_dna_hash: dna_info.hash,
+        lobby_name: LobbyDnaProperties::get()?.name,
+        network_seed: dna_info.modifiers.network_seed,
+        properties: dna_info.modifiers.properties,
+    };
+    let craving_cell_id = CellId::new(craving_dna_hash, agent_info()?.agent_initial_pubkey);
+    match call(
+        CallTargetCell::OtherCell(craving_cell_id),
+        "craving",
+        "register_lobby".into(),
+        None,
+        lobby_registration,
+    ) {
+        Ok(ZomeCallResponse::Ok(_)) => {}
+        Ok(response) => debug!("Could not register the lobby in the craving: {:?}", response),
+        Err(err) => debug!("Could not register the lobby in the craving: {:?}", err),
+    }
+    Ok(())
+}
 #[hdk_extern]
 pub fn get_dna_recipe(dna_recipe_hash: ActionHash) -> ExternResult<Option<Record>> {
     get(dna_recipe_hash, GetOptions::default())
 }
+/// Picks the canonical recipe among several recipes for the same dna hash, which agents
+/// may have registered concurrently: the earliest created one, then the lowest action hash.
+pub fn canonical_dna_recipe(records: impl IntoIterator<Item = Record>) -> Option<Record> {
+    records
+        .into_iter()
+        .min_by_key(|record| (record.action().timestamp(), record.action_address().clone()))
+}
+/// Gets the canonical recipe that results in the given dna hash.
+#[hdk_extern]
+pub fn get_dna_recipe_for_dna_hash(dna_hash: DnaHash) -> ExternResult<Option<Record>> {
+    let links = get_links(dna_hash_anchor(&dna_hash), LinkTypes::DnaHashToDnaRecipe, None)?;
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .filter_map(|link| link.target.into_any_dht_hash())
+        .map(|hash| GetInput::new(hash, GetOptions::default()))
+        .collect();
+    let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
+    Ok(canonical_dna_recipe(records.into_iter().flatten()))
+}
@@ -0,0 +1,222 @@
+use hdk::prelude::*;
+use hdk::prelude::holo_hash::DnaHash;
+use condenser_common::CondenserError;
+use cravings_integrity::*;
+use crate::dna_recipe::get_dna_recipe_for_dna_hash;
+
+const GLOSSARY: &str = "glossary";
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PublishGlossaryEntryInput {
+    pub definition: String,
+    /// The craving the word was condensed in, it needs to be listed in this lobby
+    pub craving_dna_hash: DnaHash,
+    pub offer_hash: ActionHash,
+}
+/// Mirror of the Offer entry of the craving DNA
+#[derive(Serialize, Deserialize, SerializedBytes, Debug)]
+struct Offer {
+    offer: String,
+    explanation: Option<String>,
+}
+/// Reads the current word of the offer from our own cell of the craving, so that the
+/// glossary can't be fed words that were never offered there.
+fn get_offered_word(craving_dna_hash: DnaHash, offer_hash: ActionHash) -> ExternResult<String> {
+    let craving_cell_id = CellId::new(craving_dna_hash, agent_info()?.agent_initial_pubkey);
+    let response = call(
+        CallTargetCell::OtherCell(craving_cell_id),
+        "craving",
+        "get_offer".into(),
+        None,
+        offer_hash,
+    )?;
+    let offer_record: Option<Record> = match response {
+        ZomeCallResponse::Ok(result) => result.decode().map_err(|err| wasm_error!(err))?,
+        response => {
+            return Err(
+                wasm_error!(
+                    WasmErrorInner::Guest(CondenserError::Unexpected { reason: format!("Could not get the offer from the craving: {:?}", response) }.encode())
+                ),
+            );
+        }
+    };
+    let offer: Offer = offer_record
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(CondenserError::NotFound { what: String::from("Offer") }.encode())
+            ),
+        )?
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(CondenserError::MissingEntry.encode())
+            ),
+        )?;
+    Ok(offer.offer)
+}
+/// Publishes the word of an offer to the glossary. Called by the craving's
+/// `publish_offer_to_glossary` via a cross-role call; the word is read from the craving
+/// cell, which we therefore need to have installed.
+#[hdk_extern]
+pub fn publish_glossary_entry(input: PublishGlossaryEntryInput) -> ExternResult<Record> {
+    let dna_recipe_record = get_dna_recipe_for_dna_hash(input.craving_dna_hash.clone())?.ok_or(
+        wasm_error!(
+            WasmErrorInner::Guest(CondenserError::NotFound { what: String::from("DnaRecipe") }.encode())
+        ),
+    )?;
+    let dna_recipe_hash = dna_recipe_record.action_address().clone();
+    let already_published = get_links(dna_recipe_hash.clone(), LinkTypes::DnaRecipeToGlossaryEntries, None)?
+        .into_iter()
+        .filter_map(|link| link.target.into_action_hash())
+        .filter_map(|hash| get(hash, GetOptions::default()).ok().flatten())
+        .filter_map(|record| record.entry().to_app_option::<GlossaryEntry>().ok().flatten())
+        .any(|glossary_entry| glossary_entry.offer_hash == input.offer_hash);
+    if already_published {
+        return Err(
+            wasm_error!(
+                WasmErrorInner::Guest(CondenserError::AlreadyExists { what: String::from("GlossaryEntry") }.encode())
+            ),
+        );
+    }
+    let word = get_offered_word(input.craving_dna_hash, input.offer_hash.clone())?;
+    let glossary_entry_hash = create_entry(&EntryTypes::GlossaryEntry(GlossaryEntry {
+        word,
+        definition: input.definition,
+        dna_recipe_hash: dna_recipe_hash.clone(),
+        offer_hash: input.offer_hash,
+    }))?;
+    create_link(
+        Path::from(GLOSSARY).path_entry_hash()?,
+        glossary_entry_hash.clone(),
+        LinkTypes::AllGlossaryEntries,
+        (),
+    )?;
+    create_link(
+        dna_recipe_hash,
+        glossary_entry_hash.clone(),
+        LinkTypes::DnaRecipeToGlossaryEntries,
+        (),
+    )?;
+    get(glossary_entry_hash, GetOptions::default())?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(CondenserError::NotFound { what: String::from("GlossaryEntry") }.encode())
+            ),
+        )
+}
+
+#[hdk_extern]
+pub fn delete_glossary_entry(glossary_entry_hash: ActionHash) -> ExternResult<ActionHash> {
+    let links = get_links(Path::from(GLOSSARY).path_entry_hash()?, LinkTypes::AllGlossaryEntries, None)?;
+    for link in links {
+        if link.target.clone().into_action_hash().as_ref() == Some(&glossary_entry_hash) {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+    delete_entry(glossary_entry_hash)
+}
+
+/// Gets all glossary entries that haven't been deleted, sorted alphabetically by word.
+#[hdk_extern]
+pub fn get_glossary(_: ()) -> ExternResult<Vec<Record>> {
+    let links = get_links(Path::from(GLOSSARY).path_entry_hash()?, LinkTypes::AllGlossaryEntries, None)?;
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .filter_map(|link| link.target.into_any_dht_hash())
+        .map(|hash| GetInput::new(hash, GetOptions::default()))
+        .collect();
+    let mut records: Vec<(String, Record)> = HDK
+        .with(|hdk| hdk.borrow().get(get_input))?
+        .into_iter()
+        .flatten()
+        .filter_map(|record| {
+            let glossary_entry: GlossaryEntry = record.entry().to_app_option().ok()??;
+            Some((glossary_entry.word.to_lowercase(), record))
+        })
+        .collect();
+    records.sort_by(|(a, _), (b, _)| a.cmp(b));
+    Ok(records.into_iter().map(|(_, record)| record).collect())
+}
+
+/// Gets the glossary entries whose word or definition contain the query, ignoring case.
+#[hdk_extern]
+pub fn search_glossary(query: String) -> ExternResult<Vec<Record>> {
+    let query = query.trim().to_lowercase();
+    Ok(
+        get_glossary(())?
+            .into_iter()
+            .filter(|record| {
+                match record.entry().to_app_option::<GlossaryEntry>() {
+                    Ok(Some(glossary_entry)) => {
+                        glossary_entry.word.to_lowercase().contains(&query)
+                            || glossary_entry.definition.to_lowercase().contains(&query)
+                    }
+                    _ => false,
+                }
+            })
+            .collect(),
+    )
+}
+
+/// Gets the glossary entries of one craving.
+#[hdk_extern]
+pub fn get_glossary_for_dna_recipe(dna_recipe_hash: ActionHash) -> ExternResult<Vec<Record>> {
+    let links = get_links(dna_recipe_hash, LinkTypes::DnaRecipeToGlossaryEntries, None)?;
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .filter_map(|link| link.target.into_any_dht_hash())
+        .map(|hash| GetInput::new(hash, GetOptions::default()))
+        .collect();
+    Ok(HDK.with(|hdk| hdk.borrow().get(get_input))?.into_iter().flatten().collect())
+}
+
+/// A self-contained glossary entry, e.g. to be saved as JSON
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ExportedGlossaryEntry {
+    pub word: String,
+    pub definition: String,
+    pub craving_title: String,
+    pub craving_dna_hash: DnaHash,
+    pub offer_hash: ActionHash,
+    pub author: AgentPubKey,
+    pub published_at: Timestamp,
+}
+/// Gets the whole glossary together with the cravings the words come from.
+#[hdk_extern]
+pub fn export_glossary(_: ()) -> ExternResult<Vec<ExportedGlossaryEntry>> {
+    let mut dna_recipes: Vec<(ActionHash, DnaRecipe)> = Vec::new();
+    let mut exported: Vec<ExportedGlossaryEntry> = Vec::new();
+    for record in get_glossary(())? {
+        let glossary_entry: GlossaryEntry = match record.entry().to_app_option().map_err(|e| wasm_error!(e))? {
+            Some(glossary_entry) => glossary_entry,
+            None => continue,
+        };
+        let cached = dna_recipes.iter().find(|(hash, _)| hash == &glossary_entry.dna_recipe_hash);
+        let dna_recipe = match cached {
+            Some((_, dna_recipe)) => dna_recipe.clone(),
+            None => {
+                let dna_recipe: DnaRecipe = match get(glossary_entry.dna_recipe_hash.clone(), GetOptions::default())? {
+                    Some(dna_recipe_record) => match dna_recipe_record.entry().to_app_option().map_err(|e| wasm_error!(e))? {
+                        Some(dna_recipe) => dna_recipe,
+                        None => continue,
+                    },
+                    None => continue,
+                };
+                dna_recipes.push((glossary_entry.dna_recipe_hash.clone(), dna_recipe.clone()));
+                dna_recipe
+            }
+        };
+        exported.push(ExportedGlossaryEntry {
+            word: glossary_entry.word,
+            definition: glossary_entry.definition,
+            craving_title: dna_recipe.title,
+            craving_dna_hash: dna_recipe.resulting_dna_hash,
+            offer_hash: glossary_entry.offer_hash,
+            author: record.action().author().clone(),
+            published_at: record.action().timestamp(),
+        });
+    }
+    Ok(exported)
+}
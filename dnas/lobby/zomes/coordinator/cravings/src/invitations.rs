@@ -0,0 +1,47 @@
+use hdk::prelude::*;
+use condenser_common::{CondenserError, Invitation, InvitationProof, SignedInvitation};
+use cravings_integrity::*;
+
+/// Gets the invitation proof this agent joined the lobby with.
+#[hdk_extern]
+pub fn get_my_invitation_proof(_: ()) -> ExternResult<InvitationProof> {
+    let my_pubkey = agent_info()?.agent_initial_pubkey;
+    if LobbyDnaProperties::get()?.creator == Some(my_pubkey) {
+        return Ok(InvitationProof::default());
+    }
+    let records = query(ChainQueryFilter::new().action_type(ActionType::AgentValidationPkg))?;
+    let membrane_proof = records.into_iter().find_map(|record| match record.action() {
+        Action::AgentValidationPkg(AgentValidationPkg { membrane_proof, .. }) => Some(membrane_proof.clone()),
+        _ => None,
+    });
+    InvitationProof::from_membrane_proof(&membrane_proof.flatten())
+        .map_err(|err| wasm_error!(WasmErrorInner::Guest(err.encode())))
+}
+
+/// Invites the given agent to this invitation-only lobby. The returned proof has to be
+/// passed as membrane proof when the invitee installs the lobby.
+#[hdk_extern]
+pub fn create_invitation(invitee: AgentPubKey) -> ExternResult<InvitationProof> {
+    if !LobbyDnaProperties::get()?.invitation_only.unwrap_or(false) {
+        return Err(
+            wasm_error!(
+                WasmErrorInner::Guest(
+                    CondenserError::NotAllowed {
+                        reason: String::from("invitations are only needed in invitation-only lobbies"),
+                    }.encode(),
+                )
+            ),
+        );
+    }
+    let my_pubkey = agent_info()?.agent_initial_pubkey;
+    let mut invitation_proof = get_my_invitation_proof(())?;
+    let invitation = Invitation {
+        dna_hash: dna_info()?.hash,
+        inviter: my_pubkey.clone(),
+        invitee,
+        created_at: sys_time()?,
+    };
+    let signature = sign(my_pubkey, &invitation)?;
+    invitation_proof.chain.push(SignedInvitation { invitation, signature });
+    Ok(invitation_proof)
+}
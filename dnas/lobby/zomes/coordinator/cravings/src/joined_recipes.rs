@@ -0,0 +1,93 @@
+use std::collections::HashSet;
+
+use hdk::prelude::*;
+use hdk::prelude::holo_hash::DnaHash;
+use condenser_common::CondenserError;
+use cravings_integrity::*;
+use crate::dna_recipe::get_dna_recipe_for_dna_hash;
+
+fn get_recipe_for_craving(craving_dna_hash: DnaHash) -> ExternResult<ActionHash> {
+    let dna_recipe_record = get_dna_recipe_for_dna_hash(craving_dna_hash)?.ok_or(
+        wasm_error!(
+            WasmErrorInner::Guest(CondenserError::NotFound { what: String::from("DnaRecipe") }.encode())
+        ),
+    )?;
+    Ok(dna_recipe_record.action_address().clone())
+}
+
+/// Records that we installed the craving of the given recipe.
+pub fn join_recipe(dna_recipe_hash: ActionHash) -> ExternResult<()> {
+    let my_pubkey = agent_info()?.agent_initial_pubkey;
+    let already_joined = get_links(my_pubkey.clone(), LinkTypes::AgentToJoinedRecipe, None)?
+        .into_iter()
+        .any(|link| link.target.into_action_hash().as_ref() == Some(&dna_recipe_hash));
+    if already_joined {
+        return Ok(());
+    }
+    create_link(my_pubkey.clone(), dna_recipe_hash.clone(), LinkTypes::AgentToJoinedRecipe, ())?;
+    create_link(dna_recipe_hash, my_pubkey, LinkTypes::JoinedRecipeToAgents, ())?;
+    Ok(())
+}
+
+/// Called by the app when a craving cell gets installed or enabled.
+#[hdk_extern]
+pub fn join_craving(craving_dna_hash: DnaHash) -> ExternResult<()> {
+    join_recipe(get_recipe_for_craving(craving_dna_hash)?)
+}
+
+/// Called by the app when a craving cell gets disabled.
+#[hdk_extern]
+pub fn leave_craving(craving_dna_hash: DnaHash) -> ExternResult<()> {
+    let dna_recipe_hash = get_recipe_for_craving(craving_dna_hash)?;
+    let my_pubkey = agent_info()?.agent_initial_pubkey;
+    for link in get_links(my_pubkey.clone(), LinkTypes::AgentToJoinedRecipe, None)? {
+        if link.target.into_action_hash().as_ref() == Some(&dna_recipe_hash) {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+    for link in get_links(dna_recipe_hash, LinkTypes::JoinedRecipeToAgents, None)? {
+        if link.author == my_pubkey {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+    Ok(())
+}
+
+#[hdk_extern]
+pub fn get_recipe_joiners(dna_recipe_hash: ActionHash) -> ExternResult<Vec<AgentPubKey>> {
+    let mut joiners: Vec<AgentPubKey> = Vec::new();
+    for link in get_links(dna_recipe_hash, LinkTypes::JoinedRecipeToAgents, None)? {
+        if let Some(agent) = link.target.into_agent_pub_key() {
+            if !joiners.contains(&agent) {
+                joiners.push(agent);
+            }
+        }
+    }
+    Ok(joiners)
+}
+
+#[hdk_extern]
+pub fn get_recipe_join_count(dna_recipe_hash: ActionHash) -> ExternResult<usize> {
+    Ok(get_recipe_joiners(dna_recipe_hash)?.len())
+}
+
+/// Gets the recipes of the cravings we have installed.
+#[hdk_extern]
+pub fn get_my_joined_recipes(_: ()) -> ExternResult<Vec<Record>> {
+    let my_pubkey = agent_info()?.agent_initial_pubkey;
+    let dna_recipe_hashes: HashSet<ActionHash> = get_links(my_pubkey, LinkTypes::AgentToJoinedRecipe, None)?
+        .into_iter()
+        .filter_map(|link| link.target.into_action_hash())
+        .collect();
+    let get_input: Vec<GetInput> = dna_recipe_hashes
+        .into_iter()
+        .map(|hash| GetInput::new(hash.into(), GetOptions::default()))
+        .collect();
+    let mut records: Vec<Record> = HDK
+        .with(|hdk| hdk.borrow().get(get_input))?
+        .into_iter()
+        .flatten()
+        .collect();
+    records.sort_by_key(|record| record.action().timestamp());
+    Ok(records)
+}
@@ -3,10 +3,31 @@ pub mod anchor_to_lobby_infos;
 pub mod lobby_info;
 pub mod all_craving_recipes;
 pub mod dna_recipe;
+pub mod craving_tags;
+pub mod invitations;
+pub mod lobby_admins;
+pub mod lobby_members;
+pub mod recipe_status;
+pub mod announcements;
+pub mod craving_proposals;
+pub mod glossary;
+pub mod craving_previews;
+pub mod joined_recipes;
+pub mod recipe_export;
 use hdk::prelude::*;
+use condenser_common::CondenserError;
 use cravings_integrity::*;
 #[hdk_extern]
 pub fn init(_: ()) -> ExternResult<InitCallbackResult> {
+    // Allow any other member to send us remote signals, e.g. about new announcements
+    let mut functions = BTreeSet::new();
+    functions.insert((zome_info()?.name, "recv_remote_signal".into()));
+    create_cap_grant(CapGrantEntry {
+        tag: "remote_signals".into(),
+        access: CapAccess::Unrestricted,
+        functions: GrantedFunctions::Listed(functions),
+    })?;
+    lobby_members::register_as_lobby_member()?;
     Ok(InitCallbackResult::Pass)
 }
 #[derive(Serialize, Deserialize, Debug)]
@@ -16,13 +37,26 @@ pub enum Signal {
     EntryUpdated {
         action: SignedActionHashed,
         record: Record,
-        original_record: Record,
+        original_record: Box<Record>,
         app_entry: EntryTypes,
-        original_app_entry: EntryTypes,
+        original_app_entry: Box<EntryTypes>,
     },
     EntryDeleted { action: SignedActionHashed, original_app_entry: EntryTypes },
     LinkCreated { action: SignedActionHashed, link_type: LinkTypes },
     LinkDeleted { action: SignedActionHashed, link_type: LinkTypes },
+    /// A signal sent to us by another member of the lobby
+    Remote { provenance: AgentPubKey, signal: RemoteSignal },
+}
+/// Signals that get sent to the other members of the lobby.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(tag = "type")]
+pub enum RemoteSignal {
+    AnnouncementPosted { record: Record },
+}
+#[hdk_extern]
+pub fn recv_remote_signal(signal: RemoteSignal) -> ExternResult<()> {
+    let provenance = call_info()?.provenance;
+    emit_signal(Signal::Remote { provenance, signal })
 }
 #[hdk_extern(infallible)]
 pub fn post_commit(committed_actions: Vec<SignedActionHashed>) {
@@ -43,12 +77,16 @@ fn signal_action(action: SignedActionHashed) -> ExternResult<()> {
                 _ => {
                     return Err(
                         wasm_error!(
-                            WasmErrorInner::Guest("Create should carry an entry"
-                            .to_string())
+                            WasmErrorInner::Guest(CondenserError::MalformedOp { reason: String::from("Create should carry an entry") }.encode())
                         ),
                     );
                 }
             };
+            if let EntryTypes::Announcement(_) = &app_entry {
+                if let Err(err) = announcements::broadcast_announcement(record.clone()) {
+                    error!("Error broadcasting announcement: {:?}", err);
+                }
+            }
             emit_signal(Signal::EntryCreated {
                 action,
                 app_entry,
@@ -65,8 +103,7 @@ fn signal_action(action: SignedActionHashed) -> ExternResult<()> {
                 _ => {
                     return Err(
                         wasm_error!(
-                            WasmErrorInner::Guest("Update should carry an entry"
-                            .to_string())
+                            WasmErrorInner::Guest(CondenserError::MalformedOp { reason: String::from("Update should carry an entry") }.encode())
                         ),
                     );
                 }
@@ -82,8 +119,7 @@ fn signal_action(action: SignedActionHashed) -> ExternResult<()> {
                 _ => {
                     return Err(
                         wasm_error!(
-                            WasmErrorInner::Guest("Update should carry an entry"
-                            .to_string())
+                            WasmErrorInner::Guest(CondenserError::MalformedOp { reason: String::from("Update should carry an entry") }.encode())
                         ),
                     );
                 }
@@ -92,8 +128,8 @@ fn signal_action(action: SignedActionHashed) -> ExternResult<()> {
                 action,
                 record,
                 app_entry,
-                original_record,
-                original_app_entry,
+                original_record: Box::new(original_record),
+                original_app_entry: Box::new(original_app_entry),
             })?;
             Ok(())
         }
@@ -101,8 +137,7 @@ fn signal_action(action: SignedActionHashed) -> ExternResult<()> {
             let original_app_entry = get_entry_for_action(&delete.deletes_address)?
                 .ok_or(
                     wasm_error!(
-                        WasmErrorInner::Guest("Deleted action should carry an entry"
-                        .to_string())
+                        WasmErrorInner::Guest(CondenserError::MalformedOp { reason: String::from("Deleted action should carry an entry") }.encode())
                     ),
                 )?;
             emit_signal(Signal::EntryDeleted {
@@ -118,7 +153,7 @@ fn signal_action(action: SignedActionHashed) -> ExternResult<()> {
                 )?
                 .ok_or(
                     wasm_error!(
-                        WasmErrorInner::Guest("Link type should be exist".to_string())
+                        WasmErrorInner::Guest(CondenserError::MalformedOp { reason: String::from("Link type should be exist") }.encode())
                     ),
                 )?;
             emit_signal(Signal::LinkCreated {
@@ -134,7 +169,7 @@ fn signal_action(action: SignedActionHashed) -> ExternResult<()> {
                 )?
                 .ok_or(
                     wasm_error!(
-                        WasmErrorInner::Guest("Create Link should exist".to_string())
+                        WasmErrorInner::Guest(CondenserError::MalformedOp { reason: String::from("Create Link should exist") }.encode())
                     ),
                 )?;
             match record.action() {
@@ -145,8 +180,7 @@ fn signal_action(action: SignedActionHashed) -> ExternResult<()> {
                         )?
                         .ok_or(
                             wasm_error!(
-                                WasmErrorInner::Guest("Link type should be exist"
-                                .to_string())
+                                WasmErrorInner::Guest(CondenserError::MalformedOp { reason: String::from("Link type should be exist") }.encode())
                             ),
                         )?;
                     emit_signal(Signal::LinkDeleted {
@@ -156,11 +190,11 @@ fn signal_action(action: SignedActionHashed) -> ExternResult<()> {
                     Ok(())
                 }
                 _ => {
-                    return Err(
+                    Err(
                         wasm_error!(
-                            WasmErrorInner::Guest("Create Link should exist".to_string())
+                            WasmErrorInner::Guest(CondenserError::MalformedOp { reason: String::from("Create Link should exist") }.encode())
                         ),
-                    );
+                    )
                 }
             }
         }
@@ -188,12 +222,10 @@ fn get_entry_for_action(action_hash: &ActionHash) -> ExternResult<Option<EntryTy
             return Ok(None);
         }
     };
-    Ok(
-        EntryTypes::deserialize_from_type(
-            zome_index.clone(),
-            entry_index.clone(),
-            entry,
-        )?,
+    EntryTypes::deserialize_from_type(
+        *zome_index,
+        *entry_index,
+        entry,
     )
 }
 fn get_entry_and_record_for_action(
@@ -223,8 +255,8 @@ fn get_entry_and_record_for_action(
     Ok((
         Some(record_clone),
         EntryTypes::deserialize_from_type(
-            zome_index.clone(),
-            entry_index.clone(),
+            *zome_index,
+            *entry_index,
             entry,
         )?,
     ))
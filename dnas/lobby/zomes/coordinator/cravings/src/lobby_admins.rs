@@ -0,0 +1,179 @@
+use hdk::prelude::*;
+use condenser_common::CondenserError;
+use cravings_integrity::*;
+
+const ALL_LOBBY_ADMIN_ROLES: &str = "all_lobby_admin_roles";
+
+/// Gets the admin role records of all admins that haven't stepped down.
+fn get_lobby_admin_roles() -> ExternResult<Vec<(Record, LobbyAdminRole)>> {
+    let links = get_links(
+        Path::from(ALL_LOBBY_ADMIN_ROLES).path_entry_hash()?,
+        LinkTypes::AllLobbyAdminRoles,
+        None,
+    )?;
+    let mut roles: Vec<(Record, LobbyAdminRole)> = Vec::new();
+    for link in links {
+        let role_hash = match link.target.into_action_hash() {
+            Some(role_hash) => role_hash,
+            None => continue,
+        };
+        let record = match get_details(role_hash, GetOptions::default())? {
+            Some(Details::Record(record_details)) if record_details.deletes.is_empty() => record_details.record,
+            _ => continue,
+        };
+        let lobby_admin_role: Option<LobbyAdminRole> = record
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))?;
+        if let Some(lobby_admin_role) = lobby_admin_role {
+            roles.push((record, lobby_admin_role));
+        }
+    }
+    Ok(roles)
+}
+
+/// Gets the hash of the admin role of the given agent, if they have one they haven't stepped down from.
+pub fn get_admin_role_hash(agent: &AgentPubKey) -> ExternResult<Option<ActionHash>> {
+    Ok(
+        get_lobby_admin_roles()?
+            .into_iter()
+            .filter(|(_, lobby_admin_role)| &lobby_admin_role.agent == agent)
+            .min_by_key(|(record, _)| record.action().timestamp())
+            .map(|(record, _)| record.action_address().clone()),
+    )
+}
+
+/// Returns the admin role hash that my LobbyInfo changes need to reference, failing if
+/// the lobby is in admin mode and I am neither the creator nor an admin.
+pub fn my_admin_role_hash_for_lobby_info() -> ExternResult<Option<ActionHash>> {
+    let lobby_dna_properties = LobbyDnaProperties::get()?;
+    if !lobby_dna_properties.is_admin_mode() {
+        return Ok(None);
+    }
+    let my_pubkey = agent_info()?.agent_initial_pubkey;
+    if lobby_dna_properties.creator.as_ref() == Some(&my_pubkey) {
+        return Ok(None);
+    }
+    match get_admin_role_hash(&my_pubkey)? {
+        Some(admin_role_hash) => Ok(Some(admin_role_hash)),
+        None => Err(not_admin()),
+    }
+}
+
+fn not_admin() -> WasmError {
+    wasm_error!(WasmErrorInner::Guest(CondenserError::NotAuthor { what: String::from("LobbyAdminRole") }.encode()))
+}
+
+/// Gets the creator and all current admins of the lobby. Empty if the lobby is not in admin mode.
+#[hdk_extern]
+pub fn get_lobby_admins(_: ()) -> ExternResult<Vec<AgentPubKey>> {
+    let lobby_dna_properties = LobbyDnaProperties::get()?;
+    if !lobby_dna_properties.is_admin_mode() {
+        return Ok(vec![]);
+    }
+    let mut admins: Vec<AgentPubKey> = lobby_dna_properties.creator.into_iter().collect();
+    for (_, lobby_admin_role) in get_lobby_admin_roles()? {
+        if !admins.contains(&lobby_admin_role.agent) {
+            admins.push(lobby_admin_role.agent);
+        }
+    }
+    Ok(admins)
+}
+
+#[hdk_extern]
+pub fn grant_lobby_admin(agent: AgentPubKey) -> ExternResult<Record> {
+    if get_admin_role_hash(&agent)?.is_some() || LobbyDnaProperties::get()?.creator.as_ref() == Some(&agent) {
+        return Err(
+            wasm_error!(
+                WasmErrorInner::Guest(CondenserError::AlreadyExists { what: String::from("LobbyAdminRole") }.encode())
+            ),
+        );
+    }
+    let my_pubkey = agent_info()?.agent_initial_pubkey;
+    let granted_by_role = match LobbyDnaProperties::get()?.creator.as_ref() == Some(&my_pubkey) {
+        true => None,
+        false => Some(get_admin_role_hash(&my_pubkey)?.ok_or(not_admin())?),
+    };
+    let lobby_admin_role = LobbyAdminRole { agent, granted_by_role };
+    let lobby_admin_role_hash = create_entry(&EntryTypes::LobbyAdminRole(lobby_admin_role))?;
+    create_link(
+        Path::from(ALL_LOBBY_ADMIN_ROLES).path_entry_hash()?,
+        lobby_admin_role_hash.clone(),
+        LinkTypes::AllLobbyAdminRoles,
+        (),
+    )?;
+    get(lobby_admin_role_hash, GetOptions::default())?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(CondenserError::NotFound { what: String::from("LobbyAdminRole") }.encode())
+            ),
+        )
+}
+
+/// Deletes all admin roles of the given agent together with their links.
+fn delete_admin_roles(agent: &AgentPubKey) -> ExternResult<()> {
+    let links = get_links(
+        Path::from(ALL_LOBBY_ADMIN_ROLES).path_entry_hash()?,
+        LinkTypes::AllLobbyAdminRoles,
+        None,
+    )?;
+    let role_hashes: Vec<ActionHash> = get_lobby_admin_roles()?
+        .into_iter()
+        .filter(|(_, lobby_admin_role)| &lobby_admin_role.agent == agent)
+        .map(|(record, _)| record.action_address().clone())
+        .collect();
+    if role_hashes.is_empty() {
+        return Err(
+            wasm_error!(
+                WasmErrorInner::Guest(CondenserError::NotFound { what: String::from("LobbyAdminRole") }.encode())
+            ),
+        );
+    }
+    for link in links {
+        let is_agents = link
+            .target
+            .clone()
+            .into_action_hash()
+            .is_some_and(|role_hash| role_hashes.contains(&role_hash));
+        if is_agents {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+    for role_hash in role_hashes {
+        delete_entry(role_hash)?;
+    }
+    Ok(())
+}
+
+/// Gives up all my admin roles.
+#[hdk_extern]
+pub fn step_down_as_lobby_admin(_: ()) -> ExternResult<()> {
+    delete_admin_roles(&agent_info()?.agent_initial_pubkey)
+}
+
+/// Revokes all admin roles of the given agent. Only the creator of the lobby and the
+/// admins who granted the roles may do so, see `check_may_delete_role`.
+#[hdk_extern]
+pub fn revoke_lobby_admin(agent: AgentPubKey) -> ExternResult<()> {
+    let my_pubkey = agent_info()?.agent_initial_pubkey;
+    if LobbyDnaProperties::get()?.creator.as_ref() != Some(&my_pubkey) {
+        let roles = get_lobby_admin_roles()?;
+        let my_role_hashes: Vec<ActionHash> = roles
+            .iter()
+            .filter(|(_, lobby_admin_role)| lobby_admin_role.agent == my_pubkey)
+            .map(|(record, _)| record.action_address().clone())
+            .collect();
+        let granted_by_me = roles
+            .into_iter()
+            .filter(|(_, lobby_admin_role)| lobby_admin_role.agent == agent)
+            .all(|(_, lobby_admin_role)| {
+                lobby_admin_role
+                    .granted_by_role
+                    .is_some_and(|granted_by_role| my_role_hashes.contains(&granted_by_role))
+            });
+        if !granted_by_me {
+            return Err(not_admin());
+        }
+    }
+    delete_admin_roles(&agent)
+}
@@ -1,16 +1,25 @@
 use hdk::prelude::*;
+use condenser_common::CondenserError;
 use cravings_integrity::*;
-
-const LOBBY_INFO: &str = "LOBBY_INFO";
+use crate::lobby_admins::my_admin_role_hash_for_lobby_info;
 
 #[hdk_extern]
-pub fn create_lobby_info(lobby_info: LobbyInfo) -> ExternResult<Record> {
-    let anchor = anchor(LinkTypes::AnchorToLobbyInfo, LOBBY_INFO.into(), LOBBY_INFO.into())?;
+pub fn create_lobby_info(mut lobby_info: LobbyInfo) -> ExternResult<Record> {
+    let my_pubkey = agent_info()?.agent_initial_pubkey;
+    if LobbyDnaProperties::get()?.creator.is_some_and(|creator| creator != my_pubkey) {
+        return Err(
+            wasm_error!(
+                WasmErrorInner::Guest(CondenserError::NotAuthor { what: String::from("Lobby") }.encode())
+            ),
+        );
+    }
+    lobby_info.admin_role_hash = my_admin_role_hash_for_lobby_info()?;
+    let anchor = lobby_info_anchor()?;
     let existing_links = get_links(anchor.clone(), LinkTypes::AnchorToLobbyInfo, None)?;
-    if existing_links.len() != 0 {
+    if !existing_links.is_empty() {
         return Err(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("There is already a link from the LOBBY_INFO anchor. Only one link is allowed."))
+                WasmErrorInner::Guest(CondenserError::AlreadyExists { what: String::from("LobbyInfo") }.encode())
             ),
         );
     }
@@ -20,43 +29,125 @@ pub fn create_lobby_info(lobby_info: LobbyInfo) -> ExternResult<Record> {
     let record = get(lobby_info_hash, GetOptions::default())?
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Could not find the newly created Craving."))
+                WasmErrorInner::Guest(CondenserError::NotFound { what: String::from("LobbyInfo") }.encode())
             ),
         )?;
 
     Ok(record)
 }
-#[hdk_extern]
-pub fn get_lobby_info(
-    _: (),
-) -> ExternResult<Option<Record>> {
-    let anchor = anchor(LinkTypes::AnchorToLobbyInfo, LOBBY_INFO.into(), LOBBY_INFO.into())?;
-    let anchor_links = get_links(anchor.clone(), LinkTypes::AnchorToLobbyInfo, None)?;
-    if anchor_links.len() == 0 {
-        return Err(
-            wasm_error!(
-                WasmErrorInner::Guest(String::from("There is no link pointing to the lobby info yet."))
-            ),
-        );
-    }
-
-    let original_lobby_info_hash = ActionHash::try_from(
-        anchor_links.first().unwrap().target.clone(),
-    ).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
-
+/// Gets the canonical original LobbyInfo. Lobbies with a creator only have the creator's
+/// link. Otherwise, if concurrent `create_lobby_info` calls raced past the existing-links
+/// check, the earliest link (then the lowest link hash) wins.
+fn get_original_lobby_info_hash() -> ExternResult<Option<ActionHash>> {
+    let anchor = lobby_info_anchor()?;
+    let mut anchor_links = get_links(anchor, LinkTypes::AnchorToLobbyInfo, None)?;
+    anchor_links.sort_by(|a, b| {
+        a.timestamp
+            .cmp(&b.timestamp)
+            .then_with(|| a.create_link_hash.cmp(&b.create_link_hash))
+    });
+    Ok(anchor_links.into_iter().find_map(|link| link.target.into_action_hash()))
+}
+/// Gets the original LobbyInfo record and all its updates, ordered by time.
+fn get_lobby_info_records(original_lobby_info_hash: ActionHash) -> ExternResult<Vec<Record>> {
     let links = get_links(
         original_lobby_info_hash.clone(),
         LinkTypes::LobbyInfoUpdates,
         None,
     )?;
-    let latest_link = links
+    let get_input: Vec<GetInput> = std::iter::once(original_lobby_info_hash.into())
+        .chain(links.into_iter().filter_map(|link| link.target.into_any_dht_hash()))
+        .map(|hash| GetInput::new(hash, GetOptions::default()))
+        .collect();
+    let mut records: Vec<Record> = HDK
+        .with(|hdk| hdk.borrow().get(get_input))?
         .into_iter()
-        .max_by(|link_a, link_b| link_b.timestamp.cmp(&link_a.timestamp));
-    let latest_lobby_info_hash = match latest_link {
-        Some(link) => ActionHash::try_from(link.target.clone()).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?,
-        None => original_lobby_info_hash.clone(),
+        .flatten()
+        .collect();
+    records.sort_by(|a, b| {
+        a.action()
+            .timestamp()
+            .cmp(&b.action().timestamp())
+            .then_with(|| a.action_address().cmp(b.action_address()))
+    });
+    records.dedup_by(|a, b| a.action_address() == b.action_address());
+    Ok(records)
+}
+fn previous_lobby_info_hash(record: &Record) -> Option<ActionHash> {
+    match record.action() {
+        Action::Update(update) => Some(update.original_action_address.clone()),
+        _ => None,
+    }
+}
+/// The latest revision that has not been updated yet. If there are concurrent branches,
+/// the most recent head wins.
+fn latest_lobby_info(records: Vec<Record>) -> Option<Record> {
+    let previous_hashes: Vec<ActionHash> = records.iter().filter_map(previous_lobby_info_hash).collect();
+    records
+        .into_iter()
+        .filter(|record| !previous_hashes.contains(record.action_address()))
+        .last()
+}
+#[hdk_extern]
+pub fn get_lobby_info(
+    _: (),
+) -> ExternResult<Option<Record>> {
+    let original_lobby_info_hash = get_original_lobby_info_hash()?.ok_or(
+        wasm_error!(
+            WasmErrorInner::Guest(CondenserError::NotFound { what: String::from("LobbyInfo") }.encode())
+        ),
+    )?;
+    Ok(latest_lobby_info(get_lobby_info_records(original_lobby_info_hash)?))
+}
+#[derive(Serialize, Deserialize, Debug)]
+pub struct LobbyInfoRevision {
+    pub record: Record,
+    /// The revision this one was based on, None for the original
+    pub previous_lobby_info_hash: Option<ActionHash>,
+}
+/// Gets all revisions of the LobbyInfo, oldest first.
+#[hdk_extern]
+pub fn get_lobby_info_history(_: ()) -> ExternResult<Vec<LobbyInfoRevision>> {
+    let original_lobby_info_hash = match get_original_lobby_info_hash()? {
+        Some(hash) => hash,
+        None => return Ok(vec![]),
     };
-    get(latest_lobby_info_hash, GetOptions::default())
+    Ok(
+        get_lobby_info_records(original_lobby_info_hash)?
+            .into_iter()
+            .map(|record| LobbyInfoRevision {
+                previous_lobby_info_hash: previous_lobby_info_hash(&record),
+                record,
+            })
+            .collect(),
+    )
+}
+#[derive(Serialize, Deserialize, Debug)]
+pub struct LobbyInfoConflict {
+    pub previous_lobby_info_hash: ActionHash,
+    /// Concurrent updates of the same revision, oldest first
+    pub branches: Vec<Record>,
+}
+/// Gets all revisions of the LobbyInfo that have been updated more than once, i.e.
+/// where members edited concurrently and one of the edits is not shown anymore.
+#[hdk_extern]
+pub fn get_lobby_info_conflicts(_: ()) -> ExternResult<Vec<LobbyInfoConflict>> {
+    let mut conflicts: Vec<LobbyInfoConflict> = Vec::new();
+    for revision in get_lobby_info_history(())? {
+        let previous_lobby_info_hash = match revision.previous_lobby_info_hash {
+            Some(hash) => hash,
+            None => continue,
+        };
+        match conflicts.iter_mut().find(|c| c.previous_lobby_info_hash == previous_lobby_info_hash) {
+            Some(conflict) => conflict.branches.push(revision.record),
+            None => conflicts.push(LobbyInfoConflict {
+                previous_lobby_info_hash,
+                branches: vec![revision.record],
+            }),
+        }
+    }
+    conflicts.retain(|conflict| conflict.branches.len() > 1);
+    Ok(conflicts)
 }
 #[derive(Serialize, Deserialize, Debug)]
 pub struct UpdateLobbyInfoInput {
@@ -80,9 +171,26 @@ pub fn update_lobby_info(input: UpdateLobbyInfoInput) -> ExternResult<Record> {
     //     return Err(wasm_error!(WasmErrorInner::Guest(String::from("Only the creator of a Lobby is allowed to update the LobbyInfo."))));
     // }
 
+    // refuse to silently overwrite an edit we have not seen
+    if let Some(latest) = get_lobby_info(())? {
+        if latest.action_address() != &input.previous_lobby_info_hash {
+            return Err(
+                wasm_error!(
+                    WasmErrorInner::Guest(
+                        CondenserError::Outdated {
+                            what: String::from("LobbyInfo"),
+                            latest: latest.action_address().to_string(),
+                        }.encode(),
+                    )
+                ),
+            );
+        }
+    }
+    let mut updated_lobby_info = input.updated_lobby_info;
+    updated_lobby_info.admin_role_hash = my_admin_role_hash_for_lobby_info()?;
     let updated_lobby_info_hash = update_entry(
         input.previous_lobby_info_hash.clone(),
-        &input.updated_lobby_info,
+        &updated_lobby_info,
     )?;
     create_link(
         input.original_lobby_info_hash.clone(),
@@ -93,7 +201,7 @@ pub fn update_lobby_info(input: UpdateLobbyInfoInput) -> ExternResult<Record> {
     let record = get(updated_lobby_info_hash.clone(), GetOptions::default())?
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Could not find the newly updated LobbyInfo"))
+                WasmErrorInner::Guest(CondenserError::NotFound { what: String::from("LobbyInfo") }.encode())
             ),
         )?;
     Ok(record)
@@ -106,7 +214,7 @@ pub fn update_lobby_info(input: UpdateLobbyInfoInput) -> ExternResult<Record> {
 pub fn get_lobby_name(_: ()) -> ExternResult<String> {
     let dna_info = dna_info()?;
     let properties = LobbyDnaProperties::try_from(dna_info.modifiers.properties)
-        .map_err(|err| wasm_error!(WasmErrorInner::Guest(format!("Failed to read lobby dna properties from dna info: {}", err.to_string()))))?;
+        .map_err(|err| wasm_error!(WasmErrorInner::Guest(CondenserError::InvalidDnaProperties { reason: err.to_string() }.encode())))?;
     Ok(properties.name)
 }
 
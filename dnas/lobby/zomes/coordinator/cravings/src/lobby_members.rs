@@ -0,0 +1,84 @@
+use hdk::prelude::*;
+use cravings_integrity::*;
+
+const LOBBY_MEMBERS: &str = "lobby_members";
+
+/// A member of the lobby together with their profile, if they have created one.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct LobbyMember {
+    pub agent: AgentPubKey,
+    pub profile: Option<Record>,
+    pub joined_at: Timestamp,
+}
+
+/// Registers me in the member directory of the lobby. Called from `init`.
+pub fn register_as_lobby_member() -> ExternResult<()> {
+    let my_pubkey = agent_info()?.agent_initial_pubkey;
+    create_link(
+        Path::from(LOBBY_MEMBERS).path_entry_hash()?,
+        my_pubkey,
+        LinkTypes::LobbyMembers,
+        (),
+    )?;
+    Ok(())
+}
+
+/// Gets the members of the lobby with the earliest time they registered, deduplicated.
+fn get_member_links() -> ExternResult<Vec<(AgentPubKey, Timestamp)>> {
+    let mut links = get_links(
+        Path::from(LOBBY_MEMBERS).path_entry_hash()?,
+        LinkTypes::LobbyMembers,
+        None,
+    )?;
+    links.sort_by_key(|link| link.timestamp);
+    let mut members: Vec<(AgentPubKey, Timestamp)> = Vec::new();
+    for link in links {
+        if let Some(agent) = link.target.into_agent_pub_key() {
+            if !members.iter().any(|(member, _)| member == &agent) {
+                members.push((agent, link.timestamp));
+            }
+        }
+    }
+    Ok(members)
+}
+
+/// Gets the profile of the agent from the profiles zome of this cell. Members without
+/// a profile, or a profiles zome that can't be reached, simply yield None.
+fn get_member_profile(agent: AgentPubKey) -> Option<Record> {
+    match call(CallTargetCell::Local, "profiles", "get_agent_profile".into(), None, agent) {
+        Ok(ZomeCallResponse::Ok(response)) => response.decode::<Option<Record>>().ok().flatten(),
+        Ok(_) | Err(_) => None,
+    }
+}
+
+/// Gets all members of the lobby, ordered by the time they joined.
+#[hdk_extern]
+pub fn get_lobby_members(_: ()) -> ExternResult<Vec<LobbyMember>> {
+    Ok(
+        get_member_links()?
+            .into_iter()
+            .map(|(agent, joined_at)| LobbyMember {
+                profile: get_member_profile(agent.clone()),
+                agent,
+                joined_at,
+            })
+            .collect(),
+    )
+}
+
+/// Gets all members of the lobby except myself, i.e. the recipients of remote signals.
+pub fn get_other_lobby_members() -> ExternResult<Vec<AgentPubKey>> {
+    let my_pubkey = agent_info()?.agent_initial_pubkey;
+    Ok(
+        get_member_links()?
+            .into_iter()
+            .map(|(agent, _)| agent)
+            .filter(|agent| agent != &my_pubkey)
+            .collect(),
+    )
+}
+
+#[hdk_extern]
+pub fn get_member_count(_: ()) -> ExternResult<usize> {
+    Ok(get_member_links()?.len())
+}
@@ -0,0 +1,115 @@
+use hdk::prelude::*;
+use condenser_common::CondenserError;
+use cravings_integrity::*;
+use crate::dna_recipe::{get_dna_recipe_for_dna_hash, list_dna_recipe, CreateDnaRecipeInput};
+
+/// Serializes a recipe so that it can be imported into another lobby, signed by us.
+/// If the recipe was imported itself, the export keeps pointing to its original lobby.
+#[hdk_extern]
+pub fn export_recipe(dna_recipe_hash: ActionHash) -> ExternResult<SerializedBytes> {
+    let record = get(dna_recipe_hash.clone(), GetOptions::default())?.ok_or(
+        wasm_error!(
+            WasmErrorInner::Guest(CondenserError::NotFound { what: String::from("DnaRecipe") }.encode())
+        ),
+    )?;
+    let dna_recipe: DnaRecipe = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(CondenserError::WrongType { expected: String::from("DnaRecipe") }.encode())
+            ),
+        )?;
+    let (origin_lobby_dna_hash, origin_lobby_name) = match get_recipe_origin(dna_recipe_hash)? {
+        Some(recipe_origin) => (recipe_origin.origin_lobby_dna_hash, recipe_origin.origin_lobby_name),
+        None => (dna_info()?.hash, LobbyDnaProperties::get()?.name),
+    };
+    let export = RecipeExport {
+        version: RECIPE_EXPORT_VERSION,
+        dna_recipe,
+        origin_lobby_dna_hash,
+        origin_lobby_name,
+        exported_at: sys_time()?,
+    };
+    let exported_by = agent_info()?.agent_initial_pubkey;
+    let signature = sign(exported_by.clone(), &export)?;
+    SerializedBytes::try_from(SignedRecipeExport { export, exported_by, signature })
+        .map_err(|err| wasm_error!(err))
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ImportRecipeInput {
+    /// As returned by `export_recipe` of another lobby
+    pub recipe_export: SerializedBytes,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+/// Lists a recipe exported from another lobby in this lobby and records where it comes from.
+#[hdk_extern]
+pub fn import_recipe(input: ImportRecipeInput) -> ExternResult<Record> {
+    let signed_recipe_export = SignedRecipeExport::try_from(input.recipe_export).map_err(|err| {
+        wasm_error!(
+            WasmErrorInner::Guest(CondenserError::InvalidRecipeExport { reason: err.to_string() }.encode())
+        )
+    })?;
+    if let Some(err) = signed_recipe_export.verify()? {
+        return Err(wasm_error!(WasmErrorInner::Guest(err.encode())));
+    }
+    let export = signed_recipe_export.export;
+    if export.origin_lobby_dna_hash == dna_info()?.hash {
+        return Err(
+            wasm_error!(
+                WasmErrorInner::Guest(CondenserError::NotAllowed { reason: String::from("the recipe was exported from this lobby") }.encode())
+            ),
+        );
+    }
+    // the recipe may already be listed here, registered or imported from any lobby
+    if get_dna_recipe_for_dna_hash(export.dna_recipe.resulting_dna_hash.clone())?.is_some() {
+        return Err(
+            wasm_error!(
+                WasmErrorInner::Guest(CondenserError::AlreadyExists { what: String::from("DnaRecipe") }.encode())
+            ),
+        );
+    }
+    let record = list_dna_recipe(CreateDnaRecipeInput {
+        dna_recipe: export.dna_recipe,
+        tags: input.tags,
+    })?;
+    let dna_recipe_hash = record.action_address().clone();
+    let recipe_origin_hash = create_entry(&EntryTypes::RecipeOrigin(RecipeOrigin {
+        dna_recipe_hash: dna_recipe_hash.clone(),
+        version: export.version,
+        origin_lobby_dna_hash: export.origin_lobby_dna_hash,
+        origin_lobby_name: export.origin_lobby_name,
+        exported_at: export.exported_at,
+        exported_by: signed_recipe_export.exported_by,
+        signature: signed_recipe_export.signature,
+    }))?;
+    create_link(dna_recipe_hash, recipe_origin_hash, LinkTypes::DnaRecipeToOrigin, ())?;
+    Ok(record)
+}
+
+/// Gets where the recipe was imported from, None if it was registered in this lobby.
+/// The origin is the unverified claim of the exporter, see `RecipeOrigin`.
+#[hdk_extern]
+pub fn get_recipe_origin(dna_recipe_hash: ActionHash) -> ExternResult<Option<RecipeOrigin>> {
+    let mut links = get_links(dna_recipe_hash, LinkTypes::DnaRecipeToOrigin, None)?;
+    links.sort_by(|a, b| {
+        a.timestamp
+            .cmp(&b.timestamp)
+            .then_with(|| a.create_link_hash.cmp(&b.create_link_hash))
+    });
+    for link in links {
+        let recipe_origin_hash = match link.target.into_action_hash() {
+            Some(recipe_origin_hash) => recipe_origin_hash,
+            None => continue,
+        };
+        if let Some(record) = get(recipe_origin_hash, GetOptions::default())? {
+            if let Some(recipe_origin) = record.entry().to_app_option::<RecipeOrigin>().map_err(|e| wasm_error!(e))? {
+                return Ok(Some(recipe_origin));
+            }
+        }
+    }
+    Ok(None)
+}
@@ -0,0 +1,107 @@
+use hdk::prelude::*;
+use condenser_common::CondenserError;
+use cravings_integrity::*;
+use crate::lobby_admins::get_admin_role_hash;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SetRecipeStatusInput {
+    pub dna_recipe_hash: ActionHash,
+    pub state: RecipeState,
+    #[serde(default)]
+    pub superseded_by: Option<ActionHash>,
+}
+
+/// Sets the status of a recipe. Allowed for the author of the recipe and, in admin mode,
+/// for the creator and the admins of the lobby.
+#[hdk_extern]
+pub fn set_recipe_status(input: SetRecipeStatusInput) -> ExternResult<Record> {
+    let dna_recipe_record = get(input.dna_recipe_hash.clone(), GetOptions::default())?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(CondenserError::NotFound { what: String::from("DnaRecipe") }.encode())
+            ),
+        )?;
+    let my_pubkey = agent_info()?.agent_initial_pubkey;
+    let admin_role_hash = match dna_recipe_record.action().author() == &my_pubkey {
+        true => None,
+        false => get_admin_role_hash(&my_pubkey)?,
+    };
+    let recipe_status = RecipeStatus {
+        dna_recipe_hash: input.dna_recipe_hash.clone(),
+        state: input.state,
+        superseded_by: input.superseded_by,
+        admin_role_hash,
+    };
+    let recipe_status_hash = create_entry(&EntryTypes::RecipeStatus(recipe_status))?;
+    create_link(
+        input.dna_recipe_hash,
+        recipe_status_hash.clone(),
+        LinkTypes::RecipeToStatuses,
+        (),
+    )?;
+    get(recipe_status_hash, GetOptions::default())?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(CondenserError::NotFound { what: String::from("RecipeStatus") }.encode())
+            ),
+        )
+}
+
+/// Picks the link to the latest status among the status links of a recipe.
+fn latest_status_hash(links: Vec<Link>) -> Option<ActionHash> {
+    links
+        .into_iter()
+        .max_by(|a, b| {
+            a.timestamp
+                .cmp(&b.timestamp)
+                .then_with(|| a.create_link_hash.cmp(&b.create_link_hash))
+        })
+        .and_then(|link| link.target.into_action_hash())
+}
+
+/// Gets the latest status record of the recipe, None if it never got one and is thus active.
+#[hdk_extern]
+pub fn get_recipe_status(dna_recipe_hash: ActionHash) -> ExternResult<Option<Record>> {
+    match latest_status_hash(get_links(dna_recipe_hash, LinkTypes::RecipeToStatuses, None)?) {
+        Some(recipe_status_hash) => get(recipe_status_hash, GetOptions::default()),
+        None => Ok(None),
+    }
+}
+
+/// Gets the states of the given recipes with one batch of link and one batch of record lookups.
+pub fn get_recipe_states(dna_recipe_hashes: Vec<ActionHash>) -> ExternResult<Vec<RecipeState>> {
+    let get_links_input: Vec<GetLinksInput> = dna_recipe_hashes
+        .into_iter()
+        .map(|dna_recipe_hash| {
+            Ok(GetLinksInput::new(
+                dna_recipe_hash.into(),
+                LinkTypes::RecipeToStatuses.try_into_filter()?,
+                None,
+            ))
+        })
+        .collect::<ExternResult<_>>()?;
+    let status_hashes: Vec<Option<ActionHash>> = HDK
+        .with(|hdk| hdk.borrow().get_links(get_links_input))?
+        .into_iter()
+        .map(latest_status_hash)
+        .collect();
+    let get_input: Vec<GetInput> = status_hashes
+        .iter()
+        .flatten()
+        .map(|hash| GetInput::new(hash.clone().into(), GetOptions::default()))
+        .collect();
+    let mut status_records = HDK.with(|hdk| hdk.borrow().get(get_input))?.into_iter();
+    let mut states = Vec::new();
+    for status_hash in status_hashes {
+        let record = match status_hash {
+            Some(_) => status_records.next().flatten(),
+            None => None,
+        };
+        let recipe_status: Option<RecipeStatus> = match record {
+            Some(record) => record.entry().to_app_option().map_err(|e| wasm_error!(e))?,
+            None => None,
+        };
+        states.push(recipe_status.map_or(RecipeState::Active, |recipe_status| recipe_status.state));
+    }
+    Ok(states)
+}
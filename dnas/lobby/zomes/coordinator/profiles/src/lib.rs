@@ -1 +1,221 @@
-extern crate hc_zome_profiles_coordinator;
\ No newline at end of file
+pub mod profile;
+use hdk::prelude::*;
+use condenser_common::CondenserError;
+use profiles_integrity::*;
+#[hdk_extern]
+pub fn init(_: ()) -> ExternResult<InitCallbackResult> {
+    Ok(InitCallbackResult::Pass)
+}
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(tag = "type")]
+pub enum Signal {
+    EntryCreated { action: SignedActionHashed, app_entry: EntryTypes, record: Record },
+    EntryUpdated {
+        action: SignedActionHashed,
+        record: Record,
+        original_record: Box<Record>,
+        app_entry: EntryTypes,
+        original_app_entry: EntryTypes,
+    },
+    EntryDeleted { action: SignedActionHashed, original_app_entry: EntryTypes },
+    LinkCreated { action: SignedActionHashed, link_type: LinkTypes },
+    LinkDeleted { action: SignedActionHashed, link_type: LinkTypes },
+}
+#[hdk_extern(infallible)]
+pub fn post_commit(committed_actions: Vec<SignedActionHashed>) {
+    for action in committed_actions {
+        if let Err(err) = signal_action(action) {
+            error!("Error signaling new action: {:?}", err);
+        }
+    }
+}
+fn signal_action(action: SignedActionHashed) -> ExternResult<()> {
+    match action.hashed.content.clone() {
+        Action::Create(_create) => {
+            let (maybe_record, maybe_app_entry) = get_entry_and_record_for_action(
+                &action.hashed.hash,
+            )?;
+            let (record, app_entry) = match (maybe_record, maybe_app_entry) {
+                (Some(record), Some(app_entry)) => (record, app_entry),
+                _ => {
+                    return Err(
+                        wasm_error!(
+                            WasmErrorInner::Guest(CondenserError::MalformedOp { reason: String::from("Create should carry an entry") }.encode())
+                        ),
+                    );
+                }
+            };
+            emit_signal(Signal::EntryCreated {
+                action,
+                app_entry,
+                record,
+            })?;
+            Ok(())
+        }
+        Action::Update(update) => {
+            let (maybe_record, maybe_app_entry) = get_entry_and_record_for_action(
+                &action.hashed.hash,
+            )?;
+            let (record, app_entry) = match (maybe_record, maybe_app_entry) {
+                (Some(record), Some(app_entry)) => (record, app_entry),
+                _ => {
+                    return Err(
+                        wasm_error!(
+                            WasmErrorInner::Guest(CondenserError::MalformedOp { reason: String::from("Update should carry an entry") }.encode())
+                        ),
+                    );
+                }
+            };
+            let (maybe_original_record, maybe_original_app_entry) = get_entry_and_record_for_action(
+                &update.original_action_address,
+            )?;
+            let (original_record, original_app_entry) = match (
+                maybe_original_record,
+                maybe_original_app_entry,
+            ) {
+                (Some(record), Some(app_entry)) => (record, app_entry),
+                _ => {
+                    return Err(
+                        wasm_error!(
+                            WasmErrorInner::Guest(CondenserError::MalformedOp { reason: String::from("Update should carry an entry") }.encode())
+                        ),
+                    );
+                }
+            };
+            emit_signal(Signal::EntryUpdated {
+                action,
+                record,
+                app_entry,
+                original_record: Box::new(original_record),
+                original_app_entry,
+            })?;
+            Ok(())
+        }
+        Action::Delete(delete) => {
+            let original_app_entry = get_entry_for_action(&delete.deletes_address)?
+                .ok_or(
+                    wasm_error!(
+                        WasmErrorInner::Guest(CondenserError::MalformedOp { reason: String::from("Deleted action should carry an entry") }.encode())
+                    ),
+                )?;
+            emit_signal(Signal::EntryDeleted {
+                action,
+                original_app_entry,
+            })?;
+            Ok(())
+        }
+        Action::CreateLink(create_link) => {
+            let link_type = LinkTypes::from_type(
+                    create_link.zome_index,
+                    create_link.link_type,
+                )?
+                .ok_or(
+                    wasm_error!(
+                        WasmErrorInner::Guest(CondenserError::MalformedOp { reason: String::from("Link type should be exist") }.encode())
+                    ),
+                )?;
+            emit_signal(Signal::LinkCreated {
+                action,
+                link_type,
+            })?;
+            Ok(())
+        }
+        Action::DeleteLink(delete_link) => {
+            let record = get(
+                    delete_link.link_add_address.clone(),
+                    GetOptions::default(),
+                )?
+                .ok_or(
+                    wasm_error!(
+                        WasmErrorInner::Guest(CondenserError::MalformedOp { reason: String::from("Create Link should exist") }.encode())
+                    ),
+                )?;
+            match record.action() {
+                Action::CreateLink(create_link) => {
+                    let link_type = LinkTypes::from_type(
+                            create_link.zome_index,
+                            create_link.link_type,
+                        )?
+                        .ok_or(
+                            wasm_error!(
+                                WasmErrorInner::Guest(CondenserError::MalformedOp { reason: String::from("Link type should be exist") }.encode())
+                            ),
+                        )?;
+                    emit_signal(Signal::LinkDeleted {
+                        action,
+                        link_type,
+                    })?;
+                    Ok(())
+                }
+                _ => {
+                    Err(
+                        wasm_error!(
+                            WasmErrorInner::Guest(CondenserError::MalformedOp { reason: String::from("Create Link should exist") }.encode())
+                        ),
+                    )
+                }
+            }
+        }
+        _ => Ok(()),
+    }
+}
+fn get_entry_for_action(action_hash: &ActionHash) -> ExternResult<Option<EntryTypes>> {
+    let record = match get_details(action_hash.clone(), GetOptions::default())? {
+        Some(Details::Record(record_details)) => record_details.record,
+        _ => {
+            return Ok(None);
+        }
+    };
+    let entry = match record.entry().as_option() {
+        Some(entry) => entry,
+        None => {
+            return Ok(None);
+        }
+    };
+    let (zome_index, entry_index) = match record.action().entry_type() {
+        Some(EntryType::App(AppEntryDef { zome_index, entry_index, .. })) => {
+            (zome_index, entry_index)
+        }
+        _ => {
+            return Ok(None);
+        }
+    };
+    EntryTypes::deserialize_from_type(
+        *zome_index,
+        *entry_index,
+        entry,
+    )
+}
+fn get_entry_and_record_for_action(
+    action_hash: &ActionHash,
+) -> ExternResult<(Option<Record>, Option<EntryTypes>)> {
+    let record = match get_details(action_hash.clone(), GetOptions::default())? {
+        Some(Details::Record(record_details)) => record_details.record,
+        _ => {
+            return Ok((None, None));
+        }
+    };
+    let record_clone = record.clone();
+    let entry = match record.entry().as_option() {
+        Some(entry) => entry,
+        None => {
+            return Ok((Some(record), None));
+        }
+    };
+    let (zome_index, entry_index) = match record.action().entry_type() {
+        Some(EntryType::App(AppEntryDef { zome_index, entry_index, .. })) => {
+            (zome_index, entry_index)
+        }
+        _ => {
+            return Ok((Some(record), None));
+        }
+    };
+    Ok((
+        Some(record_clone),
+        EntryTypes::deserialize_from_type(
+            *zome_index,
+            *entry_index,
+            entry,
+        )?,
+    ))
+}
@@ -0,0 +1,165 @@
+use hdk::prelude::*;
+use hdk::hash_path::path::Component;
+use condenser_common::CondenserError;
+use profiles_integrity::*;
+
+const ALL_PROFILES: &str = "all_profiles";
+/// Agents are listed under the first characters of their lowercased nickname
+const NICKNAME_PREFIX_CHARS: usize = MIN_NICKNAME_CHARS;
+
+fn nickname_prefix_path(nickname: &str) -> ExternResult<TypedPath> {
+    let prefix: String = nickname.to_lowercase().chars().take(NICKNAME_PREFIX_CHARS).collect();
+    Path::from(vec![Component::from(ALL_PROFILES), Component::from(prefix)]).typed(LinkTypes::PrefixPath)
+}
+
+fn nickname_tag(nickname: &str) -> LinkTag {
+    LinkTag::new(nickname.to_lowercase().into_bytes())
+}
+
+fn get_agent_profile_links(agent: AgentPubKey) -> ExternResult<Vec<Link>> {
+    let mut links = get_links(agent, LinkTypes::AgentToProfile, None)?;
+    links.sort_by_key(|link| link.timestamp);
+    Ok(links)
+}
+
+fn check_profile(profile: &Profile) -> ExternResult<()> {
+    match validate_profile(profile) {
+        Some(err) => Err(wasm_error!(WasmErrorInner::Guest(err.encode()))),
+        None => Ok(()),
+    }
+}
+
+/// Lists me under the prefix of my nickname and links my public key to the profile.
+fn link_profile(profile: &Profile, profile_hash: ActionHash) -> ExternResult<()> {
+    let my_pubkey = agent_info()?.agent_initial_pubkey;
+    let path = nickname_prefix_path(&profile.nickname)?;
+    path.ensure()?;
+    create_link(
+        path.path_entry_hash()?,
+        my_pubkey.clone(),
+        LinkTypes::PathToAgent,
+        nickname_tag(&profile.nickname),
+    )?;
+    create_link(my_pubkey, profile_hash, LinkTypes::AgentToProfile, ())?;
+    Ok(())
+}
+
+fn get_profile_record(profile_hash: ActionHash) -> ExternResult<Record> {
+    get(profile_hash, GetOptions::default())?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(CondenserError::NotFound { what: String::from("Profile") }.encode())
+            ),
+        )
+}
+
+#[hdk_extern]
+pub fn create_profile(profile: Profile) -> ExternResult<Record> {
+    check_profile(&profile)?;
+    let my_pubkey = agent_info()?.agent_initial_pubkey;
+    if !get_agent_profile_links(my_pubkey)?.is_empty() {
+        return Err(
+            wasm_error!(
+                WasmErrorInner::Guest(CondenserError::AlreadyExists { what: String::from("Profile") }.encode())
+            ),
+        );
+    }
+    let profile_hash = create_entry(&EntryTypes::Profile(profile.clone()))?;
+    link_profile(&profile, profile_hash.clone())?;
+    get_profile_record(profile_hash)
+}
+
+#[hdk_extern]
+pub fn update_profile(profile: Profile) -> ExternResult<Record> {
+    check_profile(&profile)?;
+    let my_pubkey = agent_info()?.agent_initial_pubkey;
+    let profile_links = get_agent_profile_links(my_pubkey.clone())?;
+    let previous_profile_hash = profile_links
+        .last()
+        .and_then(|link| link.target.clone().into_action_hash())
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(CondenserError::NotFound { what: String::from("Profile") }.encode())
+            ),
+        )?;
+    let previous_profile: Profile = get_profile_record(previous_profile_hash.clone())?
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(CondenserError::MissingEntry.encode())
+            ),
+        )?;
+    let profile_hash = update_entry(previous_profile_hash, &profile)?;
+
+    for link in profile_links {
+        delete_link(link.create_link_hash)?;
+    }
+    let previous_path = nickname_prefix_path(&previous_profile.nickname)?;
+    for link in get_links(previous_path.path_entry_hash()?, LinkTypes::PathToAgent, None)? {
+        if link.target.clone().into_agent_pub_key().as_ref() == Some(&my_pubkey) {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+    link_profile(&profile, profile_hash.clone())?;
+    get_profile_record(profile_hash)
+}
+
+/// Gets the latest profile of the given agent, if they have created one.
+#[hdk_extern]
+pub fn get_agent_profile(agent: AgentPubKey) -> ExternResult<Option<Record>> {
+    match get_agent_profile_links(agent)?
+        .pop()
+        .and_then(|link| link.target.into_action_hash())
+    {
+        Some(profile_hash) => get(profile_hash, GetOptions::default()),
+        None => Ok(None),
+    }
+}
+
+/// Gets the agents whose nickname starts with the given filter (case-insensitive).
+#[hdk_extern]
+pub fn search_agents(nickname_filter: String) -> ExternResult<Vec<AgentPubKey>> {
+    let filter_chars = nickname_filter.chars().count();
+    if filter_chars < NICKNAME_PREFIX_CHARS {
+        return Err(
+            wasm_error!(
+                WasmErrorInner::Guest(
+                    CondenserError::TooShort {
+                        field: String::from("nickname_filter"),
+                        min: NICKNAME_PREFIX_CHARS,
+                        actual: filter_chars,
+                    }.encode(),
+                )
+            ),
+        );
+    }
+    let links = get_links(
+        nickname_prefix_path(&nickname_filter)?.path_entry_hash()?,
+        LinkTypes::PathToAgent,
+        Some(nickname_tag(&nickname_filter)),
+    )?;
+    Ok(dedup_agents(links))
+}
+
+/// Gets all agents that have created a profile.
+#[hdk_extern]
+pub fn get_agents_with_profile(_: ()) -> ExternResult<Vec<AgentPubKey>> {
+    let root = Path::from(ALL_PROFILES).typed(LinkTypes::PrefixPath)?;
+    let mut links: Vec<Link> = Vec::new();
+    for path in root.children_paths()? {
+        links.extend(get_links(path.path_entry_hash()?, LinkTypes::PathToAgent, None)?);
+    }
+    Ok(dedup_agents(links))
+}
+
+fn dedup_agents(links: Vec<Link>) -> Vec<AgentPubKey> {
+    let mut agents: Vec<AgentPubKey> = Vec::new();
+    for agent in links.into_iter().filter_map(|link| link.target.into_agent_pub_key()) {
+        if !agents.contains(&agent) {
+            agents.push(agent);
+        }
+    }
+    agents
+}
@@ -1,24 +1,95 @@
 use hdi::prelude::*;
+use condenser_common::{CondenserError, dna_hash_anchor};
+use crate::{must_get_app_entry, LinkTypes, LobbyDnaProperties, LobbyInfo};
+/// The base of the canonical link to the original LobbyInfo, derived from the DNA hash
+/// so that validation can recognize it.
+pub fn lobby_info_anchor() -> ExternResult<AnyLinkableHash> {
+    Ok(dna_hash_anchor(&dna_info()?.hash))
+}
+/// Checks whether the author already linked a LobbyInfo from the canonical anchor before this action.
+fn has_linked_lobby_info_before(action: &CreateLink, anchor: &AnyLinkableHash) -> ExternResult<bool> {
+    let scoped_link_type = ScopedLinkType::try_from(LinkTypes::AnchorToLobbyInfo)?;
+    let activity = must_get_agent_activity(action.author.clone(), ChainFilter::new(action.prev_action.clone()))?;
+    Ok(
+        activity.iter().any(|activity| match activity.action.action() {
+            Action::CreateLink(create_link) => {
+                create_link.zome_index == scoped_link_type.zome_index
+                    && create_link.link_type == scoped_link_type.zome_type
+                    && &create_link.base_address == anchor
+            }
+            _ => false,
+        }),
+    )
+}
+/// Links can either hang off the canonical anchor, pointing to the original LobbyInfo, or
+/// off the public key of their author. If the lobby has a creator, only the creator can link
+/// from the canonical anchor, and only once, so there is exactly one canonical LobbyInfo.
+/// Without a creator every agent can link at most one LobbyInfo from the canonical anchor,
+/// so validation only guarantees one canonical LobbyInfo per agent, not one in total.
+/// Validation can't see other agents' chains, so concurrent links of several agents are
+/// settled by the coordinator: `get_lobby_info` picks the earliest one for everyone.
 pub fn validate_create_link_anchor_to_lobby_infos(
-    _action: CreateLink,
-    _base_address: AnyLinkableHash,
-    _target_address: AnyLinkableHash,
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    // Check the entry type for the given action hash
-    // let action_hash = ActionHash::from(target_address);
-    // let record = must_get_valid_record(action_hash)?;
-    // let _lobby_info: crate::LobbyInfo = record
-    //     .entry()
-    //     .to_app_option()
-    //     .map_err(|e| wasm_error!(e))?
-    //     .ok_or(
-    //         wasm_error!(
-    //             WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
-    //         ),
-    //     )?;
-    // TODO: add the appropriate validation rules
-    Ok(ValidateCallbackResult::Valid)
+    let action_hash = ActionHash::try_from(target_address).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
+    let record = match must_get_app_entry::<LobbyInfo>(action_hash)? {
+        Some((record, _)) => record,
+        None => {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    CondenserError::WrongType { expected: String::from("LobbyInfo") }.encode(),
+                ),
+            );
+        }
+    };
+    let anchor = lobby_info_anchor()?;
+    if base_address == anchor {
+        if !matches!(record.action(), Action::Create(_)) || record.action().author() != &action.author {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    CondenserError::NotAllowed {
+                        reason: String::from("the lobby info anchor may only link to an original LobbyInfo of the link author"),
+                    }.encode(),
+                ),
+            );
+        }
+        let creator = LobbyDnaProperties::get()?.creator;
+        if creator.is_some_and(|creator| creator != action.author) {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    CondenserError::NotAuthor { what: String::from("canonical LobbyInfo of the lobby creator") }.encode(),
+                ),
+            );
+        }
+        if has_linked_lobby_info_before(&action, &anchor)? {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    CondenserError::AlreadyExists { what: String::from("LobbyInfo") }.encode(),
+                ),
+            );
+        }
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    match base_address.into_agent_pub_key() {
+        Some(agent) if agent == action.author => Ok(ValidateCallbackResult::Valid),
+        Some(_) => {
+            Ok(
+                ValidateCallbackResult::Invalid(
+                    CondenserError::NotAuthor { what: String::from("AnchorToLobbyInfo link") }.encode(),
+                ),
+            )
+        }
+        None => {
+            Ok(
+                ValidateCallbackResult::Invalid(
+                    CondenserError::WrongType { expected: String::from("lobby info anchor or AgentPubKey") }.encode(),
+                ),
+            )
+        }
+    }
 }
 pub fn validate_delete_link_anchor_to_lobby_infos(
     _action: DeleteLink,
@@ -29,7 +100,7 @@ pub fn validate_delete_link_anchor_to_lobby_infos(
 ) -> ExternResult<ValidateCallbackResult> {
     Ok(
         ValidateCallbackResult::Invalid(
-            String::from("AnchorToLobbyInfo links cannot be deleted"),
+            CondenserError::NotDeletable { what: String::from("AnchorToLobbyInfo link") }.encode(),
         ),
     )
 }
@@ -0,0 +1,155 @@
+use hdi::prelude::*;
+use condenser_common::CondenserError;
+use crate::{must_get_app_entry, must_get_created_entry, DnaRecipe};
+pub const MAX_ANNOUNCEMENT_TITLE_CHARS: usize = 100;
+pub const MAX_ANNOUNCEMENT_BODY_CHARS: usize = 2000;
+/// A message to all members of the lobby, e.g. about a new craving.
+#[hdk_entry_helper]
+#[derive(Clone)]
+pub struct Announcement {
+    pub title: String,
+    pub body: String,
+    /// The craving recipe the announcement is about, if any
+    pub dna_recipe_hash: Option<ActionHash>,
+    /// After this time the announcement is no longer listed
+    pub expires_at: Option<Timestamp>,
+}
+fn validate_announcement_text(field: &str, text: &str, max: usize) -> Option<CondenserError> {
+    let chars = text.trim().chars().count();
+    if chars == 0 {
+        return Some(CondenserError::TooShort { field: String::from(field), min: 1, actual: chars });
+    }
+    if chars > max {
+        return Some(CondenserError::TooLong { field: String::from(field), max, actual: chars });
+    }
+    None
+}
+pub fn validate_create_announcement(
+    action: EntryCreationAction,
+    announcement: Announcement,
+) -> ExternResult<ValidateCallbackResult> {
+    let text_error = validate_announcement_text("title", &announcement.title, MAX_ANNOUNCEMENT_TITLE_CHARS)
+        .or_else(|| validate_announcement_text("body", &announcement.body, MAX_ANNOUNCEMENT_BODY_CHARS));
+    if let Some(err) = text_error {
+        return Ok(ValidateCallbackResult::Invalid(err.encode()));
+    }
+    if let Some(dna_recipe_hash) = announcement.dna_recipe_hash {
+        if must_get_created_entry::<DnaRecipe>(dna_recipe_hash)?.is_none() {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    CondenserError::WrongType { expected: String::from("DnaRecipe") }.encode(),
+                ),
+            );
+        }
+    }
+    if announcement.expires_at.is_some_and(|expires_at| &expires_at <= action.timestamp()) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAllowed { reason: String::from("announcements must expire in the future") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_announcement(
+    action: Update,
+    _announcement: Announcement,
+    original_action: EntryCreationAction,
+    _original_announcement: Announcement,
+) -> ExternResult<ValidateCallbackResult> {
+    if &action.author != original_action.author() {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("Announcement") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_announcement(
+    action: Delete,
+    original_action: EntryCreationAction,
+    _original_announcement: Announcement,
+) -> ExternResult<ValidateCallbackResult> {
+    if &action.author != original_action.author() {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("Announcement") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+/// Checks that the link points to an announcement of its author.
+fn validate_announcement_link_target(
+    action: &CreateLink,
+    target_address: AnyLinkableHash,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = ActionHash::try_from(target_address).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
+    match must_get_app_entry::<Announcement>(action_hash)? {
+        Some((record, _)) if record.action().author() == &action.author => Ok(ValidateCallbackResult::Valid),
+        Some(_) => {
+            Ok(
+                ValidateCallbackResult::Invalid(
+                    CondenserError::NotAuthor { what: String::from("Announcement") }.encode(),
+                ),
+            )
+        }
+        None => {
+            Ok(
+                ValidateCallbackResult::Invalid(
+                    CondenserError::WrongType { expected: String::from("Announcement") }.encode(),
+                ),
+            )
+        }
+    }
+}
+pub fn validate_create_link_announcement_updates(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let result = validate_announcement_link_target(&action, base_address)?;
+    if result != ValidateCallbackResult::Valid {
+        return Ok(result);
+    }
+    validate_announcement_link_target(&action, target_address)
+}
+pub fn validate_delete_link_announcement_updates(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            CondenserError::NotDeletable { what: String::from("AnnouncementUpdates link") }.encode(),
+        ),
+    )
+}
+pub fn validate_create_link_all_announcements(
+    action: CreateLink,
+    _base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    validate_announcement_link_target(&action, target_address)
+}
+pub fn validate_delete_link_all_announcements(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("AllAnnouncements link") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
@@ -0,0 +1,139 @@
+use hdi::prelude::*;
+use condenser_common::CondenserError;
+use crate::{must_get_created_entry, validate_text_length, DnaRecipe, MAX_CHARS_LIMIT};
+
+pub const MAX_PREVIEW_ASSOCIATIONS: usize = 10;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PreviewAssociation {
+    pub association: String,
+    pub resonators: usize,
+}
+
+/// A snapshot of what is going on in a craving, published to the lobby by its
+/// participants so that members can see what the craving is about before joining it.
+/// The craving DNA can't be read from the lobby, so the associations and counts are
+/// self-reported by the author and can't be validated. Show them as the author's claim,
+/// not as verified activity.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct CravingPreview {
+    pub dna_recipe_hash: ActionHash,
+    /// The associations with the most resonators, most resonated first
+    pub top_associations: Vec<PreviewAssociation>,
+    pub association_count: usize,
+    pub offer_count: usize,
+    pub reflection_count: usize,
+    pub anecdote_count: usize,
+    pub participant_count: usize,
+    /// When the latest association, offer, reflection or anecdote was created
+    pub last_activity: Option<Timestamp>,
+}
+pub fn validate_create_craving_preview(
+    action: EntryCreationAction,
+    craving_preview: CravingPreview,
+) -> ExternResult<ValidateCallbackResult> {
+    if craving_preview.top_associations.len() > MAX_PREVIEW_ASSOCIATIONS {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::TooLong {
+                    field: String::from("top_associations"),
+                    max: MAX_PREVIEW_ASSOCIATIONS,
+                    actual: craving_preview.top_associations.len(),
+                }.encode(),
+            ),
+        );
+    }
+    for preview_association in &craving_preview.top_associations {
+        if let Some(err) = validate_text_length("top_associations.association", &preview_association.association, MAX_CHARS_LIMIT) {
+            return Ok(ValidateCallbackResult::Invalid(err.encode()));
+        }
+    }
+    if craving_preview.last_activity.is_some_and(|last_activity| &last_activity > action.timestamp()) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAllowed { reason: String::from("last_activity can't be in the future") }.encode(),
+            ),
+        );
+    }
+    if must_get_created_entry::<DnaRecipe>(craving_preview.dna_recipe_hash)?.is_none() {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::WrongType { expected: String::from("DnaRecipe") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_craving_preview(
+    _action: Update,
+    _craving_preview: CravingPreview,
+    _original_action: EntryCreationAction,
+    _original_craving_preview: CravingPreview,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(CondenserError::NotUpdatable { what: String::from("CravingPreview") }.encode()))
+}
+pub fn validate_delete_craving_preview(
+    action: Delete,
+    original_action: EntryCreationAction,
+    _original_craving_preview: CravingPreview,
+) -> ExternResult<ValidateCallbackResult> {
+    if &action.author != original_action.author() {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("CravingPreview") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_create_link_dna_recipe_to_craving_previews(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = ActionHash::try_from(target_address).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
+    let record = must_get_valid_record(action_hash)?;
+    if record.action().author() != &action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("CravingPreview") }.encode(),
+            ),
+        );
+    }
+    let craving_preview: CravingPreview = match record.entry().to_app_option().ok().flatten() {
+        Some(craving_preview) => craving_preview,
+        None => {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    CondenserError::WrongType { expected: String::from("CravingPreview") }.encode(),
+                ),
+            );
+        }
+    };
+    if base_address != AnyLinkableHash::from(craving_preview.dna_recipe_hash) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::WrongType { expected: String::from("DnaRecipe of the CravingPreview") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_dna_recipe_to_craving_previews(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("DnaRecipeToCravingPreviews link") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
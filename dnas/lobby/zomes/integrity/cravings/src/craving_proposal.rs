@@ -0,0 +1,262 @@
+use hdi::prelude::*;
+use condenser_common::CondenserError;
+use crate::{
+    must_get_created_entry, validate_chars_limits, validate_text_length, DnaRecipe, LinkTypes,
+    LobbyDnaProperties, MAX_RECIPE_DESCRIPTION_CHARS, MAX_RECIPE_TITLE_CHARS,
+};
+pub const DEFAULT_PROPOSAL_THRESHOLD: usize = 3;
+/// Higher thresholds are capped so that the resonance hashes fit into the 1 KB link tag
+/// of the CravingProposalToDnaRecipe link.
+pub const MAX_PROPOSAL_THRESHOLD: usize = 20;
+/// A craving that doesn't exist yet. Once enough members resonated with it, it can be
+/// promoted to a DnaRecipe with the same title, description and limits.
+#[hdk_entry_helper]
+#[derive(Clone)]
+pub struct CravingProposal {
+    pub title: String,
+    pub description: String,
+    pub max_anecdote_chars: Option<usize>,
+    pub max_association_chars: Option<usize>,
+    pub max_offer_chars: Option<usize>,
+    pub max_reflection_chars: Option<usize>,
+}
+/// Tag of the CravingProposalToDnaRecipe link: the resonances that justify the promotion
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+pub struct CravingProposalPromotion {
+    /// Hashes of exactly `proposal_threshold` CravingProposalToResonators links, each of a
+    /// different member
+    pub resonance_hashes: Vec<ActionHash>,
+}
+impl LobbyDnaProperties {
+    pub fn proposal_threshold(&self) -> usize {
+        self.proposal_threshold
+            .unwrap_or(DEFAULT_PROPOSAL_THRESHOLD)
+            .min(MAX_PROPOSAL_THRESHOLD)
+    }
+}
+pub fn validate_create_craving_proposal(
+    _action: EntryCreationAction,
+    craving_proposal: CravingProposal,
+) -> ExternResult<ValidateCallbackResult> {
+    let text_error = validate_text_length("title", &craving_proposal.title, MAX_RECIPE_TITLE_CHARS)
+        .or_else(|| validate_text_length("description", &craving_proposal.description, MAX_RECIPE_DESCRIPTION_CHARS));
+    if let Some(err) = text_error {
+        return Ok(ValidateCallbackResult::Invalid(err.encode()));
+    }
+    let limits = [
+        ("max_anecdote_chars", craving_proposal.max_anecdote_chars),
+        ("max_association_chars", craving_proposal.max_association_chars),
+        ("max_offer_chars", craving_proposal.max_offer_chars),
+        ("max_reflection_chars", craving_proposal.max_reflection_chars),
+    ];
+    match validate_chars_limits(limits) {
+        Some(err) => Ok(ValidateCallbackResult::Invalid(err.encode())),
+        None => Ok(ValidateCallbackResult::Valid),
+    }
+}
+pub fn validate_update_craving_proposal(
+    _action: Update,
+    _craving_proposal: CravingProposal,
+    _original_action: EntryCreationAction,
+    _original_craving_proposal: CravingProposal,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(CondenserError::NotUpdatable { what: String::from("CravingProposal") }.encode()))
+}
+pub fn validate_delete_craving_proposal(
+    action: Delete,
+    original_action: EntryCreationAction,
+    _original_craving_proposal: CravingProposal,
+) -> ExternResult<ValidateCallbackResult> {
+    if &action.author != original_action.author() {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("CravingProposal") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+fn not_a_craving_proposal() -> ValidateCallbackResult {
+    ValidateCallbackResult::Invalid(CondenserError::WrongType { expected: String::from("CravingProposal") }.encode())
+}
+pub fn validate_create_link_all_craving_proposals(
+    action: CreateLink,
+    _base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = ActionHash::try_from(target_address).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
+    match must_get_created_entry::<CravingProposal>(action_hash)? {
+        Some((author, _)) if author == action.author => Ok(ValidateCallbackResult::Valid),
+        Some(_) => {
+            Ok(
+                ValidateCallbackResult::Invalid(
+                    CondenserError::NotAuthor { what: String::from("CravingProposal") }.encode(),
+                ),
+            )
+        }
+        None => Ok(not_a_craving_proposal()),
+    }
+}
+pub fn validate_delete_link_all_craving_proposals(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("AllCravingProposals link") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+/// Members resonate with a proposal by linking it to their own public key.
+pub fn validate_create_link_craving_proposal_to_resonators(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = ActionHash::try_from(base_address).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
+    if must_get_created_entry::<CravingProposal>(action_hash)?.is_none() {
+        return Ok(not_a_craving_proposal());
+    }
+    if target_address.into_agent_pub_key().as_ref() != Some(&action.author) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("CravingProposalToResonators link") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_craving_proposal_to_resonators(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("CravingProposalToResonators link") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+/// Checks that the resonances are links to the proposal of exactly as many different
+/// members as the threshold requires.
+///
+/// Validation can only see that the resonances were created, not whether they were
+/// withdrawn later on, so withdrawals are only respected by the coordinator zome.
+fn check_resonances(
+    craving_proposal_hash: &ActionHash,
+    promotion: &CravingProposalPromotion,
+) -> ExternResult<Option<CondenserError>> {
+    let threshold = LobbyDnaProperties::get()?.proposal_threshold();
+    if promotion.resonance_hashes.len() != threshold {
+        return Ok(Some(CondenserError::NotAllowed {
+            reason: format!("the promotion needs exactly {} resonances but has {}", threshold, promotion.resonance_hashes.len()),
+        }));
+    }
+    let scoped_link_type = ScopedLinkType::try_from(LinkTypes::CravingProposalToResonators)?;
+    let mut resonators: Vec<AgentPubKey> = Vec::new();
+    for resonance_hash in &promotion.resonance_hashes {
+        let resonance_action = must_get_action(resonance_hash.clone())?;
+        let is_resonance = match resonance_action.action() {
+            Action::CreateLink(create_link) => {
+                create_link.zome_index == scoped_link_type.zome_index
+                    && create_link.link_type == scoped_link_type.zome_type
+                    && create_link.base_address == AnyLinkableHash::from(craving_proposal_hash.clone())
+            }
+            _ => false,
+        };
+        if !is_resonance {
+            return Ok(Some(CondenserError::InvalidLinkTag { expected: String::from("resonances with the CravingProposal") }));
+        }
+        let resonator = resonance_action.action().author().clone();
+        if resonators.contains(&resonator) {
+            return Ok(Some(CondenserError::InvalidLinkTag { expected: String::from("resonances of different members") }));
+        }
+        resonators.push(resonator);
+    }
+    Ok(None)
+}
+/// The promoted recipe must be one of the link author and match the proposal.
+pub fn validate_create_link_craving_proposal_to_dna_recipe(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let craving_proposal_hash = ActionHash::try_from(base_address).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
+    let craving_proposal = match must_get_created_entry::<CravingProposal>(craving_proposal_hash.clone())? {
+        Some((_, craving_proposal)) => craving_proposal,
+        None => return Ok(not_a_craving_proposal()),
+    };
+    let dna_recipe_hash = ActionHash::try_from(target_address).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
+    if must_get_action(dna_recipe_hash.clone())?.action().author() != &action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("DnaRecipe") }.encode(),
+            ),
+        );
+    }
+    let dna_recipe = match must_get_created_entry::<DnaRecipe>(dna_recipe_hash)? {
+        Some((_, dna_recipe)) => dna_recipe,
+        None => {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    CondenserError::WrongType { expected: String::from("DnaRecipe") }.encode(),
+                ),
+            );
+        }
+    };
+    let properties = &dna_recipe.properties;
+    let matches_proposal = properties.title == craving_proposal.title
+        && properties.description == craving_proposal.description
+        && properties.max_anecdote_chars == craving_proposal.max_anecdote_chars
+        && properties.max_association_chars == craving_proposal.max_association_chars
+        && properties.max_offer_chars == craving_proposal.max_offer_chars
+        && properties.max_reflection_chars == craving_proposal.max_reflection_chars;
+    if !matches_proposal {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::WrongType { expected: String::from("DnaRecipe matching the CravingProposal") }.encode(),
+            ),
+        );
+    }
+    let promotion = match CravingProposalPromotion::try_from(SerializedBytes::from(UnsafeBytes::from(tag.into_inner()))) {
+        Ok(promotion) => promotion,
+        Err(_) => {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    CondenserError::InvalidLinkTag { expected: String::from("CravingProposalPromotion") }.encode(),
+                ),
+            );
+        }
+    };
+    match check_resonances(&craving_proposal_hash, &promotion)? {
+        Some(err) => Ok(ValidateCallbackResult::Invalid(err.encode())),
+        None => Ok(ValidateCallbackResult::Valid),
+    }
+}
+pub fn validate_delete_link_craving_proposal_to_dna_recipe(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            CondenserError::NotDeletable { what: String::from("CravingProposalToDnaRecipe link") }.encode(),
+        ),
+    )
+}
@@ -0,0 +1,124 @@
+use hdi::prelude::*;
+use condenser_common::CondenserError;
+use crate::{must_get_created_entry, DnaRecipe};
+
+/// Root of the path tree the tags are anchored in, one child path per tag
+pub const CRAVING_TAGS: &str = "craving_tags";
+pub const MAX_CRAVING_TAG_CHARS: usize = 30;
+pub const MAX_CRAVING_TAGS_PER_RECIPE: usize = 10;
+
+/// Tags are case-insensitive and whitespace inside them is collapsed to a single space.
+pub fn normalize_craving_tag(tag: &str) -> String {
+    tag.split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+        .to_lowercase()
+}
+/// Mirror of the hdk Path, which integrity zomes don't have: a list of components,
+/// each a string encoded as little-endian u32 chars.
+#[derive(Serialize, Deserialize, Debug, SerializedBytes)]
+struct PathMirror(Vec<SerializedBytes>);
+
+/// The entry hash of the path `craving_tags.<tag>` the coordinator links tagged recipes from.
+pub fn craving_tag_path_entry_hash(tag: &str) -> ExternResult<EntryHash> {
+    let component = |text: &str| {
+        SerializedBytes::from(UnsafeBytes::from(
+            text.chars().flat_map(|c| (c as u32).to_le_bytes()).collect::<Vec<u8>>(),
+        ))
+    };
+    let path = PathMirror(vec![component(CRAVING_TAGS), component(tag)]);
+    hash_entry(Entry::App(AppEntryBytes(
+        SerializedBytes::try_from(path).map_err(|e| wasm_error!(e))?,
+    )))
+}
+pub fn validate_craving_tag(tag: &str) -> Option<CondenserError> {
+    if tag.is_empty() {
+        return Some(CondenserError::TooShort { field: String::from("tag"), min: 1, actual: 0 });
+    }
+    if tag.chars().count() > MAX_CRAVING_TAG_CHARS {
+        return Some(CondenserError::TooLong { field: String::from("tag"), max: MAX_CRAVING_TAG_CHARS, actual: tag.chars().count() });
+    }
+    if normalize_craving_tag(tag) != tag {
+        return Some(CondenserError::WrongType { expected: String::from("normalized tag") });
+    }
+    None
+}
+pub fn validate_create_link_craving_tags(
+    _action: CreateLink,
+    _base_address: AnyLinkableHash,
+    _target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    // links of the craving_tags path tree
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_craving_tags(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            CondenserError::NotDeletable { what: String::from("CravingTags link") }.encode(),
+        ),
+    )
+}
+pub fn validate_create_link_tag_to_craving_recipes(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let craving_tag = match String::from_utf8(tag.into_inner()) {
+        Ok(craving_tag) => craving_tag,
+        Err(_) => {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    CondenserError::InvalidLinkTag { expected: String::from("craving tag") }.encode(),
+                ),
+            );
+        }
+    };
+    if let Some(err) = validate_craving_tag(&craving_tag) {
+        return Ok(ValidateCallbackResult::Invalid(err.encode()));
+    }
+    if base_address != AnyLinkableHash::from(craving_tag_path_entry_hash(&craving_tag)?) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::WrongType { expected: String::from("path of the craving tag") }.encode(),
+            ),
+        );
+    }
+    let action_hash = ActionHash::try_from(target_address).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
+    let recipe_action = must_get_action(action_hash.clone())?;
+    if recipe_action.action().author() != &action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("DnaRecipe") }.encode(),
+            ),
+        );
+    }
+    if must_get_created_entry::<DnaRecipe>(action_hash)?.is_none() {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::WrongType { expected: String::from("DnaRecipe") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_tag_to_craving_recipes(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            CondenserError::NotDeletable { what: String::from("TagToCravingRecipes link") }.encode(),
+        ),
+    )
+}
@@ -1,4 +1,12 @@
 use hdi::prelude::*;
+use condenser_common::{CondenserError, dna_hash_anchor};
+use crate::must_get_created_entry;
+
+pub const MAX_RECIPE_TITLE_CHARS: usize = 80;
+pub const MAX_RECIPE_DESCRIPTION_CHARS: usize = 10000;
+/// Allowed range for the max_*_chars properties of a craving
+pub const MIN_CHARS_LIMIT: usize = 1;
+pub const MAX_CHARS_LIMIT: usize = 20000;
 
 #[derive(Clone, Serialize, Deserialize, Debug, SerializedBytes)]
 pub struct CravingDnaProperties {
@@ -8,8 +16,16 @@ pub struct CravingDnaProperties {
     pub max_association_chars: Option<usize>,
     pub max_offer_chars: Option<usize>,
     pub max_reflection_chars: Option<usize>,
+    #[serde(default)]
+    pub lobby_dna_hash: Option<DnaHash>,
+    #[serde(default)]
+    pub lobby_creator: Option<AgentPubKey>,
 }
 
+/// The recipe to install a craving. There should be one recipe per `resulting_dna_hash`,
+/// but validation can't see whether another recipe was linked to the same DNA hash, so
+/// only `create_dna_recipe` checks that. Concurrent or hand-crafted commits can still
+/// create duplicates, readers pick the canonical one with `canonical_dna_recipe`.
 #[hdk_entry_helper]
 #[derive(Clone)]
 pub struct DnaRecipe {
@@ -20,10 +36,57 @@ pub struct DnaRecipe {
     pub membrane_proof: Option<MembraneProof>,
     pub resulting_dna_hash: DnaHash,
 }
+pub(crate) fn validate_text_length(field: &str, text: &str, max: usize) -> Option<CondenserError> {
+    if text.trim().is_empty() {
+        return Some(CondenserError::TooShort { field: String::from(field), min: 1, actual: text.chars().count() });
+    }
+    let chars = text.chars().count();
+    if chars > max {
+        return Some(CondenserError::TooLong { field: String::from(field), max, actual: chars });
+    }
+    None
+}
+pub(crate) fn validate_chars_limits(limits: [(&str, Option<usize>); 4]) -> Option<CondenserError> {
+    for (field, limit) in limits {
+        if let Some(limit) = limit {
+            if !(MIN_CHARS_LIMIT..=MAX_CHARS_LIMIT).contains(&limit) {
+                return Some(CondenserError::OutOfRange {
+                    field: String::from(field),
+                    min: MIN_CHARS_LIMIT,
+                    max: MAX_CHARS_LIMIT,
+                    actual: limit,
+                });
+            }
+        }
+    }
+    None
+}
 pub fn validate_create_dna_recipe(
     _action: EntryCreationAction,
-    _dna_recipe: DnaRecipe,
+    dna_recipe: DnaRecipe,
 ) -> ExternResult<ValidateCallbackResult> {
+    let properties = &dna_recipe.properties;
+    let text_fields = [
+        ("title", &dna_recipe.title, MAX_RECIPE_TITLE_CHARS),
+        ("properties.title", &properties.title, MAX_RECIPE_TITLE_CHARS),
+        ("properties.description", &properties.description, MAX_RECIPE_DESCRIPTION_CHARS),
+    ];
+    for (field, text, max) in text_fields {
+        if let Some(err) = validate_text_length(field, text, max) {
+            return Ok(ValidateCallbackResult::Invalid(err.encode()));
+        }
+    }
+
+    let limits = [
+        ("max_anecdote_chars", properties.max_anecdote_chars),
+        ("max_association_chars", properties.max_association_chars),
+        ("max_offer_chars", properties.max_offer_chars),
+        ("max_reflection_chars", properties.max_reflection_chars),
+    ];
+    if let Some(err) = validate_chars_limits(limits) {
+        return Ok(ValidateCallbackResult::Invalid(err.encode()));
+    }
+
     Ok(ValidateCallbackResult::Valid)
 }
 pub fn validate_update_dna_recipe(
@@ -32,14 +95,14 @@ pub fn validate_update_dna_recipe(
     _original_action: EntryCreationAction,
     _original_dna_recipe: DnaRecipe,
 ) -> ExternResult<ValidateCallbackResult> {
-    Ok(ValidateCallbackResult::Invalid(String::from("Dna Recipes cannot be updated")))
+    Ok(ValidateCallbackResult::Invalid(CondenserError::NotUpdatable { what: String::from("DnaRecipe") }.encode()))
 }
 pub fn validate_delete_dna_recipe(
     _action: Delete,
     _original_action: EntryCreationAction,
     _original_dna_recipe: DnaRecipe,
 ) -> ExternResult<ValidateCallbackResult> {
-    Ok(ValidateCallbackResult::Invalid(String::from("Dna Recipes cannot be deleted")))
+    Ok(ValidateCallbackResult::Invalid(CondenserError::NotDeletable { what: String::from("DnaRecipe") }.encode()))
 }
 pub fn validate_create_link_all_craving_recipes(
     _action: CreateLink,
@@ -48,16 +111,13 @@ pub fn validate_create_link_all_craving_recipes(
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
     let action_hash = ActionHash::try_from(target_address).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
-    let record = must_get_valid_record(action_hash)?;
-    let _dna_recipe: crate::DnaRecipe = record
-        .entry()
-        .to_app_option()
-        .map_err(|e| wasm_error!(e))?
-        .ok_or(
-            wasm_error!(
-                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+    if must_get_created_entry::<DnaRecipe>(action_hash)?.is_none() {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::WrongType { expected: String::from("DnaRecipe") }.encode(),
             ),
-        )?;
+        );
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 pub fn validate_delete_link_all_craving_recipes(
@@ -69,7 +129,46 @@ pub fn validate_delete_link_all_craving_recipes(
 ) -> ExternResult<ValidateCallbackResult> {
     Ok(
         ValidateCallbackResult::Invalid(
-            String::from("AllCravingRecipes links cannot be deleted"),
+            CondenserError::NotDeletable { what: String::from("AllCravingRecipes link") }.encode(),
+        ),
+    )
+}
+pub fn validate_create_link_dna_hash_to_dna_recipe(
+    _action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = ActionHash::try_from(target_address).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
+    let dna_recipe = match must_get_created_entry::<DnaRecipe>(action_hash)? {
+        Some((_, dna_recipe)) => dna_recipe,
+        None => {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    CondenserError::WrongType { expected: String::from("DnaRecipe") }.encode(),
+                ),
+            );
+        }
+    };
+    if base_address != dna_hash_anchor(&dna_recipe.resulting_dna_hash) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::WrongType { expected: String::from("anchor of the resulting DNA hash") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_dna_hash_to_dna_recipe(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            CondenserError::NotDeletable { what: String::from("DnaHashToDnaRecipe link") }.encode(),
         ),
     )
 }
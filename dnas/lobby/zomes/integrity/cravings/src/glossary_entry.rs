@@ -0,0 +1,137 @@
+use hdi::prelude::*;
+use condenser_common::CondenserError;
+use crate::{must_get_created_entry, validate_text_length, DnaRecipe, MAX_CHARS_LIMIT};
+
+/// Words are offers of a craving, so they can be as long as the longest allowed offer
+pub const MAX_GLOSSARY_WORD_CHARS: usize = MAX_CHARS_LIMIT;
+pub const MAX_GLOSSARY_DEFINITION_CHARS: usize = 2000;
+
+/// A word a craving has settled on, published to the lobby by a member of the craving.
+#[hdk_entry_helper]
+#[derive(Clone)]
+pub struct GlossaryEntry {
+    pub word: String,
+    pub definition: String,
+    /// The recipe of the craving the word was condensed in
+    pub dna_recipe_hash: ActionHash,
+    /// The offer in the craving DNA the word is read from. Validation can't reach into the
+    /// craving DNA, so `publish_glossary_entry` reads the word from the publisher's craving cell
+    pub offer_hash: ActionHash,
+}
+pub fn validate_create_glossary_entry(
+    _action: EntryCreationAction,
+    glossary_entry: GlossaryEntry,
+) -> ExternResult<ValidateCallbackResult> {
+    let text_error = validate_text_length("word", &glossary_entry.word, MAX_GLOSSARY_WORD_CHARS)
+        .or_else(|| validate_text_length("definition", &glossary_entry.definition, MAX_GLOSSARY_DEFINITION_CHARS));
+    if let Some(err) = text_error {
+        return Ok(ValidateCallbackResult::Invalid(err.encode()));
+    }
+    if must_get_created_entry::<DnaRecipe>(glossary_entry.dna_recipe_hash)?.is_none() {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::WrongType { expected: String::from("DnaRecipe") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_update_glossary_entry(
+    _action: Update,
+    _glossary_entry: GlossaryEntry,
+    _original_action: EntryCreationAction,
+    _original_glossary_entry: GlossaryEntry,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(CondenserError::NotUpdatable { what: String::from("GlossaryEntry") }.encode()))
+}
+pub fn validate_delete_glossary_entry(
+    action: Delete,
+    original_action: EntryCreationAction,
+    _original_glossary_entry: GlossaryEntry,
+) -> ExternResult<ValidateCallbackResult> {
+    if &action.author != original_action.author() {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("GlossaryEntry") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+fn validate_glossary_entry_link_target(
+    action: &CreateLink,
+    target_address: AnyLinkableHash,
+) -> ExternResult<Result<GlossaryEntry, ValidateCallbackResult>> {
+    let action_hash = ActionHash::try_from(target_address).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
+    match must_get_created_entry::<GlossaryEntry>(action_hash)? {
+        Some((author, glossary_entry)) if author == action.author => Ok(Ok(glossary_entry)),
+        Some(_) => Ok(Err(ValidateCallbackResult::Invalid(
+            CondenserError::NotAuthor { what: String::from("GlossaryEntry") }.encode(),
+        ))),
+        None => Ok(Err(ValidateCallbackResult::Invalid(
+            CondenserError::WrongType { expected: String::from("GlossaryEntry") }.encode(),
+        ))),
+    }
+}
+pub fn validate_create_link_all_glossary_entries(
+    action: CreateLink,
+    _base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    match validate_glossary_entry_link_target(&action, target_address)? {
+        Ok(_) => Ok(ValidateCallbackResult::Valid),
+        Err(invalid) => Ok(invalid),
+    }
+}
+pub fn validate_delete_link_all_glossary_entries(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("AllGlossaryEntries link") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_create_link_dna_recipe_to_glossary_entries(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let glossary_entry = match validate_glossary_entry_link_target(&action, target_address)? {
+        Ok(glossary_entry) => glossary_entry,
+        Err(invalid) => return Ok(invalid),
+    };
+    if base_address != AnyLinkableHash::from(glossary_entry.dna_recipe_hash) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::WrongType { expected: String::from("DnaRecipe of the GlossaryEntry") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_dna_recipe_to_glossary_entries(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("DnaRecipeToGlossaryEntries link") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
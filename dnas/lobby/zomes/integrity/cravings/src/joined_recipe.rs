@@ -0,0 +1,81 @@
+use hdi::prelude::*;
+use condenser_common::CondenserError;
+use crate::{must_get_created_entry, DnaRecipe};
+
+fn validate_joined_dna_recipe(dna_recipe_address: AnyLinkableHash) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = ActionHash::try_from(dna_recipe_address).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
+    if must_get_created_entry::<DnaRecipe>(action_hash)?.is_none() {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::WrongType { expected: String::from("DnaRecipe") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+fn validate_joiner(action: &CreateLink, agent_address: AnyLinkableHash, what: &str) -> Option<ValidateCallbackResult> {
+    if agent_address.into_agent_pub_key().as_ref() != Some(&action.author) {
+        return Some(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from(what) }.encode(),
+            ),
+        );
+    }
+    None
+}
+/// Members link from their own public key to the recipes of the cravings they installed.
+pub fn validate_create_link_agent_to_joined_recipe(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if let Some(invalid) = validate_joiner(&action, base_address, "AgentToJoinedRecipe link") {
+        return Ok(invalid);
+    }
+    validate_joined_dna_recipe(target_address)
+}
+pub fn validate_delete_link_agent_to_joined_recipe(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("AgentToJoinedRecipe link") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+/// The reverse of AgentToJoinedRecipe, to count the members that joined a recipe.
+pub fn validate_create_link_joined_recipe_to_agents(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if let Some(invalid) = validate_joiner(&action, target_address, "JoinedRecipeToAgents link") {
+        return Ok(invalid);
+    }
+    validate_joined_dna_recipe(base_address)
+}
+pub fn validate_delete_link_joined_recipe_to_agents(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("JoinedRecipeToAgents link") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
@@ -4,7 +4,28 @@ pub mod lobby_info;
 pub use lobby_info::*;
 pub mod dna_recipe;
 pub use dna_recipe::*;
+pub mod craving_tag;
+pub use craving_tag::*;
+pub mod lobby_admin_role;
+pub use lobby_admin_role::*;
+pub mod lobby_member;
+pub use lobby_member::*;
+pub mod recipe_status;
+pub use recipe_status::*;
+pub mod announcement;
+pub use announcement::*;
+pub mod craving_proposal;
+pub use craving_proposal::*;
+pub mod glossary_entry;
+pub use glossary_entry::*;
+pub mod craving_preview;
+pub use craving_preview::*;
+pub mod joined_recipe;
+pub use joined_recipe::*;
+pub mod recipe_origin;
+pub use recipe_origin::*;
 use hdi::prelude::*;
+use condenser_common::{CondenserError, InvitationProof};
 #[derive(Serialize, Deserialize)]
 #[serde(tag = "type")]
 #[hdk_entry_defs]
@@ -12,6 +33,68 @@ use hdi::prelude::*;
 pub enum EntryTypes {
     DnaRecipe(DnaRecipe),
     LobbyInfo(LobbyInfo),
+    LobbyAdminRole(LobbyAdminRole),
+    RecipeStatus(RecipeStatus),
+    Announcement(Announcement),
+    CravingProposal(CravingProposal),
+    GlossaryEntry(GlossaryEntry),
+    CravingPreview(CravingPreview),
+    RecipeOrigin(RecipeOrigin),
+}
+macro_rules! entry_type_conversions {
+    ($($variant:ident),*) => {
+        $(
+            impl TryFrom<EntryTypes> for $variant {
+                type Error = EntryTypes;
+                fn try_from(entry_types: EntryTypes) -> Result<Self, Self::Error> {
+                    match entry_types {
+                        EntryTypes::$variant(entry) => Ok(entry),
+                        other => Err(other),
+                    }
+                }
+            }
+        )*
+    };
+}
+entry_type_conversions!(
+    DnaRecipe,
+    LobbyInfo,
+    LobbyAdminRole,
+    RecipeStatus,
+    Announcement,
+    CravingProposal,
+    GlossaryEntry,
+    CravingPreview,
+    RecipeOrigin
+);
+/// Gets the record and the app entry written by the given action, None if the action
+/// didn't write an entry of type `T`.
+pub(crate) fn must_get_app_entry<T: TryFrom<EntryTypes>>(action_hash: ActionHash) -> ExternResult<Option<(Record, T)>> {
+    let record = must_get_valid_record(action_hash)?;
+    let app_entry_def = match record.action().entry_type() {
+        Some(EntryType::App(app_entry_def)) => app_entry_def.clone(),
+        _ => return Ok(None),
+    };
+    let entry = record
+        .entry()
+        .as_option()
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(CondenserError::MissingEntry.encode())
+            ),
+        )?;
+    let app_entry = EntryTypes::deserialize_from_type(app_entry_def.zome_index, app_entry_def.entry_index, entry)?;
+    Ok(app_entry.and_then(|app_entry| T::try_from(app_entry).ok()).map(|app_entry| (record, app_entry)))
+}
+/// Gets the author and the app entry of the given create action, None if the action
+/// isn't the creation of an entry of type `T`.
+pub(crate) fn must_get_created_entry<T: TryFrom<EntryTypes>>(action_hash: ActionHash) -> ExternResult<Option<(AgentPubKey, T)>> {
+    match must_get_app_entry::<T>(action_hash)? {
+        Some((record, app_entry)) if matches!(record.action(), Action::Create(_)) => {
+            Ok(Some((record.action().author().clone(), app_entry)))
+        }
+        _ => Ok(None),
+    }
 }
 #[derive(Serialize, Deserialize)]
 #[hdk_link_types]
@@ -19,23 +102,68 @@ pub enum LinkTypes {
     AllCravingRecipes,
     LobbyInfoUpdates,
     AnchorToLobbyInfo,
+    DnaHashToDnaRecipe,
+    CravingTags,
+    TagToCravingRecipes,
+    AllLobbyAdminRoles,
+    LobbyMembers,
+    RecipeToStatuses,
+    AnnouncementUpdates,
+    AllAnnouncements,
+    AllCravingProposals,
+    CravingProposalToResonators,
+    CravingProposalToDnaRecipe,
+    AllGlossaryEntries,
+    DnaRecipeToGlossaryEntries,
+    DnaRecipeToCravingPreviews,
+    AgentToJoinedRecipe,
+    JoinedRecipeToAgents,
+    DnaRecipeToOrigin,
 }
 #[hdk_extern]
 pub fn genesis_self_check(
-    _data: GenesisSelfCheckData,
+    data: GenesisSelfCheckData,
 ) -> ExternResult<ValidateCallbackResult> {
-    Ok(ValidateCallbackResult::Valid)
+    validate_agent_joining(data.agent_key, &data.membrane_proof)
 }
+/// In invitation-only lobbies, joining agents have to present a chain of invitations
+/// starting with the creator of the lobby. Lobbies without properties, like the base
+/// cell of the lobby role, are open.
 pub fn validate_agent_joining(
-    _agent_pub_key: AgentPubKey,
-    _membrane_proof: &Option<MembraneProof>,
+    agent_pub_key: AgentPubKey,
+    membrane_proof: &Option<MembraneProof>,
 ) -> ExternResult<ValidateCallbackResult> {
-    Ok(ValidateCallbackResult::Valid)
+    let Ok(lobby_dna_properties) = LobbyDnaProperties::get() else {
+        return Ok(ValidateCallbackResult::Valid);
+    };
+    if !lobby_dna_properties.invitation_only.unwrap_or(false) {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    let creator = match lobby_dna_properties.creator {
+        Some(creator) => creator,
+        None => {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    CondenserError::InvalidDnaProperties {
+                        reason: String::from("invitation-only lobbies need a creator"),
+                    }.encode(),
+                ),
+            );
+        }
+    };
+    let invitation_proof = match InvitationProof::from_membrane_proof(membrane_proof) {
+        Ok(invitation_proof) => invitation_proof,
+        Err(err) => return Ok(ValidateCallbackResult::Invalid(err.encode())),
+    };
+    match invitation_proof.verify(&dna_info()?.hash, &creator, &agent_pub_key)? {
+        Some(err) => Ok(ValidateCallbackResult::Invalid(err.encode())),
+        None => Ok(ValidateCallbackResult::Valid),
+    }
 }
 #[hdk_extern]
 pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
-    match op.to_type::<EntryTypes, LinkTypes>()? {
-        OpType::StoreEntry(store_entry) => {
+    match op.flattened::<EntryTypes, LinkTypes>()? {
+        FlatOp::StoreEntry(store_entry) => {
             match store_entry {
                 OpEntry::CreateEntry { app_entry, action } => {
                     match app_entry {
@@ -51,6 +179,48 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 lobby_info,
                             )
                         }
+                        EntryTypes::LobbyAdminRole(lobby_admin_role) => {
+                            validate_create_lobby_admin_role(
+                                EntryCreationAction::Create(action),
+                                lobby_admin_role,
+                            )
+                        }
+                        EntryTypes::RecipeStatus(recipe_status) => {
+                            validate_create_recipe_status(
+                                EntryCreationAction::Create(action),
+                                recipe_status,
+                            )
+                        }
+                        EntryTypes::Announcement(announcement) => {
+                            validate_create_announcement(
+                                EntryCreationAction::Create(action),
+                                announcement,
+                            )
+                        }
+                        EntryTypes::CravingProposal(craving_proposal) => {
+                            validate_create_craving_proposal(
+                                EntryCreationAction::Create(action),
+                                craving_proposal,
+                            )
+                        }
+                        EntryTypes::GlossaryEntry(glossary_entry) => {
+                            validate_create_glossary_entry(
+                                EntryCreationAction::Create(action),
+                                glossary_entry,
+                            )
+                        }
+                        EntryTypes::CravingPreview(craving_preview) => {
+                            validate_create_craving_preview(
+                                EntryCreationAction::Create(action),
+                                craving_preview,
+                            )
+                        }
+                        EntryTypes::RecipeOrigin(recipe_origin) => {
+                            validate_create_recipe_origin(
+                                EntryCreationAction::Create(action),
+                                recipe_origin,
+                            )
+                        }
                     }
                 }
                 OpEntry::UpdateEntry { app_entry, action, .. } => {
@@ -67,12 +237,54 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 lobby_info,
                             )
                         }
+                        EntryTypes::LobbyAdminRole(lobby_admin_role) => {
+                            validate_create_lobby_admin_role(
+                                EntryCreationAction::Update(action),
+                                lobby_admin_role,
+                            )
+                        }
+                        EntryTypes::RecipeStatus(recipe_status) => {
+                            validate_create_recipe_status(
+                                EntryCreationAction::Update(action),
+                                recipe_status,
+                            )
+                        }
+                        EntryTypes::Announcement(announcement) => {
+                            validate_create_announcement(
+                                EntryCreationAction::Update(action),
+                                announcement,
+                            )
+                        }
+                        EntryTypes::CravingProposal(craving_proposal) => {
+                            validate_create_craving_proposal(
+                                EntryCreationAction::Update(action),
+                                craving_proposal,
+                            )
+                        }
+                        EntryTypes::GlossaryEntry(glossary_entry) => {
+                            validate_create_glossary_entry(
+                                EntryCreationAction::Update(action),
+                                glossary_entry,
+                            )
+                        }
+                        EntryTypes::CravingPreview(craving_preview) => {
+                            validate_create_craving_preview(
+                                EntryCreationAction::Update(action),
+                                craving_preview,
+                            )
+                        }
+                        EntryTypes::RecipeOrigin(recipe_origin) => {
+                            validate_create_recipe_origin(
+                                EntryCreationAction::Update(action),
+                                recipe_origin,
+                            )
+                        }
                     }
                 }
                 _ => Ok(ValidateCallbackResult::Valid),
             }
         }
-        OpType::RegisterUpdate(update_entry) => {
+        FlatOp::RegisterUpdate(update_entry) => {
             match update_entry {
                 OpUpdate::Entry {
                     original_action,
@@ -92,6 +304,83 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 original_lobby_info,
                             )
                         }
+                        (
+                            EntryTypes::LobbyAdminRole(lobby_admin_role),
+                            EntryTypes::LobbyAdminRole(original_lobby_admin_role),
+                        ) => {
+                            validate_update_lobby_admin_role(
+                                action,
+                                lobby_admin_role,
+                                original_action,
+                                original_lobby_admin_role,
+                            )
+                        }
+                        (
+                            EntryTypes::RecipeStatus(recipe_status),
+                            EntryTypes::RecipeStatus(original_recipe_status),
+                        ) => {
+                            validate_update_recipe_status(
+                                action,
+                                recipe_status,
+                                original_action,
+                                original_recipe_status,
+                            )
+                        }
+                        (
+                            EntryTypes::Announcement(announcement),
+                            EntryTypes::Announcement(original_announcement),
+                        ) => {
+                            validate_update_announcement(
+                                action,
+                                announcement,
+                                original_action,
+                                original_announcement,
+                            )
+                        }
+                        (
+                            EntryTypes::CravingProposal(craving_proposal),
+                            EntryTypes::CravingProposal(original_craving_proposal),
+                        ) => {
+                            validate_update_craving_proposal(
+                                action,
+                                craving_proposal,
+                                original_action,
+                                original_craving_proposal,
+                            )
+                        }
+                        (
+                            EntryTypes::GlossaryEntry(glossary_entry),
+                            EntryTypes::GlossaryEntry(original_glossary_entry),
+                        ) => {
+                            validate_update_glossary_entry(
+                                action,
+                                glossary_entry,
+                                original_action,
+                                original_glossary_entry,
+                            )
+                        }
+                        (
+                            EntryTypes::CravingPreview(craving_preview),
+                            EntryTypes::CravingPreview(original_craving_preview),
+                        ) => {
+                            validate_update_craving_preview(
+                                action,
+                                craving_preview,
+                                original_action,
+                                original_craving_preview,
+                            )
+                        }
+                        (
+                            EntryTypes::RecipeOrigin(recipe_origin),
+                            EntryTypes::RecipeOrigin(original_recipe_origin),
+                        ) => {
+                            validate_update_recipe_origin(
+                                action,
+                                recipe_origin,
+                                original_action,
+                                original_recipe_origin,
+                            )
+                        }
                         (
                             EntryTypes::DnaRecipe(dna_recipe),
                             EntryTypes::DnaRecipe(original_dna_recipe),
@@ -106,8 +395,7 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         _ => {
                             Ok(
                                 ValidateCallbackResult::Invalid(
-                                    "Original and updated entry types must be the same"
-                                        .to_string(),
+                                    CondenserError::MalformedOp { reason: String::from("Original and updated entry types must be the same") }.encode(),
                                 ),
                             )
                         }
@@ -116,7 +404,7 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                 _ => Ok(ValidateCallbackResult::Valid),
             }
         }
-        OpType::RegisterDelete(delete_entry) => {
+        FlatOp::RegisterDelete(delete_entry) => {
             match delete_entry {
                 OpDelete::Entry { original_action, original_app_entry, action } => {
                     match original_app_entry {
@@ -134,12 +422,61 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 lobby_info,
                             )
                         }
+                        EntryTypes::LobbyAdminRole(lobby_admin_role) => {
+                            validate_delete_lobby_admin_role(
+                                action,
+                                original_action,
+                                lobby_admin_role,
+                            )
+                        }
+                        EntryTypes::RecipeStatus(recipe_status) => {
+                            validate_delete_recipe_status(
+                                action,
+                                original_action,
+                                recipe_status,
+                            )
+                        }
+                        EntryTypes::Announcement(announcement) => {
+                            validate_delete_announcement(
+                                action,
+                                original_action,
+                                announcement,
+                            )
+                        }
+                        EntryTypes::CravingProposal(craving_proposal) => {
+                            validate_delete_craving_proposal(
+                                action,
+                                original_action,
+                                craving_proposal,
+                            )
+                        }
+                        EntryTypes::GlossaryEntry(glossary_entry) => {
+                            validate_delete_glossary_entry(
+                                action,
+                                original_action,
+                                glossary_entry,
+                            )
+                        }
+                        EntryTypes::CravingPreview(craving_preview) => {
+                            validate_delete_craving_preview(
+                                action,
+                                original_action,
+                                craving_preview,
+                            )
+                        }
+                        EntryTypes::RecipeOrigin(recipe_origin) => {
+                            validate_delete_recipe_origin(
+                                action,
+                                original_action,
+                                recipe_origin,
+                            )
+                        }
                     }
                 }
                 _ => Ok(ValidateCallbackResult::Valid),
             }
         }
-        OpType::RegisterCreateLink {
+        FlatOp::RegisterCreateLink {
             link_type,
             base_address,
             target_address,
@@ -171,9 +508,145 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         tag,
                     )
                 }
+                LinkTypes::DnaHashToDnaRecipe => {
+                    validate_create_link_dna_hash_to_dna_recipe(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::CravingTags => {
+                    validate_create_link_craving_tags(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::TagToCravingRecipes => {
+                    validate_create_link_tag_to_craving_recipes(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AllLobbyAdminRoles => {
+                    validate_create_link_all_lobby_admin_roles(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::LobbyMembers => {
+                    validate_create_link_lobby_members(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::RecipeToStatuses => {
+                    validate_create_link_recipe_to_statuses(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AnnouncementUpdates => {
+                    validate_create_link_announcement_updates(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AllAnnouncements => {
+                    validate_create_link_all_announcements(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AllCravingProposals => {
+                    validate_create_link_all_craving_proposals(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::CravingProposalToResonators => {
+                    validate_create_link_craving_proposal_to_resonators(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::CravingProposalToDnaRecipe => {
+                    validate_create_link_craving_proposal_to_dna_recipe(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AllGlossaryEntries => {
+                    validate_create_link_all_glossary_entries(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::DnaRecipeToGlossaryEntries => {
+                    validate_create_link_dna_recipe_to_glossary_entries(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::DnaRecipeToCravingPreviews => {
+                    validate_create_link_dna_recipe_to_craving_previews(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AgentToJoinedRecipe => {
+                    validate_create_link_agent_to_joined_recipe(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::JoinedRecipeToAgents => {
+                    validate_create_link_joined_recipe_to_agents(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::DnaRecipeToOrigin => {
+                    validate_create_link_dna_recipe_to_origin(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
             }
         }
-        OpType::RegisterDeleteLink {
+        FlatOp::RegisterDeleteLink {
             link_type,
             base_address,
             target_address,
@@ -209,33 +682,228 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         tag,
                     )
                 }
-            }
-        }
-        OpType::StoreRecord(store_record) => {
-            match store_record {
-                OpRecord::CreateEntry { app_entry, action } => {
-                    match app_entry {
-                        EntryTypes::DnaRecipe(dna_recipe) => {
-                            validate_create_dna_recipe(
-                                EntryCreationAction::Create(action),
-                                dna_recipe,
-                            )
-                        }
-                        EntryTypes::LobbyInfo(lobby_info) => {
-                            validate_create_lobby_info(
-                                EntryCreationAction::Create(action),
-                                lobby_info,
-                            )
-                        }
-                    }
+                LinkTypes::DnaHashToDnaRecipe => {
+                    validate_delete_link_dna_hash_to_dna_recipe(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
                 }
-                OpRecord::UpdateEntry {
-                    original_action_hash,
-                    app_entry,
-                    action,
-                    ..
-                } => {
-                    let original_record = must_get_valid_record(original_action_hash)?;
+                LinkTypes::CravingTags => {
+                    validate_delete_link_craving_tags(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::TagToCravingRecipes => {
+                    validate_delete_link_tag_to_craving_recipes(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AllLobbyAdminRoles => {
+                    validate_delete_link_all_lobby_admin_roles(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::LobbyMembers => {
+                    validate_delete_link_lobby_members(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::RecipeToStatuses => {
+                    validate_delete_link_recipe_to_statuses(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AnnouncementUpdates => {
+                    validate_delete_link_announcement_updates(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AllAnnouncements => {
+                    validate_delete_link_all_announcements(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AllCravingProposals => {
+                    validate_delete_link_all_craving_proposals(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::CravingProposalToResonators => {
+                    validate_delete_link_craving_proposal_to_resonators(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::CravingProposalToDnaRecipe => {
+                    validate_delete_link_craving_proposal_to_dna_recipe(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AllGlossaryEntries => {
+                    validate_delete_link_all_glossary_entries(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::DnaRecipeToGlossaryEntries => {
+                    validate_delete_link_dna_recipe_to_glossary_entries(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::DnaRecipeToCravingPreviews => {
+                    validate_delete_link_dna_recipe_to_craving_previews(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AgentToJoinedRecipe => {
+                    validate_delete_link_agent_to_joined_recipe(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::JoinedRecipeToAgents => {
+                    validate_delete_link_joined_recipe_to_agents(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::DnaRecipeToOrigin => {
+                    validate_delete_link_dna_recipe_to_origin(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+            }
+        }
+        FlatOp::StoreRecord(store_record) => {
+            match store_record {
+                OpRecord::CreateEntry { app_entry, action } => {
+                    match app_entry {
+                        EntryTypes::DnaRecipe(dna_recipe) => {
+                            validate_create_dna_recipe(
+                                EntryCreationAction::Create(action),
+                                dna_recipe,
+                            )
+                        }
+                        EntryTypes::LobbyInfo(lobby_info) => {
+                            validate_create_lobby_info(
+                                EntryCreationAction::Create(action),
+                                lobby_info,
+                            )
+                        }
+                        EntryTypes::LobbyAdminRole(lobby_admin_role) => {
+                            validate_create_lobby_admin_role(
+                                EntryCreationAction::Create(action),
+                                lobby_admin_role,
+                            )
+                        }
+                        EntryTypes::RecipeStatus(recipe_status) => {
+                            validate_create_recipe_status(
+                                EntryCreationAction::Create(action),
+                                recipe_status,
+                            )
+                        }
+                        EntryTypes::Announcement(announcement) => {
+                            validate_create_announcement(
+                                EntryCreationAction::Create(action),
+                                announcement,
+                            )
+                        }
+                        EntryTypes::CravingProposal(craving_proposal) => {
+                            validate_create_craving_proposal(
+                                EntryCreationAction::Create(action),
+                                craving_proposal,
+                            )
+                        }
+                        EntryTypes::GlossaryEntry(glossary_entry) => {
+                            validate_create_glossary_entry(
+                                EntryCreationAction::Create(action),
+                                glossary_entry,
+                            )
+                        }
+                        EntryTypes::CravingPreview(craving_preview) => {
+                            validate_create_craving_preview(
+                                EntryCreationAction::Create(action),
+                                craving_preview,
+                            )
+                        }
+                        EntryTypes::RecipeOrigin(recipe_origin) => {
+                            validate_create_recipe_origin(
+                                EntryCreationAction::Create(action),
+                                recipe_origin,
+                            )
+                        }
+                    }
+                }
+                OpRecord::UpdateEntry {
+                    original_action_hash,
+                    app_entry,
+                    action,
+                    ..
+                } => {
+                    let original_record = must_get_valid_record(original_action_hash)?;
                     let original_action = original_record.action().clone();
                     let original_action = match original_action {
                         Action::Create(create) => EntryCreationAction::Create(create),
@@ -243,8 +911,7 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         _ => {
                             return Ok(
                                 ValidateCallbackResult::Invalid(
-                                    "Original action for an update must be a Create or Update action"
-                                        .to_string(),
+                                    CondenserError::MalformedOp { reason: String::from("Original action for an update must be a Create or Update action") }.encode(),
                                 ),
                             );
                         }
@@ -265,8 +932,7 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                     None => {
                                         return Ok(
                                             ValidateCallbackResult::Invalid(
-                                                "The updated entry type must be the same as the original entry type"
-                                                    .to_string(),
+                                                CondenserError::MalformedOp { reason: String::from("The updated entry type must be the same as the original entry type") }.encode(),
                                             ),
                                         );
                                     }
@@ -284,29 +950,238 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         EntryTypes::LobbyInfo(lobby_info) => {
                             let result = validate_create_lobby_info(
                                 EntryCreationAction::Update(action.clone()),
-                                lobby_info.clone(),
+                                lobby_info.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_lobby_info: Option<LobbyInfo> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_lobby_info = match original_lobby_info {
+                                    Some(lobby_info) => lobby_info,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                CondenserError::MalformedOp { reason: String::from("The updated entry type must be the same as the original entry type") }.encode(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_lobby_info(
+                                    action,
+                                    lobby_info,
+                                    original_action,
+                                    original_lobby_info,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::LobbyAdminRole(lobby_admin_role) => {
+                            let result = validate_create_lobby_admin_role(
+                                EntryCreationAction::Update(action.clone()),
+                                lobby_admin_role.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_lobby_admin_role: Option<LobbyAdminRole> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_lobby_admin_role = match original_lobby_admin_role {
+                                    Some(lobby_admin_role) => lobby_admin_role,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                CondenserError::MalformedOp { reason: String::from("The updated entry type must be the same as the original entry type") }.encode(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_lobby_admin_role(
+                                    action,
+                                    lobby_admin_role,
+                                    original_action,
+                                    original_lobby_admin_role,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::RecipeStatus(recipe_status) => {
+                            let result = validate_create_recipe_status(
+                                EntryCreationAction::Update(action.clone()),
+                                recipe_status.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_recipe_status: Option<RecipeStatus> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_recipe_status = match original_recipe_status {
+                                    Some(recipe_status) => recipe_status,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                CondenserError::MalformedOp { reason: String::from("The updated entry type must be the same as the original entry type") }.encode(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_recipe_status(
+                                    action,
+                                    recipe_status,
+                                    original_action,
+                                    original_recipe_status,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::Announcement(announcement) => {
+                            let result = validate_create_announcement(
+                                EntryCreationAction::Update(action.clone()),
+                                announcement.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_announcement: Option<Announcement> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_announcement = match original_announcement {
+                                    Some(announcement) => announcement,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                CondenserError::MalformedOp { reason: String::from("The updated entry type must be the same as the original entry type") }.encode(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_announcement(
+                                    action,
+                                    announcement,
+                                    original_action,
+                                    original_announcement,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::CravingProposal(craving_proposal) => {
+                            let result = validate_create_craving_proposal(
+                                EntryCreationAction::Update(action.clone()),
+                                craving_proposal.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_craving_proposal: Option<CravingProposal> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_craving_proposal = match original_craving_proposal {
+                                    Some(craving_proposal) => craving_proposal,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                CondenserError::MalformedOp { reason: String::from("The updated entry type must be the same as the original entry type") }.encode(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_craving_proposal(
+                                    action,
+                                    craving_proposal,
+                                    original_action,
+                                    original_craving_proposal,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::GlossaryEntry(glossary_entry) => {
+                            let result = validate_create_glossary_entry(
+                                EntryCreationAction::Update(action.clone()),
+                                glossary_entry.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_glossary_entry: Option<GlossaryEntry> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_glossary_entry = match original_glossary_entry {
+                                    Some(glossary_entry) => glossary_entry,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                CondenserError::MalformedOp { reason: String::from("The updated entry type must be the same as the original entry type") }.encode(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_glossary_entry(
+                                    action,
+                                    glossary_entry,
+                                    original_action,
+                                    original_glossary_entry,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::CravingPreview(craving_preview) => {
+                            let result = validate_create_craving_preview(
+                                EntryCreationAction::Update(action.clone()),
+                                craving_preview.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_craving_preview: Option<CravingPreview> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_craving_preview = match original_craving_preview {
+                                    Some(craving_preview) => craving_preview,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                CondenserError::MalformedOp { reason: String::from("The updated entry type must be the same as the original entry type") }.encode(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_craving_preview(
+                                    action,
+                                    craving_preview,
+                                    original_action,
+                                    original_craving_preview,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::RecipeOrigin(recipe_origin) => {
+                            let result = validate_create_recipe_origin(
+                                EntryCreationAction::Update(action.clone()),
+                                recipe_origin.clone(),
                             )?;
                             if let ValidateCallbackResult::Valid = result {
-                                let original_lobby_info: Option<LobbyInfo> = original_record
+                                let original_recipe_origin: Option<RecipeOrigin> = original_record
                                     .entry()
                                     .to_app_option()
                                     .map_err(|e| wasm_error!(e))?;
-                                let original_lobby_info = match original_lobby_info {
-                                    Some(lobby_info) => lobby_info,
+                                let original_recipe_origin = match original_recipe_origin {
+                                    Some(recipe_origin) => recipe_origin,
                                     None => {
                                         return Ok(
                                             ValidateCallbackResult::Invalid(
-                                                "The updated entry type must be the same as the original entry type"
-                                                    .to_string(),
+                                                CondenserError::MalformedOp { reason: String::from("The updated entry type must be the same as the original entry type") }.encode(),
                                             ),
                                         );
                                     }
                                 };
-                                validate_update_lobby_info(
+                                validate_update_recipe_origin(
                                     action,
-                                    lobby_info,
+                                    recipe_origin,
                                     original_action,
-                                    original_lobby_info,
+                                    original_recipe_origin,
                                 )
                             } else {
                                 Ok(result)
@@ -323,8 +1198,7 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         _ => {
                             return Ok(
                                 ValidateCallbackResult::Invalid(
-                                    "Original action for a delete must be a Create or Update action"
-                                        .to_string(),
+                                    CondenserError::MalformedOp { reason: String::from("Original action for a delete must be a Create or Update action") }.encode(),
                                 ),
                             );
                         }
@@ -341,8 +1215,7 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                             if original_action.entry_type().visibility().is_public() {
                                 return Ok(
                                     ValidateCallbackResult::Invalid(
-                                        "Original record for a delete of a public entry must contain an entry"
-                                            .to_string(),
+                                        CondenserError::MalformedOp { reason: String::from("Original record for a delete of a public entry must contain an entry") }.encode(),
                                     ),
                                 );
                             } else {
@@ -351,16 +1224,15 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         }
                     };
                     let original_app_entry = match EntryTypes::deserialize_from_type(
-                        app_entry_type.zome_index.clone(),
-                        app_entry_type.entry_index.clone(),
-                        &entry,
+                        app_entry_type.zome_index,
+                        app_entry_type.entry_index,
+                        entry,
                     )? {
                         Some(app_entry) => app_entry,
                         None => {
                             return Ok(
                                 ValidateCallbackResult::Invalid(
-                                    "Original app entry must be one of the defined entry types for this zome"
-                                        .to_string(),
+                                    CondenserError::MalformedOp { reason: String::from("Original app entry must be one of the defined entry types for this zome") }.encode(),
                                 ),
                             );
                         }
@@ -380,6 +1252,55 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 original_lobby_info,
                             )
                         }
+                        EntryTypes::LobbyAdminRole(original_lobby_admin_role) => {
+                            validate_delete_lobby_admin_role(
+                                action,
+                                original_action,
+                                original_lobby_admin_role,
+                            )
+                        }
+                        EntryTypes::RecipeStatus(original_recipe_status) => {
+                            validate_delete_recipe_status(
+                                action,
+                                original_action,
+                                original_recipe_status,
+                            )
+                        }
+                        EntryTypes::Announcement(original_announcement) => {
+                            validate_delete_announcement(
+                                action,
+                                original_action,
+                                original_announcement,
+                            )
+                        }
+                        EntryTypes::CravingProposal(original_craving_proposal) => {
+                            validate_delete_craving_proposal(
+                                action,
+                                original_action,
+                                original_craving_proposal,
+                            )
+                        }
+                        EntryTypes::GlossaryEntry(original_glossary_entry) => {
+                            validate_delete_glossary_entry(
+                                action,
+                                original_action,
+                                original_glossary_entry,
+                            )
+                        }
+                        EntryTypes::CravingPreview(original_craving_preview) => {
+                            validate_delete_craving_preview(
+                                action,
+                                original_action,
+                                original_craving_preview,
+                            )
+                        }
+                        EntryTypes::RecipeOrigin(original_recipe_origin) => {
+                            validate_delete_recipe_origin(
+                                action,
+                                original_action,
+                                original_recipe_origin,
+                            )
+                        }
                     }
                 }
                 OpRecord::CreateLink {
@@ -414,6 +1335,142 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 tag,
                             )
                         }
+                        LinkTypes::DnaHashToDnaRecipe => {
+                            validate_create_link_dna_hash_to_dna_recipe(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::CravingTags => {
+                            validate_create_link_craving_tags(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::TagToCravingRecipes => {
+                            validate_create_link_tag_to_craving_recipes(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::AllLobbyAdminRoles => {
+                            validate_create_link_all_lobby_admin_roles(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::LobbyMembers => {
+                            validate_create_link_lobby_members(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::RecipeToStatuses => {
+                            validate_create_link_recipe_to_statuses(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::AnnouncementUpdates => {
+                            validate_create_link_announcement_updates(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::AllAnnouncements => {
+                            validate_create_link_all_announcements(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::AllCravingProposals => {
+                            validate_create_link_all_craving_proposals(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::CravingProposalToResonators => {
+                            validate_create_link_craving_proposal_to_resonators(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::CravingProposalToDnaRecipe => {
+                            validate_create_link_craving_proposal_to_dna_recipe(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::AllGlossaryEntries => {
+                            validate_create_link_all_glossary_entries(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::DnaRecipeToGlossaryEntries => {
+                            validate_create_link_dna_recipe_to_glossary_entries(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::DnaRecipeToCravingPreviews => {
+                            validate_create_link_dna_recipe_to_craving_previews(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::AgentToJoinedRecipe => {
+                            validate_create_link_agent_to_joined_recipe(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::JoinedRecipeToAgents => {
+                            validate_create_link_joined_recipe_to_agents(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::DnaRecipeToOrigin => {
+                            validate_create_link_dna_recipe_to_origin(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
                     }
                 }
                 OpRecord::DeleteLink { original_action_hash, base_address, action } => {
@@ -423,15 +1480,14 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         _ => {
                             return Ok(
                                 ValidateCallbackResult::Invalid(
-                                    "The action that a DeleteLink deletes must be a CreateLink"
-                                        .to_string(),
+                                    CondenserError::MalformedOp { reason: String::from("The action that a DeleteLink deletes must be a CreateLink") }.encode(),
                                 ),
                             );
                         }
                     };
                     let link_type = match LinkTypes::from_type(
-                        create_link.zome_index.clone(),
-                        create_link.link_type.clone(),
+                        create_link.zome_index,
+                        create_link.link_type,
                     )? {
                         Some(lt) => lt,
                         None => {
@@ -466,6 +1522,159 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 create_link.tag,
                             )
                         }
+                        LinkTypes::DnaHashToDnaRecipe => {
+                            validate_delete_link_dna_hash_to_dna_recipe(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::CravingTags => {
+                            validate_delete_link_craving_tags(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::TagToCravingRecipes => {
+                            validate_delete_link_tag_to_craving_recipes(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::AllLobbyAdminRoles => {
+                            validate_delete_link_all_lobby_admin_roles(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::LobbyMembers => {
+                            validate_delete_link_lobby_members(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::RecipeToStatuses => {
+                            validate_delete_link_recipe_to_statuses(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::AnnouncementUpdates => {
+                            validate_delete_link_announcement_updates(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::AllAnnouncements => {
+                            validate_delete_link_all_announcements(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::AllCravingProposals => {
+                            validate_delete_link_all_craving_proposals(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::CravingProposalToResonators => {
+                            validate_delete_link_craving_proposal_to_resonators(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::CravingProposalToDnaRecipe => {
+                            validate_delete_link_craving_proposal_to_dna_recipe(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::AllGlossaryEntries => {
+                            validate_delete_link_all_glossary_entries(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::DnaRecipeToGlossaryEntries => {
+                            validate_delete_link_dna_recipe_to_glossary_entries(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::DnaRecipeToCravingPreviews => {
+                            validate_delete_link_dna_recipe_to_craving_previews(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::AgentToJoinedRecipe => {
+                            validate_delete_link_agent_to_joined_recipe(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::JoinedRecipeToAgents => {
+                            validate_delete_link_joined_recipe_to_agents(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::DnaRecipeToOrigin => {
+                            validate_delete_link_dna_recipe_to_origin(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
                     }
                 }
                 OpRecord::CreatePrivateEntry { .. } => Ok(ValidateCallbackResult::Valid),
@@ -481,7 +1690,7 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                 _ => Ok(ValidateCallbackResult::Valid),
             }
         }
-        OpType::RegisterAgentActivity(agent_activity) => {
+        FlatOp::RegisterAgentActivity(agent_activity) => {
             match agent_activity {
                 OpActivity::CreateAgent { agent, action } => {
                     let previous_action = must_get_action(action.prev_action)?;
@@ -492,8 +1701,7 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         _ => {
                             Ok(
                                 ValidateCallbackResult::Invalid(
-                                    "The previous action for a `CreateAgent` action must be an `AgentValidationPkg`"
-                                        .to_string(),
+                                    CondenserError::MalformedOp { reason: String::from("The previous action for a `CreateAgent` action must be an `AgentValidationPkg`") }.encode(),
                                 ),
                             )
                         }
@@ -0,0 +1,172 @@
+use hdi::prelude::*;
+use condenser_common::CondenserError;
+use crate::{must_get_created_entry, LobbyDnaProperties};
+
+/// Makes `agent` an administrator of the lobby. Only used if `admin_mode` is enabled
+/// in the LobbyDnaProperties. The admin can step down by deleting the entry, the creator
+/// of the lobby and the admin who granted the role can revoke it the same way.
+#[hdk_entry_helper]
+#[derive(Clone)]
+pub struct LobbyAdminRole {
+    pub agent: AgentPubKey,
+    /// The role of the granting admin, None if granted by the creator of the lobby
+    pub granted_by_role: Option<ActionHash>,
+}
+impl LobbyDnaProperties {
+    pub fn is_admin_mode(&self) -> bool {
+        self.admin_mode.unwrap_or(false)
+    }
+}
+/// Checks that `agent` is the creator of the lobby or holds the given admin role, and has
+/// not stepped down from it before `prev_action`.
+///
+/// Only stepping down is checked here: validation can see the admin's own chain up to the
+/// validated action, but not whether someone else revoked the role in the meantime.
+/// Revoked roles are no longer handed out by the coordinator, so revocation only stops
+/// admins that use the regular zome functions.
+pub fn check_lobby_admin(
+    agent: &AgentPubKey,
+    prev_action: &ActionHash,
+    admin_role_hash: Option<ActionHash>,
+) -> ExternResult<Option<CondenserError>> {
+    let lobby_dna_properties = LobbyDnaProperties::get()?;
+    let creator = match lobby_dna_properties.creator {
+        Some(creator) => creator,
+        None => {
+            return Ok(Some(CondenserError::InvalidDnaProperties {
+                reason: String::from("lobbies in admin mode need a creator"),
+            }));
+        }
+    };
+    if agent == &creator {
+        return Ok(None);
+    }
+    let not_admin = CondenserError::NotAuthor { what: String::from("LobbyAdminRole") };
+    let admin_role_hash = match admin_role_hash {
+        Some(admin_role_hash) => admin_role_hash,
+        None => return Ok(Some(not_admin)),
+    };
+    match must_get_created_entry::<LobbyAdminRole>(admin_role_hash.clone())? {
+        Some((_, lobby_admin_role)) if &lobby_admin_role.agent == agent => {}
+        _ => return Ok(Some(not_admin)),
+    }
+    if has_stepped_down_before(agent, prev_action, &admin_role_hash)? {
+        return Ok(Some(not_admin));
+    }
+    Ok(None)
+}
+/// Checks whether the agent deleted the given admin role before `prev_action`.
+fn has_stepped_down_before(
+    agent: &AgentPubKey,
+    prev_action: &ActionHash,
+    admin_role_hash: &ActionHash,
+) -> ExternResult<bool> {
+    let activity = must_get_agent_activity(agent.clone(), ChainFilter::new(prev_action.clone()))?;
+    Ok(
+        activity.iter().any(|activity| match activity.action.action() {
+            Action::Delete(delete) => &delete.deletes_address == admin_role_hash,
+            _ => false,
+        }),
+    )
+}
+/// The admin themself can step down, the creator of the lobby and the admin who granted
+/// the role can revoke it.
+fn check_may_delete_role(
+    author: &AgentPubKey,
+    lobby_admin_role: &LobbyAdminRole,
+) -> ExternResult<Option<CondenserError>> {
+    if author == &lobby_admin_role.agent || LobbyDnaProperties::get()?.creator.as_ref() == Some(author) {
+        return Ok(None);
+    }
+    if let Some(granted_by_role) = lobby_admin_role.granted_by_role.clone() {
+        if let Some((_, granting_role)) = must_get_created_entry::<LobbyAdminRole>(granted_by_role)? {
+            if &granting_role.agent == author {
+                return Ok(None);
+            }
+        }
+    }
+    Ok(Some(CondenserError::NotAuthor { what: String::from("LobbyAdminRole") }))
+}
+pub fn validate_create_lobby_admin_role(
+    action: EntryCreationAction,
+    lobby_admin_role: LobbyAdminRole,
+) -> ExternResult<ValidateCallbackResult> {
+    if !LobbyDnaProperties::get()?.is_admin_mode() {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAllowed {
+                    reason: String::from("admin roles are only used in lobbies in admin mode"),
+                }.encode(),
+            ),
+        );
+    }
+    match check_lobby_admin(action.author(), action.prev_action(), lobby_admin_role.granted_by_role)? {
+        Some(err) => Ok(ValidateCallbackResult::Invalid(err.encode())),
+        None => Ok(ValidateCallbackResult::Valid),
+    }
+}
+pub fn validate_update_lobby_admin_role(
+    _action: Update,
+    _lobby_admin_role: LobbyAdminRole,
+    _original_action: EntryCreationAction,
+    _original_lobby_admin_role: LobbyAdminRole,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(CondenserError::NotUpdatable { what: String::from("LobbyAdminRole") }.encode()))
+}
+pub fn validate_delete_lobby_admin_role(
+    action: Delete,
+    _original_action: EntryCreationAction,
+    original_lobby_admin_role: LobbyAdminRole,
+) -> ExternResult<ValidateCallbackResult> {
+    match check_may_delete_role(&action.author, &original_lobby_admin_role)? {
+        Some(err) => Ok(ValidateCallbackResult::Invalid(err.encode())),
+        None => Ok(ValidateCallbackResult::Valid),
+    }
+}
+pub fn validate_create_link_all_lobby_admin_roles(
+    action: CreateLink,
+    _base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = ActionHash::try_from(target_address).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
+    let role_action = must_get_action(action_hash.clone())?;
+    if role_action.action().author() != &action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("LobbyAdminRole") }.encode(),
+            ),
+        );
+    }
+    if must_get_created_entry::<LobbyAdminRole>(action_hash)?.is_none() {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::WrongType { expected: String::from("LobbyAdminRole") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_all_lobby_admin_roles(
+    action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = ActionHash::try_from(target).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
+    let lobby_admin_role = match must_get_created_entry::<LobbyAdminRole>(action_hash)? {
+        Some((_, lobby_admin_role)) => lobby_admin_role,
+        None => {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    CondenserError::WrongType { expected: String::from("LobbyAdminRole") }.encode(),
+                ),
+            );
+        }
+    };
+    match check_may_delete_role(&action.author, &lobby_admin_role)? {
+        Some(err) => Ok(ValidateCallbackResult::Invalid(err.encode())),
+        None => Ok(ValidateCallbackResult::Valid),
+    }
+}
@@ -1,4 +1,6 @@
 use hdi::prelude::*;
+use condenser_common::CondenserError;
+use crate::check_lobby_admin;
 #[hdk_entry_helper]
 #[derive(Clone)]
 pub struct LobbyInfo {
@@ -6,21 +8,58 @@ pub struct LobbyInfo {
     pub unenforced_rules: Option<String>,
     pub logo_src: Option<String>,
     pub network_seed: String,
+    /// Admin role of the author, needed in admin mode unless the author is the creator
+    #[serde(default)]
+    pub admin_role_hash: Option<ActionHash>,
 }
 #[derive(Clone, Serialize, Deserialize, Debug, SerializedBytes)]
 pub struct LobbyDnaProperties {
     pub name: String,
     // pub creator: holo_hash::AgentPubKeyB64,  // In version 0.1.X of the Word Condenser, anyone can update the lobby info. That's to reduce friction of (out-of-band) invitations
+    /// Creator of the lobby, the root of all invitations in invitation-only lobbies.
+    /// The new fields are optional so that the properties of existing lobbies still parse.
+    #[serde(default)]
+    pub creator: Option<AgentPubKey>,
+    /// If true, joining agents need an InvitationProof as membrane proof
+    #[serde(default)]
+    pub invitation_only: Option<bool>,
+    /// If true, only the creator and admins appointed through LobbyAdminRole entries
+    /// can create and update the LobbyInfo
+    #[serde(default)]
+    pub admin_mode: Option<bool>,
+    /// Number of members that need to resonate with a CravingProposal before it can be
+    /// promoted to a DnaRecipe, DEFAULT_PROPOSAL_THRESHOLD if not set
+    #[serde(default)]
+    pub proposal_threshold: Option<usize>,
+}
+impl LobbyDnaProperties {
+    pub fn get() -> ExternResult<LobbyDnaProperties> {
+        LobbyDnaProperties::try_from(dna_info()?.modifiers.properties)
+            .map_err(|err| wasm_error!(WasmErrorInner::Guest(CondenserError::InvalidDnaProperties { reason: err.to_string() }.encode())))
+    }
+}
+fn validate_lobby_info_author(
+    author: &AgentPubKey,
+    prev_action: &ActionHash,
+    lobby_info: &LobbyInfo,
+) -> ExternResult<ValidateCallbackResult> {
+    if !LobbyDnaProperties::get()?.is_admin_mode() {
+        return Ok(ValidateCallbackResult::Valid);
+    }
+    match check_lobby_admin(author, prev_action, lobby_info.admin_role_hash.clone())? {
+        Some(err) => Ok(ValidateCallbackResult::Invalid(err.encode())),
+        None => Ok(ValidateCallbackResult::Valid),
+    }
 }
 pub fn validate_create_lobby_info(
-    _action: EntryCreationAction,
-    _lobby_info: LobbyInfo,
+    action: EntryCreationAction,
+    lobby_info: LobbyInfo,
 ) -> ExternResult<ValidateCallbackResult> {
-    Ok(ValidateCallbackResult::Valid)
+    validate_lobby_info_author(action.author(), action.prev_action(), &lobby_info)
 }
 pub fn validate_update_lobby_info(
-    _action: Update,
-    _lobby_info: LobbyInfo,
+    action: Update,
+    lobby_info: LobbyInfo,
     _original_action: EntryCreationAction,
     _original_lobby_info: LobbyInfo,
 ) -> ExternResult<ValidateCallbackResult> {
@@ -39,14 +78,15 @@ pub fn validate_update_lobby_info(
     //     return Ok(ValidateCallbackResult::Invalid(String::from("Only the creator of a Lobby is allowed to update the Lobby info")));
     // }
 
-    Ok(ValidateCallbackResult::Valid)
+    // Lobbies in admin mode opt into the creator/admin-only rule instead
+    validate_lobby_info_author(&action.author, &action.prev_action, &lobby_info)
 }
 pub fn validate_delete_lobby_info(
     _action: Delete,
     _original_action: EntryCreationAction,
     _original_lobby_info: LobbyInfo,
 ) -> ExternResult<ValidateCallbackResult> {
-    Ok(ValidateCallbackResult::Invalid(String::from("Lobby Infos cannot be deleted")))
+    Ok(ValidateCallbackResult::Invalid(CondenserError::NotDeletable { what: String::from("LobbyInfo") }.encode()))
 }
 pub fn validate_create_link_lobby_info_updates(
     _action: CreateLink,
@@ -62,7 +102,7 @@ pub fn validate_create_link_lobby_info_updates(
         .map_err(|e| wasm_error!(e))?
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+                WasmErrorInner::Guest(CondenserError::MissingEntry.encode())
             ),
         )?;
     let action_hash = ActionHash::try_from(target_address).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
@@ -73,7 +113,7 @@ pub fn validate_create_link_lobby_info_updates(
         .map_err(|e| wasm_error!(e))?
         .ok_or(
             wasm_error!(
-                WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+                WasmErrorInner::Guest(CondenserError::MissingEntry.encode())
             ),
         )?;
     Ok(ValidateCallbackResult::Valid)
@@ -87,7 +127,7 @@ pub fn validate_delete_link_lobby_info_updates(
 ) -> ExternResult<ValidateCallbackResult> {
     Ok(
         ValidateCallbackResult::Invalid(
-            String::from("LobbyInfoUpdates links cannot be deleted"),
+            CondenserError::NotDeletable { what: String::from("LobbyInfoUpdates link") }.encode(),
         ),
     )
 }
@@ -0,0 +1,46 @@
+use hdi::prelude::*;
+use condenser_common::CondenserError;
+/// Every agent registers themself as a member of the lobby by linking from the
+/// members anchor to their own public key. Nobody can register someone else.
+pub fn validate_create_link_lobby_members(
+    action: CreateLink,
+    _base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let member = match target_address.into_agent_pub_key() {
+        Some(member) => member,
+        None => {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    CondenserError::WrongType { expected: String::from("AgentPubKey") }.encode(),
+                ),
+            );
+        }
+    };
+    if member != action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("LobbyMembers link") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+/// Members can leave the directory again, but only on their own behalf.
+pub fn validate_delete_link_lobby_members(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("LobbyMembers link") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
@@ -0,0 +1,172 @@
+use hdi::prelude::*;
+use condenser_common::CondenserError;
+use crate::{must_get_created_entry, DnaRecipe};
+
+/// Version of the RecipeExport format written by `export_recipe`
+pub const RECIPE_EXPORT_VERSION: u32 = 1;
+
+/// A DnaRecipe as it is shared between lobbies, together with the lobby the exporter
+/// claims it was originally registered in.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RecipeExport {
+    pub version: u32,
+    pub dna_recipe: DnaRecipe,
+    pub origin_lobby_dna_hash: DnaHash,
+    pub origin_lobby_name: String,
+    pub exported_at: Timestamp,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, SerializedBytes)]
+pub struct SignedRecipeExport {
+    pub export: RecipeExport,
+    pub exported_by: AgentPubKey,
+    /// Signature of `exported_by` over the export
+    pub signature: Signature,
+}
+
+impl SignedRecipeExport {
+    /// Checks the version and the signature, returns the reason if they don't check out.
+    /// A valid signature only shows who made the export, not that its origin is true.
+    pub fn verify(&self) -> ExternResult<Option<CondenserError>> {
+        if self.export.version != RECIPE_EXPORT_VERSION {
+            return Ok(Some(CondenserError::InvalidRecipeExport {
+                reason: format!("unsupported version {}", self.export.version),
+            }));
+        }
+        if !verify_signature(self.exported_by.clone(), self.signature.clone(), &self.export)? {
+            return Ok(Some(CondenserError::InvalidRecipeExport { reason: String::from("invalid signature") }));
+        }
+        Ok(None)
+    }
+}
+
+/// Where an imported DnaRecipe comes from according to whoever exported it. Holds
+/// everything of the SignedRecipeExport except the recipe itself, so that the signature
+/// can be checked against the recipe.
+///
+/// The origin is unverified: validation can't reach into the origin lobby, so neither
+/// the origin lobby nor the membership of `exported_by` in it can be checked. Show it as
+/// a claim of `exported_by`.
+#[hdk_entry_helper]
+#[derive(Clone)]
+pub struct RecipeOrigin {
+    /// The imported recipe in this lobby
+    pub dna_recipe_hash: ActionHash,
+    pub version: u32,
+    pub origin_lobby_dna_hash: DnaHash,
+    pub origin_lobby_name: String,
+    pub exported_at: Timestamp,
+    pub exported_by: AgentPubKey,
+    pub signature: Signature,
+}
+
+impl RecipeOrigin {
+    pub fn to_signed_recipe_export(&self, dna_recipe: DnaRecipe) -> SignedRecipeExport {
+        SignedRecipeExport {
+            export: RecipeExport {
+                version: self.version,
+                dna_recipe,
+                origin_lobby_dna_hash: self.origin_lobby_dna_hash.clone(),
+                origin_lobby_name: self.origin_lobby_name.clone(),
+                exported_at: self.exported_at,
+            },
+            exported_by: self.exported_by.clone(),
+            signature: self.signature.clone(),
+        }
+    }
+}
+pub fn validate_create_recipe_origin(
+    action: EntryCreationAction,
+    recipe_origin: RecipeOrigin,
+) -> ExternResult<ValidateCallbackResult> {
+    if recipe_origin.origin_lobby_dna_hash == dna_info()?.hash {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAllowed { reason: String::from("the recipe was exported from this lobby") }.encode(),
+            ),
+        );
+    }
+    if must_get_action(recipe_origin.dna_recipe_hash.clone())?.action().author() != action.author() {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("DnaRecipe") }.encode(),
+            ),
+        );
+    }
+    let dna_recipe = match must_get_created_entry::<DnaRecipe>(recipe_origin.dna_recipe_hash.clone())? {
+        Some((_, dna_recipe)) => dna_recipe,
+        None => {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    CondenserError::WrongType { expected: String::from("DnaRecipe") }.encode(),
+                ),
+            );
+        }
+    };
+    match recipe_origin.to_signed_recipe_export(dna_recipe).verify()? {
+        Some(err) => Ok(ValidateCallbackResult::Invalid(err.encode())),
+        None => Ok(ValidateCallbackResult::Valid),
+    }
+}
+pub fn validate_update_recipe_origin(
+    _action: Update,
+    _recipe_origin: RecipeOrigin,
+    _original_action: EntryCreationAction,
+    _original_recipe_origin: RecipeOrigin,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(CondenserError::NotUpdatable { what: String::from("RecipeOrigin") }.encode()))
+}
+pub fn validate_delete_recipe_origin(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_recipe_origin: RecipeOrigin,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(CondenserError::NotDeletable { what: String::from("RecipeOrigin") }.encode()))
+}
+pub fn validate_create_link_dna_recipe_to_origin(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = ActionHash::try_from(target_address).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
+    let record = must_get_valid_record(action_hash)?;
+    if record.action().author() != &action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("RecipeOrigin") }.encode(),
+            ),
+        );
+    }
+    let recipe_origin: RecipeOrigin = match record.entry().to_app_option().ok().flatten() {
+        Some(recipe_origin) => recipe_origin,
+        None => {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    CondenserError::WrongType { expected: String::from("RecipeOrigin") }.encode(),
+                ),
+            );
+        }
+    };
+    if base_address != AnyLinkableHash::from(recipe_origin.dna_recipe_hash) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::WrongType { expected: String::from("DnaRecipe of the RecipeOrigin") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_dna_recipe_to_origin(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            CondenserError::NotDeletable { what: String::from("DnaRecipeToOrigin link") }.encode(),
+        ),
+    )
+}
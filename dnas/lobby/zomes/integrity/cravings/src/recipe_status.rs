@@ -0,0 +1,131 @@
+use hdi::prelude::*;
+use condenser_common::CondenserError;
+use crate::{check_lobby_admin, must_get_created_entry, DnaRecipe, LobbyDnaProperties};
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecipeState {
+    Active,
+    /// The craving is over but is still listed in the lobby
+    Closed,
+    /// The craving is no longer listed in the lobby
+    Archived,
+    /// The craving was replaced by the recipe in `superseded_by`
+    Superseded,
+}
+/// The state of a DnaRecipe, which can't be updated itself. A recipe is Active until
+/// a status is linked to it, and the latest linked status wins.
+#[hdk_entry_helper]
+#[derive(Clone)]
+pub struct RecipeStatus {
+    pub dna_recipe_hash: ActionHash,
+    pub state: RecipeState,
+    pub superseded_by: Option<ActionHash>,
+    /// Admin role of the author, needed unless the author is the author of the recipe
+    pub admin_role_hash: Option<ActionHash>,
+}
+/// The author of the recipe can always set its status, admins of lobbies in admin mode as well.
+fn check_may_set_recipe_status(
+    author: &AgentPubKey,
+    prev_action: &ActionHash,
+    recipe_author: &AgentPubKey,
+    recipe_status: &RecipeStatus,
+) -> ExternResult<Option<CondenserError>> {
+    if author == recipe_author {
+        return Ok(None);
+    }
+    if !LobbyDnaProperties::get()?.is_admin_mode() {
+        return Ok(Some(CondenserError::NotAuthor { what: String::from("DnaRecipe") }));
+    }
+    check_lobby_admin(author, prev_action, recipe_status.admin_role_hash.clone())
+}
+pub fn validate_create_recipe_status(
+    action: EntryCreationAction,
+    recipe_status: RecipeStatus,
+) -> ExternResult<ValidateCallbackResult> {
+    if must_get_created_entry::<DnaRecipe>(recipe_status.dna_recipe_hash.clone())?.is_none() {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::WrongType { expected: String::from("DnaRecipe") }.encode(),
+            ),
+        );
+    }
+    let superseded_by_is_valid = match (recipe_status.state, &recipe_status.superseded_by) {
+        (RecipeState::Superseded, Some(superseded_by)) => must_get_created_entry::<DnaRecipe>(superseded_by.clone())?.is_some(),
+        (RecipeState::Superseded, None) | (_, Some(_)) => false,
+        _ => true,
+    };
+    if !superseded_by_is_valid {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::MalformedOp {
+                    reason: String::from("superseded_by must be a DnaRecipe exactly for superseded recipes"),
+                }.encode(),
+            ),
+        );
+    }
+    let recipe_action = must_get_action(recipe_status.dna_recipe_hash.clone())?;
+    match check_may_set_recipe_status(action.author(), action.prev_action(), recipe_action.action().author(), &recipe_status)? {
+        Some(err) => Ok(ValidateCallbackResult::Invalid(err.encode())),
+        None => Ok(ValidateCallbackResult::Valid),
+    }
+}
+pub fn validate_update_recipe_status(
+    _action: Update,
+    _recipe_status: RecipeStatus,
+    _original_action: EntryCreationAction,
+    _original_recipe_status: RecipeStatus,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(CondenserError::NotUpdatable { what: String::from("RecipeStatus") }.encode()))
+}
+pub fn validate_delete_recipe_status(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_recipe_status: RecipeStatus,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(CondenserError::NotDeletable { what: String::from("RecipeStatus") }.encode()))
+}
+pub fn validate_create_link_recipe_to_statuses(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = ActionHash::try_from(target_address).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
+    if must_get_action(action_hash.clone())?.action().author() != &action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("RecipeStatus") }.encode(),
+            ),
+        );
+    }
+    let recipe_status = match must_get_created_entry::<RecipeStatus>(action_hash)? {
+        Some((_, recipe_status)) => recipe_status,
+        None => {
+            return Ok(
+                ValidateCallbackResult::Invalid(
+                    CondenserError::WrongType { expected: String::from("RecipeStatus") }.encode(),
+                ),
+            );
+        }
+    };
+    if base_address != AnyLinkableHash::from(recipe_status.dna_recipe_hash) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::WrongType { expected: String::from("DnaRecipe of the RecipeStatus") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_recipe_to_statuses(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(
+        ValidateCallbackResult::Invalid(
+            CondenserError::NotDeletable { what: String::from("RecipeToStatuses link") }.encode(),
+        ),
+    )
+}
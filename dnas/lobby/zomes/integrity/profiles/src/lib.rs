@@ -1 +1,413 @@
-extern crate hc_zome_profiles_integrity;
\ No newline at end of file
+pub mod profile;
+pub use profile::*;
+use hdi::prelude::*;
+use condenser_common::CondenserError;
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[hdk_entry_defs]
+#[unit_enum(UnitEntryTypes)]
+pub enum EntryTypes {
+    Profile(Profile),
+}
+#[derive(Serialize, Deserialize)]
+#[hdk_link_types]
+pub enum LinkTypes {
+    PrefixPath,
+    PathToAgent,
+    AgentToProfile,
+}
+#[hdk_extern]
+pub fn genesis_self_check(
+    _data: GenesisSelfCheckData,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_agent_joining(
+    _agent_pub_key: AgentPubKey,
+    _membrane_proof: &Option<MembraneProof>,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
+#[hdk_extern]
+pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
+    match op.flattened::<EntryTypes, LinkTypes>()? {
+        FlatOp::StoreEntry(store_entry) => {
+            match store_entry {
+                OpEntry::CreateEntry { app_entry, action } => {
+                    match app_entry {
+                        EntryTypes::Profile(profile) => {
+                            validate_create_profile(
+                                EntryCreationAction::Create(action),
+                                profile,
+                            )
+                        }
+                    }
+                }
+                OpEntry::UpdateEntry { app_entry, action, .. } => {
+                    match app_entry {
+                        EntryTypes::Profile(profile) => {
+                            validate_create_profile(
+                                EntryCreationAction::Update(action),
+                                profile,
+                            )
+                        }
+                    }
+                }
+                _ => Ok(ValidateCallbackResult::Valid),
+            }
+        }
+        FlatOp::RegisterUpdate(update_entry) => {
+            match update_entry {
+                OpUpdate::Entry {
+                    original_action,
+                    original_app_entry,
+                    app_entry,
+                    action,
+                } => {
+                    match (app_entry, original_app_entry) {
+                        (
+                            EntryTypes::Profile(profile),
+                            EntryTypes::Profile(original_profile),
+                        ) => {
+                            validate_update_profile(
+                                action,
+                                profile,
+                                original_action,
+                                original_profile,
+                            )
+                        }
+                    }
+                }
+                _ => Ok(ValidateCallbackResult::Valid),
+            }
+        }
+        FlatOp::RegisterDelete(delete_entry) => {
+            match delete_entry {
+                OpDelete::Entry { original_action, original_app_entry, action } => {
+                    match original_app_entry {
+                        EntryTypes::Profile(profile) => {
+                            validate_delete_profile(
+                                action,
+                                original_action,
+                                profile,
+                            )
+                        }
+                    }
+                }
+                _ => Ok(ValidateCallbackResult::Valid),
+            }
+        }
+        FlatOp::RegisterCreateLink {
+            link_type,
+            base_address,
+            target_address,
+            tag,
+            action,
+        } => {
+            match link_type {
+                LinkTypes::PrefixPath => {
+                    validate_create_link_prefix_path(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::PathToAgent => {
+                    validate_create_link_path_to_agent(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AgentToProfile => {
+                    validate_create_link_agent_to_profile(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+            }
+        }
+        FlatOp::RegisterDeleteLink {
+            link_type,
+            base_address,
+            target_address,
+            tag,
+            original_action,
+            action,
+        } => {
+            match link_type {
+                LinkTypes::PrefixPath => {
+                    validate_delete_link_prefix_path(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::PathToAgent => {
+                    validate_delete_link_path_to_agent(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+                LinkTypes::AgentToProfile => {
+                    validate_delete_link_agent_to_profile(
+                        action,
+                        original_action,
+                        base_address,
+                        target_address,
+                        tag,
+                    )
+                }
+            }
+        }
+        FlatOp::StoreRecord(store_record) => {
+            match store_record {
+                OpRecord::CreateEntry { app_entry, action } => {
+                    match app_entry {
+                        EntryTypes::Profile(profile) => {
+                            validate_create_profile(
+                                EntryCreationAction::Create(action),
+                                profile,
+                            )
+                        }
+                    }
+                }
+                OpRecord::UpdateEntry {
+                    original_action_hash,
+                    app_entry,
+                    action,
+                    ..
+                } => {
+                    let original_record = must_get_valid_record(original_action_hash)?;
+                    let original_action = original_record.action().clone();
+                    let original_action = match original_action {
+                        Action::Create(create) => EntryCreationAction::Create(create),
+                        Action::Update(update) => EntryCreationAction::Update(update),
+                        _ => {
+                            return Ok(
+                                ValidateCallbackResult::Invalid(
+                                    CondenserError::MalformedOp { reason: String::from("Original action for an update must be a Create or Update action") }.encode(),
+                                ),
+                            );
+                        }
+                    };
+                    match app_entry {
+                        EntryTypes::Profile(profile) => {
+                            let result = validate_create_profile(
+                                EntryCreationAction::Update(action.clone()),
+                                profile.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_profile: Option<Profile> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_profile = match original_profile {
+                                    Some(profile) => profile,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                CondenserError::MalformedOp { reason: String::from("The updated entry type must be the same as the original entry type") }.encode(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_profile(
+                                    action,
+                                    profile,
+                                    original_action,
+                                    original_profile,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                    }
+                }
+                OpRecord::DeleteEntry { original_action_hash, action, .. } => {
+                    let original_record = must_get_valid_record(original_action_hash)?;
+                    let original_action = original_record.action().clone();
+                    let original_action = match original_action {
+                        Action::Create(create) => EntryCreationAction::Create(create),
+                        Action::Update(update) => EntryCreationAction::Update(update),
+                        _ => {
+                            return Ok(
+                                ValidateCallbackResult::Invalid(
+                                    CondenserError::MalformedOp { reason: String::from("Original action for a delete must be a Create or Update action") }.encode(),
+                                ),
+                            );
+                        }
+                    };
+                    let app_entry_type = match original_action.entry_type() {
+                        EntryType::App(app_entry_type) => app_entry_type,
+                        _ => {
+                            return Ok(ValidateCallbackResult::Valid);
+                        }
+                    };
+                    let entry = match original_record.entry().as_option() {
+                        Some(entry) => entry,
+                        None => {
+                            if original_action.entry_type().visibility().is_public() {
+                                return Ok(
+                                    ValidateCallbackResult::Invalid(
+                                        CondenserError::MalformedOp { reason: String::from("Original record for a delete of a public entry must contain an entry") }.encode(),
+                                    ),
+                                );
+                            } else {
+                                return Ok(ValidateCallbackResult::Valid);
+                            }
+                        }
+                    };
+                    let original_app_entry = match EntryTypes::deserialize_from_type(
+                        app_entry_type.zome_index,
+                        app_entry_type.entry_index,
+                        entry,
+                    )? {
+                        Some(app_entry) => app_entry,
+                        None => {
+                            return Ok(
+                                ValidateCallbackResult::Invalid(
+                                    CondenserError::MalformedOp { reason: String::from("Original app entry must be one of the defined entry types for this zome") }.encode(),
+                                ),
+                            );
+                        }
+                    };
+                    match original_app_entry {
+                        EntryTypes::Profile(original_profile) => {
+                            validate_delete_profile(
+                                action,
+                                original_action,
+                                original_profile,
+                            )
+                        }
+                    }
+                }
+                OpRecord::CreateLink {
+                    base_address,
+                    target_address,
+                    tag,
+                    link_type,
+                    action,
+                } => {
+                    match link_type {
+                        LinkTypes::PrefixPath => {
+                            validate_create_link_prefix_path(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::PathToAgent => {
+                            validate_create_link_path_to_agent(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                        LinkTypes::AgentToProfile => {
+                            validate_create_link_agent_to_profile(
+                                action,
+                                base_address,
+                                target_address,
+                                tag,
+                            )
+                        }
+                    }
+                }
+                OpRecord::DeleteLink { original_action_hash, base_address, action } => {
+                    let record = must_get_valid_record(original_action_hash)?;
+                    let create_link = match record.action() {
+                        Action::CreateLink(create_link) => create_link.clone(),
+                        _ => {
+                            return Ok(
+                                ValidateCallbackResult::Invalid(
+                                    CondenserError::MalformedOp { reason: String::from("The action that a DeleteLink deletes must be a CreateLink") }.encode(),
+                                ),
+                            );
+                        }
+                    };
+                    let link_type = match LinkTypes::from_type(
+                        create_link.zome_index,
+                        create_link.link_type,
+                    )? {
+                        Some(lt) => lt,
+                        None => {
+                            return Ok(ValidateCallbackResult::Valid);
+                        }
+                    };
+                    match link_type {
+                        LinkTypes::PrefixPath => {
+                            validate_delete_link_prefix_path(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::PathToAgent => {
+                            validate_delete_link_path_to_agent(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::AgentToProfile => {
+                            validate_delete_link_agent_to_profile(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                    }
+                }
+                OpRecord::CreatePrivateEntry { .. } => Ok(ValidateCallbackResult::Valid),
+                OpRecord::UpdatePrivateEntry { .. } => Ok(ValidateCallbackResult::Valid),
+                OpRecord::CreateCapClaim { .. } => Ok(ValidateCallbackResult::Valid),
+                OpRecord::CreateCapGrant { .. } => Ok(ValidateCallbackResult::Valid),
+                OpRecord::UpdateCapClaim { .. } => Ok(ValidateCallbackResult::Valid),
+                OpRecord::UpdateCapGrant { .. } => Ok(ValidateCallbackResult::Valid),
+                OpRecord::Dna { .. } => Ok(ValidateCallbackResult::Valid),
+                OpRecord::OpenChain { .. } => Ok(ValidateCallbackResult::Valid),
+                OpRecord::CloseChain { .. } => Ok(ValidateCallbackResult::Valid),
+                OpRecord::InitZomesComplete { .. } => Ok(ValidateCallbackResult::Valid),
+                _ => Ok(ValidateCallbackResult::Valid),
+            }
+        }
+        FlatOp::RegisterAgentActivity(agent_activity) => {
+            match agent_activity {
+                OpActivity::CreateAgent { agent, action } => {
+                    let previous_action = must_get_action(action.prev_action)?;
+                    match previous_action.action() {
+                        Action::AgentValidationPkg(
+                            AgentValidationPkg { membrane_proof, .. },
+                        ) => validate_agent_joining(agent, membrane_proof),
+                        _ => {
+                            Ok(
+                                ValidateCallbackResult::Invalid(
+                                    CondenserError::MalformedOp { reason: String::from("The previous action for a `CreateAgent` action must be an `AgentValidationPkg`") }.encode(),
+                                ),
+                            )
+                        }
+                    }
+                }
+                _ => Ok(ValidateCallbackResult::Valid),
+            }
+        }
+    }
+}
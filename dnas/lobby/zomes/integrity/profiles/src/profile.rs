@@ -0,0 +1,171 @@
+use hdi::prelude::*;
+use std::collections::BTreeMap;
+use condenser_common::CondenserError;
+pub const MIN_NICKNAME_CHARS: usize = 3;
+pub const MAX_NICKNAME_CHARS: usize = 50;
+/// Avatars are stored as data URLs in the `avatar` field
+pub const MAX_AVATAR_BYTES: usize = 300_000;
+#[hdk_entry_helper]
+#[derive(Clone)]
+pub struct Profile {
+    pub nickname: String,
+    pub fields: BTreeMap<String, String>,
+}
+pub fn validate_profile(profile: &Profile) -> Option<CondenserError> {
+    let nickname_chars = profile.nickname.chars().count();
+    if nickname_chars < MIN_NICKNAME_CHARS {
+        return Some(CondenserError::TooShort {
+            field: String::from("nickname"),
+            min: MIN_NICKNAME_CHARS,
+            actual: nickname_chars,
+        });
+    }
+    if nickname_chars > MAX_NICKNAME_CHARS {
+        return Some(CondenserError::TooLong {
+            field: String::from("nickname"),
+            max: MAX_NICKNAME_CHARS,
+            actual: nickname_chars,
+        });
+    }
+    if let Some(avatar) = profile.fields.get("avatar") {
+        if avatar.len() > MAX_AVATAR_BYTES {
+            return Some(CondenserError::TooLong {
+                field: String::from("avatar"),
+                max: MAX_AVATAR_BYTES,
+                actual: avatar.len(),
+            });
+        }
+    }
+    None
+}
+pub fn validate_create_profile(
+    _action: EntryCreationAction,
+    profile: Profile,
+) -> ExternResult<ValidateCallbackResult> {
+    match validate_profile(&profile) {
+        Some(err) => Ok(ValidateCallbackResult::Invalid(err.encode())),
+        None => Ok(ValidateCallbackResult::Valid),
+    }
+}
+pub fn validate_update_profile(
+    action: Update,
+    _profile: Profile,
+    original_action: EntryCreationAction,
+    _original_profile: Profile,
+) -> ExternResult<ValidateCallbackResult> {
+    if &action.author != original_action.author() {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("Profile") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_profile(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_profile: Profile,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(CondenserError::NotDeletable { what: String::from("Profile") }.encode()))
+}
+/// The nickname prefix paths are plain path links, anyone may create them.
+pub fn validate_create_link_prefix_path(
+    _action: CreateLink,
+    _base_address: AnyLinkableHash,
+    _target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_prefix_path(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(CondenserError::NotDeletable { what: String::from("PrefixPath link") }.encode()))
+}
+/// Agents only list themselves under the prefix of their nickname.
+pub fn validate_create_link_path_to_agent(
+    action: CreateLink,
+    _base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if target_address.into_agent_pub_key().as_ref() != Some(&action.author) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("PathToAgent link") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_path_to_agent(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("PathToAgent link") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+/// Agents only link their own public key to a profile they authored.
+pub fn validate_create_link_agent_to_profile(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if base_address.into_agent_pub_key().as_ref() != Some(&action.author) {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("AgentToProfile link") }.encode(),
+            ),
+        );
+    }
+    let action_hash = ActionHash::try_from(target_address).map_err(|err| wasm_error!(WasmErrorInner::from(err)))?;
+    let record = must_get_valid_record(action_hash)?;
+    if record.action().author() != &action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("Profile") }.encode(),
+            ),
+        );
+    }
+    let _profile: crate::Profile = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(
+            wasm_error!(
+                WasmErrorInner::Guest(CondenserError::MissingEntry.encode())
+            ),
+        )?;
+    Ok(ValidateCallbackResult::Valid)
+}
+pub fn validate_delete_link_agent_to_profile(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author != original_action.author {
+        return Ok(
+            ValidateCallbackResult::Invalid(
+                CondenserError::NotAuthor { what: String::from("AgentToProfile link") }.encode(),
+            ),
+        );
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
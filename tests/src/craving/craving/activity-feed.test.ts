@@ -0,0 +1,71 @@
+import test from 'node:test';
+import assert from 'node:assert';
+
+import { runScenario, pause } from '@holochain/tryorama';
+import { NewEntryAction, Record } from '@holochain/client';
+
+import { createAssociation } from './association.test.js';
+import { createReflection } from './reflection.test.js';
+import { createCommentOnReflection } from './comment-on-reflection.test.js';
+
+test('activity feed merges entries, comments and resonance, most recent first', { concurrency: 1 }, async t => {
+  await runScenario(async scenario => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + '/../workdir/word-condenser.happ';
+
+    // Set up the app to be installed 
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    const [alice, bob] = await scenario.addPlayersWithApps([appSource, appSource]);
+    await scenario.shareAllAgents();
+
+    const associationRecord: Record = await createAssociation(alice.cells[0]);
+    const reflectionRecord: Record = await createReflection(alice.cells[0]);
+
+    await pause(1200);
+
+    const since = Date.now() * 1000;
+    await createCommentOnReflection(bob.cells[0], {
+      reflection_hash: reflectionRecord.signed_action.hashed.hash,
+      comment: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
+    });
+    await bob.cells[0].callZome({
+      zome_name: "craving",
+      fn_name: "add_resonator_for_entry",
+      payload: (reflectionRecord.signed_action.hashed.content as NewEntryAction).entry_hash,
+    });
+
+    await pause(1200);
+
+    let events: any[] = await alice.cells[0].callZome({
+      zome_name: "craving",
+      fn_name: "get_activity_feed",
+      payload: { since: null, limit: null },
+    });
+    const createdHashes = events
+      .filter(e => e.type === "EntryCreated")
+      .map(e => e.action.hashed.hash.toString());
+    assert.ok(createdHashes.includes(associationRecord.signed_action.hashed.hash.toString()));
+    assert.ok(createdHashes.includes(reflectionRecord.signed_action.hashed.hash.toString()));
+    assert.ok(events.some(e => e.type === "LinkCreated" && e.link_type === "EntryToResonator"));
+    const timestamps = events.map(e => e.action.hashed.content.timestamp);
+    assert.deepEqual(timestamps, [...timestamps].sort((a, b) => b - a));
+
+    // Only what bob did since then, limited to the most recent event
+    events = await alice.cells[0].callZome({
+      zome_name: "craving",
+      fn_name: "get_activity_feed",
+      payload: { since, limit: null },
+    });
+    assert.ok(events.length > 0);
+    assert.ok(events.every(e => e.action.hashed.content.author.toString() === bob.agentPubKey.toString()));
+
+    events = await alice.cells[0].callZome({
+      zome_name: "craving",
+      fn_name: "get_activity_feed",
+      payload: { since, limit: 1 },
+    });
+    assert.equal(events.length, 1);
+  });
+});
@@ -0,0 +1,65 @@
+import test from 'node:test';
+import assert from 'node:assert';
+
+import { runScenario, pause } from '@holochain/tryorama';
+
+test('offers come with the profiles of their authors from the lobby', { concurrency: 1 }, async t => {
+  await runScenario(async scenario => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + '/../workdir/word-condenser.happ';
+
+    // Set up the app to be installed 
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    const [alice, bob] = await scenario.addPlayersWithApps([appSource, appSource]);
+    await scenario.shareAllAgents();
+
+    // Profiles are looked up in the lobby clones the craving is listed in
+    const lobbyModifiers = { network_seed: "profiles lobby", properties: { name: "Profiles lobby" } };
+    const properties = {
+      title: "Workplace feelings",
+      description: "The feeling of being the only one who refills the coffee machine",
+      max_anecdote_chars: null,
+      max_association_chars: null,
+      max_offer_chars: null,
+      max_reflection_chars: null,
+    };
+    const cravingModifiers = { network_seed: "profiles craving", properties, origin_time: 1674053334548000 };
+    const cells = async player => ({
+      lobby: await player.appAgentWs.createCloneCell({ role_name: "lobby", modifiers: lobbyModifiers, name: "Profiles lobby" }),
+      craving: await player.appAgentWs.createCloneCell({ role_name: "craving", modifiers: cravingModifiers, name: "Workplace feelings" }),
+    });
+    const aliceCells = await cells(alice);
+    const bobCells = await cells(bob);
+    const call = (player, cell, zome_name, fn_name, payload) => player.appAgentWs.callZome({ cell_id: cell.cell_id, zome_name, fn_name, payload });
+
+    await call(alice, aliceCells.lobby, "profiles", "create_profile", {
+      nickname: "Alice",
+      fields: { avatar: "data:image/png;base64,AAAA" },
+    });
+    await call(alice, aliceCells.lobby, "cravings", "create_dna_recipe", {
+      dna_recipe: {
+        title: properties.title,
+        network_seed: "profiles craving",
+        properties,
+        origin_time: 1674053334548000,
+        membrane_proof: null,
+        resulting_dna_hash: aliceCells.craving.cell_id[0],
+      },
+    });
+
+    await call(alice, aliceCells.craving, "craving", "create_offer", { offer: "Alice's offer", explanation: "" });
+    // Bob has no profile
+    await call(bob, bobCells.craving, "craving", "create_offer", { offer: "Bob's offer", explanation: "" });
+
+    await pause(1200);
+
+    const offers: any[] = await call(bob, bobCells.craving, "craving", "get_all_offers_with_authors", null);
+    assert.equal(offers.length, 2);
+    const aliceOffer = offers.find(o => o.record.signed_action.hashed.content.author.toString() === alice.agentPubKey.toString());
+    const bobOffer = offers.find(o => o.record.signed_action.hashed.content.author.toString() === bob.agentPubKey.toString());
+    assert.deepEqual(aliceOffer.author_profile, { nickname: "Alice", avatar: "data:image/png;base64,AAAA" });
+    assert.equal(bobOffer.author_profile, null);
+  });
+});
@@ -0,0 +1,67 @@
+import test from 'node:test';
+import assert from 'node:assert';
+
+import { runScenario, pause } from '@holochain/tryorama';
+import { NewEntryAction, Record } from '@holochain/client';
+
+import { createAssociation } from './association.test.js';
+import { createOffer } from './offer.test.js';
+import { createReflection } from './reflection.test.js';
+
+test('contributions of an agent can be queried by kind', { concurrency: 1 }, async t => {
+  await runScenario(async scenario => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + '/../workdir/word-condenser.happ';
+
+    // Set up the app to be installed 
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    const [alice, bob] = await scenario.addPlayersWithApps([appSource, appSource]);
+    await scenario.shareAllAgents();
+
+    const associationRecord: Record = await createAssociation(alice.cells[0]);
+    const offerRecord: Record = await createOffer(alice.cells[0]);
+    const reflectionRecord: Record = await createReflection(bob.cells[0]);
+    await alice.cells[0].callZome({
+      zome_name: "craving",
+      fn_name: "add_resonator_for_entry",
+      payload: (reflectionRecord.signed_action.hashed.content as NewEntryAction).entry_hash,
+    });
+
+    await pause(1200);
+
+    let contributions: any[] = await bob.cells[0].callZome({
+      zome_name: "craving",
+      fn_name: "get_contributions_by_agent",
+      payload: { agent: alice.agentPubKey, kinds: null },
+    });
+    assert.deepEqual(contributions.map(c => c.kind), ["Association", "Offer", "Resonance"]);
+    assert.deepEqual(contributions[0].record, associationRecord);
+    assert.deepEqual(contributions[1].record, offerRecord);
+
+    contributions = await bob.cells[0].callZome({
+      zome_name: "craving",
+      fn_name: "get_contributions_by_agent",
+      payload: { agent: alice.agentPubKey, kinds: ["Offer"] },
+    });
+    assert.equal(contributions.length, 1);
+    assert.deepEqual(contributions[0].record, offerRecord);
+
+    // Withdrawn resonance is no longer a contribution
+    await alice.cells[0].callZome({
+      zome_name: "craving",
+      fn_name: "remove_resonator_for_entry",
+      payload: (reflectionRecord.signed_action.hashed.content as NewEntryAction).entry_hash,
+    });
+
+    await pause(1200);
+
+    contributions = await bob.cells[0].callZome({
+      zome_name: "craving",
+      fn_name: "get_contributions_by_agent",
+      payload: { agent: alice.agentPubKey, kinds: ["Resonance"] },
+    });
+    assert.equal(contributions.length, 0);
+  });
+});
@@ -0,0 +1,72 @@
+import test from 'node:test';
+import assert from 'node:assert';
+
+import { runScenario } from '@holochain/tryorama';
+import { encode } from '@msgpack/msgpack';
+
+test('cravings of invitation-only lobbies can only be joined by lobby members', { concurrency: 1 }, async t => {
+  await runScenario(async scenario => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + '/../workdir/word-condenser.happ';
+
+    // Set up the app to be installed 
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    const [alice, bob, carol] = await scenario.addPlayersWithApps([appSource, appSource, appSource]);
+    await scenario.shareAllAgents();
+
+    const lobby = await alice.appAgentWs.createCloneCell({
+      role_name: "lobby",
+      modifiers: {
+        network_seed: "secret",
+        properties: { name: "Secret lobby", creator: alice.agentPubKey, invitation_only: true },
+      },
+      name: "Secret lobby",
+    });
+    const bobProof = await alice.appAgentWs.callZome({
+      cell_id: lobby.cell_id,
+      zome_name: "cravings",
+      fn_name: "create_invitation",
+      payload: bob.agentPubKey,
+    });
+
+    const properties = {
+      title: "Secret craving",
+      description: "Only for members of the secret lobby",
+      max_anecdote_chars: null,
+      max_association_chars: null,
+      max_offer_chars: null,
+      max_reflection_chars: null,
+      lobby_dna_hash: lobby.cell_id[0],
+      lobby_creator: alice.agentPubKey,
+    };
+    const cloneCraving = (player, membraneProof?) => player.appAgentWs.createCloneCell({
+      role_name: "craving",
+      modifiers: { network_seed: "secret craving", properties, origin_time: 1674053334548000 },
+      membrane_proof: membraneProof ? encode(membraneProof) : undefined,
+      name: "Secret craving",
+    });
+
+    // The lobby creator joins without a proof, the invited member with the lobby invitation
+    assert.ok(await cloneCraving(alice));
+    assert.ok(await cloneCraving(bob, bobProof));
+
+    // Carol is not a member of the lobby
+    await assert.rejects(cloneCraving(carol));
+    await assert.rejects(cloneCraving(carol, bobProof), /InvalidMembraneProof/);
+  });
+});
+
+test('the plain hApp installs with a craving without properties', { concurrency: 1 }, async t => {
+  await runScenario(async scenario => {
+    const testAppPath = process.cwd() + '/../workdir/word-condenser.happ';
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    const [alice] = await scenario.addPlayersWithApps([appSource]);
+
+    const craving = alice.namedCells.get("craving")!;
+    const participants: any[] = await craving.callZome({ zome_name: "craving", fn_name: "get_all_participants", payload: null });
+    assert.equal(participants.length, 1);
+  });
+});
@@ -0,0 +1,111 @@
+import test from 'node:test';
+import assert from 'node:assert';
+
+import { runScenario, pause, CallableCell } from '@holochain/tryorama';
+import { NewEntryAction, ActionHash, Record, AppBundleSource } from '@holochain/client';
+import { decode } from '@msgpack/msgpack';
+
+import { createReflection } from './reflection.test.js';
+import { createCommentOnReflection } from './comment-on-reflection.test.js';
+
+test('comment on a Reflection notifies its author', { concurrency: 1 }, async t => {
+  await runScenario(async scenario => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + '/../workdir/word-condenser.happ';
+
+    // Set up the app to be installed 
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    // Add 2 players with the test app to the Scenario. The returned players
+    // can be destructured.
+    const [alice, bob] = await scenario.addPlayersWithApps([appSource, appSource]);
+
+    // Shortcut peer discovery through gossip and register all agents in every
+    // conductor of the scenario.
+    await scenario.shareAllAgents();
+
+    // Alice creates a Reflection
+    const reflectionRecord: Record = await createReflection(alice.cells[0]);
+
+    await pause(1200);
+
+    // Bob comments on it
+    await createCommentOnReflection(bob.cells[0], {
+      reflection_hash: reflectionRecord.signed_action.hashed.hash,
+      comment: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
+    });
+
+    await pause(1200);
+
+    let notifications: any[] = await alice.cells[0].callZome({
+      zome_name: "craving",
+      fn_name: "get_my_notifications",
+      payload: { since: null, include_resonance: false },
+    });
+    assert.equal(notifications.length, 1);
+    assert.deepEqual(notifications[0].kind, { CommentOnReflection: null });
+    assert.equal(notifications[0].read, false);
+
+    let unreadCount: number = await alice.cells[0].callZome({
+      zome_name: "craving",
+      fn_name: "get_unread_notifications_count",
+      payload: false,
+    });
+    assert.equal(unreadCount, 1);
+
+    // Alice marks the notification as read
+    await alice.cells[0].callZome({
+      zome_name: "craving",
+      fn_name: "mark_notifications_read",
+      payload: [notifications[0].notification_hash],
+    });
+
+    unreadCount = await alice.cells[0].callZome({
+      zome_name: "craving",
+      fn_name: "get_unread_notifications_count",
+      payload: false,
+    });
+    assert.equal(unreadCount, 0);
+  });
+});
+
+test('resonating with a Reflection notifies its author with the resonance link', { concurrency: 1 }, async t => {
+  await runScenario(async scenario => {
+    const testAppPath = process.cwd() + '/../workdir/word-condenser.happ';
+    const appSource = { appBundleSource: { path: testAppPath } };
+    const [alice, bob] = await scenario.addPlayersWithApps([appSource, appSource]);
+    await scenario.shareAllAgents();
+
+    // Alice creates a Reflection
+    const reflectionRecord: Record = await createReflection(alice.cells[0]);
+    const entryHash = (reflectionRecord.signed_action.hashed.content as NewEntryAction).entry_hash;
+
+    await pause(1200);
+
+    // Bob resonates with it
+    await bob.cells[0].callZome({
+      zome_name: "craving",
+      fn_name: "add_resonator_for_entry",
+      payload: entryHash,
+    });
+
+    await pause(1200);
+
+    // Resonance notifications are hidden unless asked for
+    let notifications: any[] = await alice.cells[0].callZome({
+      zome_name: "craving",
+      fn_name: "get_my_notifications",
+      payload: { since: null, include_resonance: false },
+    });
+    assert.equal(notifications.length, 0);
+
+    notifications = await alice.cells[0].callZome({
+      zome_name: "craving",
+      fn_name: "get_my_notifications",
+      payload: { since: null, include_resonance: true },
+    });
+    assert.equal(notifications.length, 1);
+    assert.ok(notifications[0].kind.Resonance.resonance_hash);
+  });
+});
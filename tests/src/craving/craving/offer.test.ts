@@ -201,3 +201,28 @@ test('create and delete Offer', { concurrency: 1 }, async t => {
     assert.equal(readDeletedOutput, undefined);
   });
 });
+
+test('creating a too long Offer fails with a TooLong error', { concurrency: 1 }, async t => {
+  await runScenario(async scenario => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + '/../workdir/word-condenser.happ';
+
+    // Set up the app to be installed 
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    const [alice] = await scenario.addPlayersWithApps([appSource]);
+
+    const tooLongOffer = await sampleOffer(alice.cells[0], { offer: "a".repeat(10000) });
+    await assert.rejects(
+      createOffer(alice.cells[0], tooLongOffer),
+      (e: any) => {
+        const message = JSON.stringify(e).replace(/\\/g, '');
+        assert.ok(message.includes('"code":"TooLong"'));
+        assert.ok(message.includes('"field":"offer"'));
+        assert.ok(message.includes('"actual":10000'));
+        return true;
+      },
+    );
+  });
+});
@@ -0,0 +1,63 @@
+import test from 'node:test';
+import assert from 'node:assert';
+
+import { runScenario, pause, CallableCell } from '@holochain/tryorama';
+import { NewEntryAction, ActionHash, Record, AppBundleSource, AgentPubKey } from '@holochain/client';
+import { decode } from '@msgpack/msgpack';
+
+import { createAssociation } from './association.test.js';
+
+test('participants register themselves and receive remote signals', { concurrency: 1 }, async t => {
+  await runScenario(async scenario => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + '/../workdir/word-condenser.happ';
+
+    // Set up the app to be installed 
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    // Add 2 players with the test app to the Scenario. The returned players
+    // can be destructured.
+    const [alice, bob] = await scenario.addPlayersWithApps([appSource, appSource]);
+
+    // Shortcut peer discovery through gossip and register all agents in every
+    // conductor of the scenario.
+    await scenario.shareAllAgents();
+
+    // Any zome call triggers init which registers the agent as a participant
+    await alice.cells[0].callZome({
+      zome_name: "craving",
+      fn_name: "get_all_participants",
+      payload: null
+    });
+    await bob.cells[0].callZome({
+      zome_name: "craving",
+      fn_name: "get_all_participants",
+      payload: null
+    });
+
+    await pause(1200);
+
+    const participants: AgentPubKey[] = await alice.cells[0].callZome({
+      zome_name: "craving",
+      fn_name: "get_all_participants",
+      payload: null
+    });
+    assert.equal(participants.length, 2);
+
+    let bobSignals: any[] = [];
+    bob.conductor.appWs().on("signal", signal => {
+      bobSignals.push(signal.payload);
+    });
+
+    // Alice creates an Association which should be signaled to Bob
+    await createAssociation(alice.cells[0]);
+
+    await pause(1200);
+
+    const remoteSignal = bobSignals.find(signal => signal.type === "Remote" && signal.signal.type === "EntryCreated");
+    assert.ok(remoteSignal);
+    assert.deepEqual(remoteSignal.provenance, alice.agentPubKey);
+    assert.equal(remoteSignal.signal.app_entry.type, "Association");
+  });
+});
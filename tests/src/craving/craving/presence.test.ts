@@ -0,0 +1,46 @@
+import test from 'node:test';
+import assert from 'node:assert';
+
+import { runScenario, pause } from '@holochain/tryorama';
+
+test('presence heartbeats reach the other participants', { concurrency: 1 }, async t => {
+  await runScenario(async scenario => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + '/../workdir/word-condenser.happ';
+
+    // Set up the app to be installed 
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    const [alice, bob] = await scenario.addPlayersWithApps([appSource, appSource]);
+    await scenario.shareAllAgents();
+
+    const aliceCraving = alice.namedCells.get("craving")!;
+    const bobCraving = bob.namedCells.get("craving")!;
+
+    // Any zome call triggers init which registers the agent as a participant
+    await aliceCraving.callZome({ zome_name: "craving", fn_name: "get_all_participants", payload: null });
+    await bobCraving.callZome({ zome_name: "craving", fn_name: "get_all_participants", payload: null });
+    await pause(1200);
+
+    let bobSignals: any[] = [];
+    bob.conductor.appWs().on("signal", signal => {
+      bobSignals.push(signal.payload);
+    });
+
+    const before = Date.now() * 1000;
+    await aliceCraving.callZome({ zome_name: "craving", fn_name: "ping_presence", payload: 10 });
+    await pause(1200);
+
+    const heartbeats = bobSignals.filter(signal => signal.type === "Remote" && signal.signal.type === "Presence");
+    assert.equal(heartbeats.length, 1);
+    assert.deepEqual(heartbeats[0].provenance, alice.agentPubKey);
+    // expires about 10 seconds after it was sent
+    const expiresAt = heartbeats[0].signal.expires_at;
+    assert.ok(expiresAt >= before + 9_000_000 && expiresAt <= Date.now() * 1000 + 10_000_000);
+
+    const onlineAgents: any[] = await aliceCraving.callZome({ zome_name: "craving", fn_name: "get_online_agents", payload: 10 });
+    assert.equal(onlineAgents.length, 2);
+    assert.ok(onlineAgents.some(agent => agent.toString() === bob.agentPubKey.toString()));
+  });
+});
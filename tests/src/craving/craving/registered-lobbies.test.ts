@@ -0,0 +1,67 @@
+import test from 'node:test';
+import assert from 'node:assert';
+
+import { runScenario, pause } from '@holochain/tryorama';
+import { decode } from '@msgpack/msgpack';
+
+test('registering a recipe in a lobby records the lobby in the craving', { concurrency: 1 }, async t => {
+  await runScenario(async scenario => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + '/../workdir/word-condenser.happ';
+
+    // Set up the app to be installed 
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    const [alice] = await scenario.addPlayersWithApps([appSource]);
+
+    const lobby = await alice.appAgentWs.createCloneCell({
+      role_name: "lobby",
+      modifiers: { network_seed: "registered lobby", properties: { name: "Registered lobby" } },
+      name: "Registered lobby",
+    });
+    const properties = {
+      title: "Registered craving",
+      description: "A craving that is listed in a lobby",
+      max_anecdote_chars: null,
+      max_association_chars: null,
+      max_offer_chars: null,
+      max_reflection_chars: null,
+    };
+    const craving = await alice.appAgentWs.createCloneCell({
+      role_name: "craving",
+      modifiers: { network_seed: "registered craving", properties, origin_time: 1674053334548000 },
+      name: "Registered craving",
+    });
+
+    await alice.appAgentWs.callZome({
+      cell_id: lobby.cell_id,
+      zome_name: "cravings",
+      fn_name: "create_dna_recipe",
+      payload: {
+        dna_recipe: {
+          title: "Registered craving",
+          network_seed: "registered craving",
+          properties,
+          origin_time: 1674053334548000,
+          membrane_proof: null,
+          resulting_dna_hash: craving.cell_id[0],
+        },
+      },
+    });
+
+    await pause(1200);
+
+    const lobbies: any[] = await alice.appAgentWs.callZome({
+      cell_id: craving.cell_id,
+      zome_name: "craving",
+      fn_name: "get_registered_lobbies",
+      payload: null,
+    });
+    assert.equal(lobbies.length, 1);
+    assert.deepEqual(lobbies[0].lobby_dna_hash, lobby.cell_id[0]);
+    assert.equal(lobbies[0].lobby_name, "Registered lobby");
+    assert.equal(lobbies[0].network_seed, "registered lobby");
+    assert.deepEqual(decode(lobbies[0].properties), { name: "Registered lobby" });
+  });
+});
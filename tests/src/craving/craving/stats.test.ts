@@ -0,0 +1,72 @@
+import test from 'node:test';
+import assert from 'node:assert';
+
+import { runScenario, pause } from '@holochain/tryorama';
+import { NewEntryAction, Record } from '@holochain/client';
+
+import { createAssociation } from './association.test.js';
+import { createOffer } from './offer.test.js';
+import { createReflection } from './reflection.test.js';
+import { createCommentOnReflection } from './comment-on-reflection.test.js';
+
+test('craving stats count contributors, activity, resonance and discussions', { concurrency: 1 }, async t => {
+  await runScenario(async scenario => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + '/../workdir/word-condenser.happ';
+
+    // Set up the app to be installed 
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    const [alice, bob] = await scenario.addPlayersWithApps([appSource, appSource]);
+    await scenario.shareAllAgents();
+
+    await createAssociation(alice.cells[0]);
+    await createOffer(alice.cells[0]);
+    const reflectionRecord: Record = await createReflection(alice.cells[0]);
+
+    await pause(1200);
+
+    await createCommentOnReflection(bob.cells[0], {
+      reflection_hash: reflectionRecord.signed_action.hashed.hash,
+      comment: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
+    });
+    await bob.cells[0].callZome({
+      zome_name: "craving",
+      fn_name: "add_resonator_for_entry",
+      payload: (reflectionRecord.signed_action.hashed.content as NewEntryAction).entry_hash,
+    });
+
+    await pause(1200);
+
+    const stats: any = await alice.cells[0].callZome({
+      zome_name: "craving",
+      fn_name: "get_craving_stats",
+      payload: null,
+    });
+    assert.equal(stats.distinct_contributors, 2);
+    const totals = stats.daily_activity.reduce(
+      (sum: any, day: any) => ({
+        associations: sum.associations + day.associations,
+        offers: sum.offers + day.offers,
+        reflections: sum.reflections + day.reflections,
+        comments: sum.comments + day.comments,
+      }),
+      { associations: 0, offers: 0, reflections: 0, comments: 0 },
+    );
+    assert.deepEqual(totals, { associations: 1, offers: 1, reflections: 1, comments: 1 });
+    assert.ok(stats.resonance_distribution.some((b: any) => b.resonators === 1 && b.entries === 1));
+    assert.equal(stats.most_discussed_reflections.length, 1);
+    assert.equal(stats.most_discussed_reflections[0].comments, 1);
+    assert.ok(stats.median_micros_to_first_offer >= 0);
+
+    // Nothing happened in a window that ended before everything
+    const emptyStats: any = await alice.cells[0].callZome({
+      zome_name: "craving",
+      fn_name: "get_craving_stats",
+      payload: { start: null, end: 0 },
+    });
+    assert.equal(emptyStats.distinct_contributors, 0);
+    assert.equal(emptyStats.daily_activity.length, 0);
+  });
+});
@@ -61,3 +61,117 @@ test('link a Anchor to a LobbyInfo', { concurrency: 1 }, async t => {
   });
 });
 
+
+test('only the anchored agent can link from their anchor', { concurrency: 1 }, async t => {
+  await runScenario(async scenario => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + '/../workdir/word-condenser.happ';
+
+    // Set up the app to be installed 
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    const [alice, bob] = await scenario.addPlayersWithApps([appSource, appSource]);
+    await scenario.shareAllAgents();
+
+    const targetRecord = await createLobbyInfo(alice.cells[0]);
+    await pause(1200);
+
+    await assert.rejects(
+      bob.cells[0].callZome({
+        zome_name: "cravings",
+        fn_name: "add_lobby_info_for_anchor",
+        payload: {
+          anchor: alice.agentPubKey,
+          lobby_info_hash: targetRecord.signed_action.hashed.hash,
+        },
+      }),
+      /NotAuthor/,
+    );
+
+    // There is only one canonical LobbyInfo
+    await assert.rejects(createLobbyInfo(bob.cells[0]), /AlreadyExists/);
+    await assert.rejects(createLobbyInfo(alice.cells[0]), /AlreadyExists/);
+  });
+});
+
+test('only the creator of a lobby can create its canonical LobbyInfo', { concurrency: 1 }, async t => {
+  await runScenario(async scenario => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + '/../workdir/word-condenser.happ';
+
+    // Set up the app to be installed 
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    const [alice, bob] = await scenario.addPlayersWithApps([appSource, appSource]);
+    await scenario.shareAllAgents();
+
+    const modifiers = {
+      network_seed: "created lobby",
+      properties: { name: "Created lobby", creator: alice.agentPubKey },
+    };
+    const aliceLobby = await alice.appAgentWs.createCloneCell({ role_name: "lobby", modifiers, name: "Created lobby" });
+    const bobLobby = await bob.appAgentWs.createCloneCell({ role_name: "lobby", modifiers, name: "Created lobby" });
+    const createLobbyInfoIn = (player, cell) => player.appAgentWs.callZome({
+      cell_id: cell.cell_id,
+      zome_name: "cravings",
+      fn_name: "create_lobby_info",
+      payload: {
+        description: "A lobby with a creator",
+        unenforced_rules: null,
+        logo_src: null,
+        network_seed: "created lobby",
+      },
+    });
+
+    await assert.rejects(createLobbyInfoIn(bob, bobLobby), /NotAuthor/);
+    assert.ok(await createLobbyInfoIn(alice, aliceLobby));
+  });
+});
+
+test('without a creator, the earliest competing LobbyInfo is canonical for everyone', { concurrency: 1 }, async t => {
+  await runScenario(async scenario => {
+    const testAppPath = process.cwd() + '/../workdir/word-condenser.happ';
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    const [alice, bob] = await scenario.addPlayersWithApps([appSource, appSource]);
+
+    const modifiers = { network_seed: "open lobby", properties: { name: "Open lobby" } };
+    const aliceLobby = await alice.appAgentWs.createCloneCell({ role_name: "lobby", modifiers, name: "Open lobby" });
+    const bobLobby = await bob.appAgentWs.createCloneCell({ role_name: "lobby", modifiers, name: "Open lobby" });
+    const call = (player, cell, fn_name, payload) => player.appAgentWs.callZome({
+      cell_id: cell.cell_id,
+      zome_name: "cravings",
+      fn_name,
+      payload,
+    });
+    const lobbyInfo = (description: string) => ({
+      description,
+      unenforced_rules: null,
+      logo_src: null,
+      network_seed: "open lobby",
+    });
+
+    // Both create a LobbyInfo before they can see each other's
+    const created = await Promise.allSettled([
+      call(alice, aliceLobby, "create_lobby_info", lobbyInfo("Alice's lobby")),
+      call(bob, bobLobby, "create_lobby_info", lobbyInfo("Bob's lobby")),
+    ]);
+    const records: Record[] = created
+      .filter((result): result is PromiseFulfilledResult<Record> => result.status === "fulfilled")
+      .map(result => result.value);
+    assert.ok(records.length >= 1);
+    await scenario.shareAllAgents();
+    await pause(3000);
+
+    const earliest = records.reduce((a, b) => {
+      const byTime = a.signed_action.hashed.content.timestamp - b.signed_action.hashed.content.timestamp;
+      return byTime <= 0 ? a : b;
+    });
+    const aliceSees: Record = await call(alice, aliceLobby, "get_lobby_info", null);
+    const bobSees: Record = await call(bob, bobLobby, "get_lobby_info", null);
+    assert.deepEqual(aliceSees.signed_action.hashed.hash, bobSees.signed_action.hashed.hash);
+    assert.deepEqual(aliceSees.signed_action.hashed.hash, earliest.signed_action.hashed.hash);
+  });
+});
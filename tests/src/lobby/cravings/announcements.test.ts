@@ -0,0 +1,79 @@
+import test from 'node:test';
+import assert from 'node:assert';
+
+import { runScenario, pause } from '@holochain/tryorama';
+import { Record } from '@holochain/client';
+
+test('post, edit and delete announcements', { concurrency: 1 }, async t => {
+  await runScenario(async scenario => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + '/../workdir/word-condenser.happ';
+
+    // Set up the app to be installed 
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    const [alice, bob] = await scenario.addPlayersWithApps([appSource, appSource]);
+    await scenario.shareAllAgents();
+
+    const aliceLobby = alice.namedCells.get("lobby")!;
+    const bobLobby = bob.namedCells.get("lobby")!;
+    const call = (cell, fn_name, payload) => cell.callZome({ zome_name: "cravings", fn_name, payload });
+
+    // Both register as members in init
+    await call(aliceLobby, "get_member_count", null);
+    await call(bobLobby, "get_member_count", null);
+    await pause(1200);
+
+    let bobSignals: any[] = [];
+    bob.conductor.appWs().on("signal", signal => {
+      bobSignals.push(signal.payload);
+    });
+
+    const announcement = {
+      title: "New craving",
+      body: "A craving about workplace feelings is up",
+      dna_recipe_hash: null,
+      expires_at: null,
+    };
+    const record: Record = await call(aliceLobby, "create_announcement", announcement);
+    const announcementHash = record.signed_action.hashed.hash;
+    await assert.rejects(call(aliceLobby, "create_announcement", { ...announcement, title: "" }), /TooShort/);
+    await assert.rejects(
+      call(aliceLobby, "create_announcement", { ...announcement, expires_at: 1000 }),
+      /NotAllowed/,
+    );
+
+    await pause(1200);
+
+    const remoteSignal = bobSignals.find(signal => signal.type === "Remote" && signal.signal.type === "AnnouncementPosted");
+    assert.ok(remoteSignal);
+    assert.deepEqual(remoteSignal.provenance, alice.agentPubKey);
+
+    // Only the author can edit
+    await assert.rejects(
+      call(bobLobby, "update_announcement", {
+        original_announcement_hash: announcementHash,
+        previous_announcement_hash: announcementHash,
+        updated_announcement: { ...announcement, body: "Bob was here" },
+      }),
+      /NotAuthor/,
+    );
+    await call(aliceLobby, "update_announcement", {
+      original_announcement_hash: announcementHash,
+      previous_announcement_hash: announcementHash,
+      updated_announcement: { ...announcement, body: "Join us!" },
+    });
+    await pause(1200);
+
+    let announcements: Record[] = await call(bobLobby, "get_all_announcements", null);
+    assert.equal(announcements.length, 1);
+
+    await assert.rejects(call(bobLobby, "delete_announcement", announcementHash));
+    await call(aliceLobby, "delete_announcement", announcementHash);
+    await pause(1200);
+
+    announcements = await call(bobLobby, "get_all_announcements", null);
+    assert.equal(announcements.length, 0);
+  });
+});
@@ -0,0 +1,153 @@
+import test from 'node:test';
+import assert from 'node:assert';
+
+import { runScenario, pause } from '@holochain/tryorama';
+import { Record } from '@holochain/client';
+import { decode } from '@msgpack/msgpack';
+
+test('cravings publish previews to the lobbies they are listed in', { concurrency: 1 }, async t => {
+  await runScenario(async scenario => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + '/../workdir/word-condenser.happ';
+
+    // Set up the app to be installed 
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    const [alice] = await scenario.addPlayersWithApps([appSource]);
+
+    const lobby = await alice.appAgentWs.createCloneCell({
+      role_name: "lobby",
+      modifiers: { network_seed: "preview lobby", properties: { name: "Preview lobby" } },
+      name: "Preview lobby",
+    });
+    const properties = {
+      title: "Workplace feelings",
+      description: "The feeling of being the only one who refills the coffee machine",
+      max_anecdote_chars: null,
+      max_association_chars: null,
+      max_offer_chars: null,
+      max_reflection_chars: null,
+    };
+    const craving = await alice.appAgentWs.createCloneCell({
+      role_name: "craving",
+      modifiers: { network_seed: "preview craving", properties, origin_time: 1674053334548000 },
+      name: "Workplace feelings",
+    });
+    const callLobby = (fn_name, payload) => alice.appAgentWs.callZome({ cell_id: lobby.cell_id, zome_name: "cravings", fn_name, payload });
+    const callCraving = (fn_name, payload) => alice.appAgentWs.callZome({ cell_id: craving.cell_id, zome_name: "craving", fn_name, payload });
+
+    const coffee: Record = await callCraving("create_association", { association: "coffee" });
+    await callCraving("create_association", { association: "chores" });
+    await callCraving("add_resonator_for_entry", (coffee.signed_action.hashed.content as any).entry_hash);
+
+    const dnaRecipe: Record = await callLobby("create_dna_recipe", {
+      dna_recipe: {
+        title: properties.title,
+        network_seed: "preview craving",
+        properties,
+        origin_time: 1674053334548000,
+        membrane_proof: null,
+        resulting_dna_hash: craving.cell_id[0],
+      },
+    });
+    const dnaRecipeHash = dnaRecipe.signed_action.hashed.hash;
+    await pause(1200);
+
+    let recipes: any[] = await callLobby("get_all_craving_recipes_with_previews", null);
+    assert.equal(recipes.length, 1);
+    assert.equal(recipes[0].preview, null);
+
+    await callCraving("publish_craving_preview_now", null);
+    await pause(1200);
+
+    recipes = await callLobby("get_all_craving_recipes_with_previews", null);
+    const preview: any = decode((recipes[0].preview.entry as any).Present.entry);
+    assert.deepEqual(preview.dna_recipe_hash, dnaRecipeHash);
+    assert.equal(preview.association_count, 2);
+    assert.equal(preview.offer_count, 0);
+    assert.equal(preview.participant_count, 1);
+    assert.deepEqual(preview.top_associations[0], { association: "coffee", resonators: 1 });
+    assert.ok(preview.last_activity);
+
+    // An unchanged preview is not published again
+    await callCraving("publish_craving_preview_now", null);
+    await pause(1200);
+    const latest: Record = await callLobby("get_craving_preview", dnaRecipeHash);
+    assert.deepEqual(latest.signed_action.hashed.hash, recipes[0].preview.signed_action.hashed.hash);
+
+    // Previews need to be about a listed craving
+    await assert.rejects(
+      callLobby("publish_craving_preview", {
+        craving_dna_hash: lobby.cell_id[0],
+        top_associations: [],
+        association_count: 0,
+        offer_count: 0,
+        reflection_count: 0,
+        anecdote_count: 0,
+        participant_count: 0,
+        last_activity: null,
+      }),
+      /NotFound/,
+    );
+  });
+});
+
+test('previews by members who joined the craving win over newer ones', { concurrency: 1 }, async t => {
+  await runScenario(async scenario => {
+    const testAppPath = process.cwd() + '/../workdir/word-condenser.happ';
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    const [alice, bob] = await scenario.addPlayersWithApps([appSource, appSource]);
+    await scenario.shareAllAgents();
+
+    const aliceLobby = alice.namedCells.get("lobby")!;
+    const bobLobby = bob.namedCells.get("lobby")!;
+    const properties = {
+      title: "Workplace feelings",
+      description: "The feeling of being the only one who refills the coffee machine",
+      max_anecdote_chars: null,
+      max_association_chars: null,
+      max_offer_chars: null,
+      max_reflection_chars: null,
+    };
+    const craving = await alice.appAgentWs.createCloneCell({
+      role_name: "craving",
+      modifiers: { network_seed: "spoofed craving", properties, origin_time: 1674053334548000 },
+      name: "Workplace feelings",
+    });
+    const dnaRecipe: Record = await aliceLobby.callZome({ zome_name: "cravings", fn_name: "create_dna_recipe", payload: {
+      dna_recipe: {
+        title: properties.title,
+        network_seed: "spoofed craving",
+        properties,
+        origin_time: 1674053334548000,
+        membrane_proof: null,
+        resulting_dna_hash: craving.cell_id[0],
+      },
+    }});
+    const dnaRecipeHash = dnaRecipe.signed_action.hashed.hash;
+    await aliceLobby.callZome({ zome_name: "cravings", fn_name: "join_craving", payload: craving.cell_id[0] });
+    await alice.appAgentWs.callZome({ cell_id: craving.cell_id, zome_name: "craving", fn_name: "create_association", payload: { association: "coffee" } });
+    await alice.appAgentWs.callZome({ cell_id: craving.cell_id, zome_name: "craving", fn_name: "publish_craving_preview_now", payload: null });
+    await pause(1200);
+
+    // Bob never joined the craving but publishes a made up preview afterwards
+    await bobLobby.callZome({ zome_name: "cravings", fn_name: "publish_craving_preview", payload: {
+      craving_dna_hash: craving.cell_id[0],
+      top_associations: [{ association: "tea", resonators: 1000 }],
+      association_count: 1000,
+      offer_count: 0,
+      reflection_count: 0,
+      anecdote_count: 0,
+      participant_count: 1000,
+      last_activity: null,
+    }});
+    await pause(1200);
+
+    const latest: Record = await bobLobby.callZome({ zome_name: "cravings", fn_name: "get_craving_preview", payload: dnaRecipeHash });
+    assert.deepEqual((latest.signed_action.hashed.content as any).author, alice.agentPubKey);
+    const preview: any = decode((latest.entry as any).Present.entry);
+    assert.equal(preview.association_count, 1);
+  });
+});
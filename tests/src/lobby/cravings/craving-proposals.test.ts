@@ -0,0 +1,83 @@
+import test from 'node:test';
+import assert from 'node:assert';
+
+import { runScenario, pause } from '@holochain/tryorama';
+import { Record, fakeDnaHash } from '@holochain/client';
+
+test('promote a CravingProposal once enough members resonate', { concurrency: 1 }, async t => {
+  await runScenario(async scenario => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + '/../workdir/word-condenser.happ';
+
+    // Set up the app to be installed 
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    const [alice, bob, carol] = await scenario.addPlayersWithApps([appSource, appSource, appSource]);
+    await scenario.shareAllAgents();
+
+    const aliceLobby = alice.namedCells.get("lobby")!;
+    const bobLobby = bob.namedCells.get("lobby")!;
+    const carolLobby = carol.namedCells.get("lobby")!;
+    const call = (cell, fn_name, payload) => cell.callZome({ zome_name: "cravings", fn_name, payload });
+
+    const proposal = {
+      title: "Lorem ipsum",
+      description: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
+      max_anecdote_chars: null,
+      max_association_chars: 70,
+      max_offer_chars: 300,
+      max_reflection_chars: null,
+    };
+    await assert.rejects(call(aliceLobby, "create_craving_proposal", { ...proposal, max_offer_chars: 0 }), /OutOfRange/);
+    const record: Record = await call(aliceLobby, "create_craving_proposal", proposal);
+    const cravingProposalHash = record.signed_action.hashed.hash;
+
+    const dnaRecipe = {
+      title: proposal.title,
+      network_seed: "Lorem ipsum",
+      properties: { ...proposal },
+      origin_time: 1674053334548000,
+      membrane_proof: null,
+      resulting_dna_hash: await fakeDnaHash(),
+    };
+    const promote = (cell, partialDnaRecipe = {}) => call(cell, "promote_craving_proposal", {
+      craving_proposal_hash: cravingProposalHash,
+      dna_recipe: { ...dnaRecipe, ...partialDnaRecipe },
+      tags: [],
+    });
+
+    await pause(1200);
+    await call(aliceLobby, "resonate_with_craving_proposal", cravingProposalHash);
+    await call(bobLobby, "resonate_with_craving_proposal", cravingProposalHash);
+    await pause(1200);
+
+    // Two resonators are not enough with the default threshold of 3
+    await assert.rejects(promote(aliceLobby), /NotAllowed/);
+
+    await call(carolLobby, "resonate_with_craving_proposal", cravingProposalHash);
+    await pause(1200);
+    let proposals = await call(bobLobby, "get_all_craving_proposals", null);
+    assert.equal(proposals.length, 1);
+    assert.equal(proposals[0].resonators.length, 3);
+    assert.equal(proposals[0].dna_recipe_hash, null);
+
+    // The recipe needs to match the proposal
+    await assert.rejects(
+      promote(bobLobby, { properties: { ...proposal, max_offer_chars: 500 } }),
+      /matching the CravingProposal/,
+    );
+    const dnaRecipeRecord: Record = await promote(bobLobby);
+    await pause(1200);
+
+    proposals = await call(aliceLobby, "get_all_craving_proposals", null);
+    assert.deepEqual(proposals[0].dna_recipe_hash, dnaRecipeRecord.signed_action.hashed.hash);
+    await assert.rejects(promote(carolLobby, { resulting_dna_hash: await fakeDnaHash() }), /AlreadyExists/);
+
+    // Resonance can be withdrawn
+    await call(carolLobby, "unresonate_with_craving_proposal", cravingProposalHash);
+    await pause(1200);
+    const resonators = await call(aliceLobby, "get_craving_proposal_resonators", cravingProposalHash);
+    assert.equal(resonators.length, 2);
+  });
+});
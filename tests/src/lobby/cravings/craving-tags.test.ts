@@ -0,0 +1,87 @@
+import test from 'node:test';
+import assert from 'node:assert';
+
+import { runScenario, pause, CallableCell } from '@holochain/tryorama';
+import { Record, fakeDnaHash } from '@holochain/client';
+
+import { createDnaRecipe } from './dna-recipe.test.js';
+
+async function recipe(title: string, description: string) {
+  return {
+    title,
+    network_seed: null,
+    properties: {
+      title,
+      description,
+      max_anecdote_chars: null,
+      max_association_chars: null,
+      max_offer_chars: null,
+      max_reflection_chars: null,
+    },
+    origin_time: null,
+    membrane_proof: null,
+    resulting_dna_hash: await fakeDnaHash(),
+  };
+}
+
+test('tag, search and paginate craving recipes', { concurrency: 1 }, async t => {
+  await runScenario(async scenario => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + '/../workdir/word-condenser.happ';
+
+    // Set up the app to be installed 
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    const [alice, bob] = await scenario.addPlayersWithApps([appSource, appSource]);
+    await scenario.shareAllAgents();
+
+    const cooking: Record = await createDnaRecipe(
+      alice.cells[0],
+      await recipe("Leftover soup", "The soup you make from whatever is left in the fridge"),
+      ["Food", " cooking  tips "],
+    );
+    await createDnaRecipe(
+      alice.cells[0],
+      await recipe("Monday feeling", "That feeling on a monday morning"),
+      ["feelings"],
+    );
+    await createDnaRecipe(
+      alice.cells[0],
+      await recipe("Soup spill", "Spilling soup on a white shirt"),
+      ["food"],
+    );
+
+    await pause(1200);
+
+    const tags: string[] = await bob.cells[0].callZome({
+      zome_name: "cravings",
+      fn_name: "get_all_craving_tags",
+      payload: null,
+    });
+    assert.deepEqual(tags, ["cooking tips", "feelings", "food"]);
+
+    const food = await bob.cells[0].callZome({
+      zome_name: "cravings",
+      fn_name: "get_craving_recipes_by_tag",
+      payload: { tag: "FOOD", page: { sort: "Oldest", limit: 1 } },
+    });
+    assert.equal(food.total, 2);
+    assert.equal(food.records.length, 1);
+    assert.deepEqual(food.records[0].signed_action.hashed.hash, cooking.signed_action.hashed.hash);
+
+    const soup = await bob.cells[0].callZome({
+      zome_name: "cravings",
+      fn_name: "search_craving_recipes",
+      payload: { query: "soup", tag: null, page: {} },
+    });
+    assert.equal(soup.total, 2);
+
+    const fridgeSoup = await bob.cells[0].callZome({
+      zome_name: "cravings",
+      fn_name: "search_craving_recipes",
+      payload: { query: "FRIDGE soup", tag: "food", page: {} },
+    });
+    assert.equal(fridgeSoup.total, 1);
+  });
+});
@@ -2,28 +2,36 @@ import test from 'node:test';
 import assert from 'node:assert';
 
 import { runScenario, pause, CallableCell } from '@holochain/tryorama';
-import { NewEntryAction, ActionHash, Record, AppBundleSource } from '@holochain/client';
+import { NewEntryAction, ActionHash, Record, AppBundleSource, fakeDnaHash } from '@holochain/client';
 import { decode } from '@msgpack/msgpack';
 
 
 async function sampleDnaRecipe(cell: CallableCell, partialDnaRecipe = {}) {
     return {
         ...{
+	  title: "Lorem ipsum",
 	  network_seed: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
-	  properties: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
+	  properties: {
+	    title: "Lorem ipsum",
+	    description: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
+	    max_anecdote_chars: null,
+	    max_association_chars: 70,
+	    max_offer_chars: 300,
+	    max_reflection_chars: null,
+	  },
 	  origin_time: 1674053334548000,
-	  clone_name: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
-	  membrane_proof: 0.5,
+	  membrane_proof: null,
+	  resulting_dna_hash: await fakeDnaHash(),
         },
         ...partialDnaRecipe
     };
 }
 
-export async function createDnaRecipe(cell: CallableCell, dnaRecipe = undefined): Promise<Record> {
+export async function createDnaRecipe(cell: CallableCell, dnaRecipe = undefined, tags: string[] = []): Promise<Record> {
     return cell.callZome({
       zome_name: "cravings",
       fn_name: "create_dna_recipe",
-      payload: dnaRecipe || await sampleDnaRecipe(cell),
+      payload: { dna_recipe: dnaRecipe || await sampleDnaRecipe(cell), tags },
     });
 }
 
@@ -87,3 +95,54 @@ test('create and read DnaRecipe', { concurrency: 1 }, async t => {
 });
 
 
+
+test('DnaRecipe with an empty title or an absurd limit is rejected', { concurrency: 1 }, async t => {
+  await runScenario(async scenario => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + '/../workdir/word-condenser.happ';
+
+    // Set up the app to be installed 
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    const [alice] = await scenario.addPlayersWithApps([appSource]);
+
+    const emptyTitle = await sampleDnaRecipe(alice.cells[0], { title: " " });
+    await assert.rejects(createDnaRecipe(alice.cells[0], emptyTitle), /TooShort/);
+
+    const recipe = await sampleDnaRecipe(alice.cells[0]);
+    const absurdLimit = { ...recipe, properties: { ...recipe.properties, max_offer_chars: 0 } };
+    await assert.rejects(createDnaRecipe(alice.cells[0], absurdLimit), /OutOfRange/);
+  });
+});
+
+test('only one DnaRecipe per resulting dna hash', { concurrency: 1 }, async t => {
+  await runScenario(async scenario => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + '/../workdir/word-condenser.happ';
+
+    // Set up the app to be installed 
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    const [alice, bob] = await scenario.addPlayersWithApps([appSource, appSource]);
+    await scenario.shareAllAgents();
+
+    const recipe = await sampleDnaRecipe(alice.cells[0]);
+    const record: Record = await createDnaRecipe(alice.cells[0], recipe);
+    assert.ok(record);
+
+    await pause(1200);
+
+    // Bob tries to register another recipe for the same dna hash
+    const sameDnaHash = { ...recipe, title: "Another title" };
+    await assert.rejects(createDnaRecipe(bob.cells[0], sameDnaHash), /AlreadyExists/);
+
+    const canonical: Record = await bob.cells[0].callZome({
+      zome_name: "cravings",
+      fn_name: "get_dna_recipe_for_dna_hash",
+      payload: recipe.resulting_dna_hash,
+    });
+    assert.deepEqual(canonical.signed_action.hashed.hash, record.signed_action.hashed.hash);
+  });
+});
@@ -0,0 +1,103 @@
+import test from 'node:test';
+import assert from 'node:assert';
+
+import { runScenario, pause } from '@holochain/tryorama';
+import { Record } from '@holochain/client';
+import { decode } from '@msgpack/msgpack';
+
+test('publish an offer of a craving to the lobby glossary', { concurrency: 1 }, async t => {
+  await runScenario(async scenario => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + '/../workdir/word-condenser.happ';
+
+    // Set up the app to be installed 
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    const [alice] = await scenario.addPlayersWithApps([appSource]);
+
+    const lobby = await alice.appAgentWs.createCloneCell({
+      role_name: "lobby",
+      modifiers: { network_seed: "glossary lobby", properties: { name: "Glossary lobby" } },
+      name: "Glossary lobby",
+    });
+    const properties = {
+      title: "Workplace feelings",
+      description: "The feeling of being the only one who refills the coffee machine",
+      max_anecdote_chars: null,
+      max_association_chars: null,
+      max_offer_chars: null,
+      max_reflection_chars: null,
+    };
+    const craving = await alice.appAgentWs.createCloneCell({
+      role_name: "craving",
+      modifiers: { network_seed: "glossary craving", properties, origin_time: 1674053334548000 },
+      name: "Workplace feelings",
+    });
+    const callLobby = (fn_name, payload) => alice.appAgentWs.callZome({ cell_id: lobby.cell_id, zome_name: "cravings", fn_name, payload });
+    const callCraving = (fn_name, payload) => alice.appAgentWs.callZome({ cell_id: craving.cell_id, zome_name: "craving", fn_name, payload });
+
+    const offer: Record = await callCraving("create_offer", { offer: "Brewburden", explanation: null });
+    const offerHash = offer.signed_action.hashed.hash;
+    const publish = () => callCraving("publish_offer_to_glossary", {
+      offer_hash: offerHash,
+      definition: "The weight of keeping the office caffeinated",
+      lobby_dna_hash: lobby.cell_id[0],
+    });
+
+    // The craving is not listed in the lobby yet
+    await assert.rejects(publish(), /NotFound/);
+
+    const dnaRecipe: Record = await callLobby("create_dna_recipe", {
+      dna_recipe: {
+        title: properties.title,
+        network_seed: "glossary craving",
+        properties,
+        origin_time: 1674053334548000,
+        membrane_proof: null,
+        resulting_dna_hash: craving.cell_id[0],
+      },
+    });
+    await pause(1200);
+
+    const glossaryEntryRecord: Record = await publish();
+    const glossaryEntry: any = decode((glossaryEntryRecord.entry as any).Present.entry);
+    assert.equal(glossaryEntry.word, "Brewburden");
+    assert.deepEqual(glossaryEntry.dna_recipe_hash, dnaRecipe.signed_action.hashed.hash);
+    assert.deepEqual(glossaryEntry.offer_hash, offerHash);
+    await pause(1200);
+    await assert.rejects(publish(), /AlreadyExists/);
+
+    // Without an explicit lobby, the lobby the craving was registered in is used
+    const secondOffer: Record = await callCraving("create_offer", { offer: "Mugmartyr", explanation: null });
+    const secondEntryRecord: Record = await callCraving("publish_offer_to_glossary", {
+      offer_hash: secondOffer.signed_action.hashed.hash,
+      definition: "Whoever ends up washing all the mugs",
+    });
+    await pause(1200);
+    await callLobby("delete_glossary_entry", secondEntryRecord.signed_action.hashed.hash);
+    await pause(1200);
+
+    // Calling the lobby directly, the word is still read from the craving
+    await assert.rejects(callLobby("publish_glossary_entry", {
+      definition: "Not an offer at all",
+      craving_dna_hash: craving.cell_id[0],
+      offer_hash: dnaRecipe.signed_action.hashed.hash,
+    }), /NotFound/);
+
+    const glossary: Record[] = await callLobby("get_glossary", null);
+    assert.equal(glossary.length, 1);
+    assert.equal((await callLobby("search_glossary", "BREW") as Record[]).length, 1);
+    assert.equal((await callLobby("search_glossary", "caffeinated") as Record[]).length, 1);
+    assert.equal((await callLobby("search_glossary", "tea") as Record[]).length, 0);
+
+    const exported: any[] = await callLobby("export_glossary", null);
+    assert.equal(exported.length, 1);
+    assert.equal(exported[0].craving_title, "Workplace feelings");
+    assert.deepEqual(exported[0].craving_dna_hash, craving.cell_id[0]);
+
+    await callLobby("delete_glossary_entry", glossaryEntryRecord.signed_action.hashed.hash);
+    await pause(1200);
+    assert.equal((await callLobby("get_glossary", null) as Record[]).length, 0);
+  });
+});
@@ -0,0 +1,79 @@
+import test from 'node:test';
+import assert from 'node:assert';
+
+import { runScenario, pause } from '@holochain/tryorama';
+import { CellId } from '@holochain/client';
+import { encode } from '@msgpack/msgpack';
+
+test('invitation-only lobbies require a chain of invitations', { concurrency: 1 }, async t => {
+  await runScenario(async scenario => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + '/../workdir/word-condenser.happ';
+
+    // Set up the app to be installed 
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    const [alice, bob, carol] = await scenario.addPlayersWithApps([appSource, appSource, appSource]);
+    await scenario.shareAllAgents();
+
+    const properties = {
+      name: "Secret lobby",
+      creator: alice.agentPubKey,
+      invitation_only: true,
+    };
+    const cloneLobby = (player, membraneProof?) => player.appAgentWs.createCloneCell({
+      role_name: "lobby",
+      modifiers: { network_seed: "secret", properties },
+      membrane_proof: membraneProof ? encode(membraneProof) : undefined,
+      name: "Secret lobby",
+    });
+
+    // The creator joins without an invitation
+    const aliceLobby = await cloneLobby(alice);
+    const aliceCellId: CellId = aliceLobby.cell_id;
+
+    // Carol has no invitation and cannot join
+    await assert.rejects(cloneLobby(carol));
+
+    const bobProof = await alice.appAgentWs.callZome({
+      cell_id: aliceCellId,
+      zome_name: "cravings",
+      fn_name: "create_invitation",
+      payload: bob.agentPubKey,
+    });
+    assert.equal(bobProof.chain.length, 1);
+
+    // Carol cannot use Bob's invitation
+    await assert.rejects(cloneLobby(carol, bobProof), /InvalidMembraneProof/);
+
+    const bobLobby = await cloneLobby(bob, bobProof);
+    assert.deepEqual(bobLobby.cell_id[0], aliceCellId[0]);
+
+    await pause(1200);
+
+    // Bob as a member can invite Carol in turn
+    const carolProof = await bob.appAgentWs.callZome({
+      cell_id: bobLobby.cell_id,
+      zome_name: "cravings",
+      fn_name: "create_invitation",
+      payload: carol.agentPubKey,
+    });
+    assert.equal(carolProof.chain.length, 2);
+    const carolLobby = await cloneLobby(carol, carolProof);
+    assert.ok(carolLobby);
+  });
+});
+
+test('the plain hApp installs with a lobby without properties', { concurrency: 1 }, async t => {
+  await runScenario(async scenario => {
+    const testAppPath = process.cwd() + '/../workdir/word-condenser.happ';
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    const [alice] = await scenario.addPlayersWithApps([appSource]);
+
+    const lobby = alice.namedCells.get("lobby")!;
+    const glossary: any[] = await lobby.callZome({ zome_name: "cravings", fn_name: "get_glossary", payload: null });
+    assert.equal(glossary.length, 0);
+  });
+});
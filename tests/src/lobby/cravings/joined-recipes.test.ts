@@ -0,0 +1,72 @@
+import test from 'node:test';
+import assert from 'node:assert';
+
+import { runScenario, pause } from '@holochain/tryorama';
+import { Record, fakeDnaHash } from '@holochain/client';
+
+import { createDnaRecipe } from './dna-recipe.test.js';
+
+test('count the members that joined a craving', { concurrency: 1 }, async t => {
+  await runScenario(async scenario => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + '/../workdir/word-condenser.happ';
+
+    // Set up the app to be installed 
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    const [alice, bob] = await scenario.addPlayersWithApps([appSource, appSource]);
+    await scenario.shareAllAgents();
+
+    const aliceLobby = alice.namedCells.get("lobby")!;
+    const bobLobby = bob.namedCells.get("lobby")!;
+    const call = (cell, fn_name, payload) => cell.callZome({ zome_name: "cravings", fn_name, payload });
+
+    const recipeFor = async (title: string) => ({
+      title,
+      network_seed: title,
+      properties: {
+        title,
+        description: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
+        max_anecdote_chars: null,
+        max_association_chars: null,
+        max_offer_chars: null,
+        max_reflection_chars: null,
+      },
+      origin_time: 1674053334548000,
+      membrane_proof: null,
+      resulting_dna_hash: await fakeDnaHash(),
+    });
+    const quiet = await recipeFor("Quiet craving");
+    const popular = await recipeFor("Popular craving");
+    const quietRecord: Record = await createDnaRecipe(aliceLobby, quiet);
+    const popularRecord: Record = await createDnaRecipe(aliceLobby, popular);
+    const popularHash = popularRecord.signed_action.hashed.hash;
+    await pause(1200);
+
+    // Listing a craving joins it
+    assert.equal(await call(bobLobby, "get_recipe_join_count", popularHash), 1);
+
+    await call(bobLobby, "join_craving", popular.resulting_dna_hash);
+    await call(bobLobby, "join_craving", popular.resulting_dna_hash);
+    await assert.rejects(call(bobLobby, "join_craving", await fakeDnaHash()), /NotFound/);
+    await pause(1200);
+
+    assert.equal(await call(aliceLobby, "get_recipe_join_count", popularHash), 2);
+    const joiners = await call(aliceLobby, "get_recipe_joiners", popularHash);
+    assert.ok(joiners.some(agent => agent.toString() === bob.agentPubKey.toString()));
+    const bobsRecipes: Record[] = await call(bobLobby, "get_my_joined_recipes", null);
+    assert.deepEqual(bobsRecipes.map(record => record.signed_action.hashed.hash), [popularHash]);
+
+    const ranked = await call(aliceLobby, "search_craving_recipes", { query: "", page: { sort: "MostJoined" } });
+    assert.deepEqual(
+      ranked.records.map(record => record.signed_action.hashed.hash),
+      [popularHash, quietRecord.signed_action.hashed.hash],
+    );
+
+    await call(bobLobby, "leave_craving", popular.resulting_dna_hash);
+    await pause(1200);
+    assert.equal(await call(aliceLobby, "get_recipe_join_count", popularHash), 1);
+    assert.equal((await call(bobLobby, "get_my_joined_recipes", null)).length, 0);
+  });
+});
@@ -0,0 +1,77 @@
+import test from 'node:test';
+import assert from 'node:assert';
+
+import { runScenario, pause } from '@holochain/tryorama';
+
+test('only admins can change the LobbyInfo of lobbies in admin mode', { concurrency: 1 }, async t => {
+  await runScenario(async scenario => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + '/../workdir/word-condenser.happ';
+
+    // Set up the app to be installed 
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    const [alice, bob] = await scenario.addPlayersWithApps([appSource, appSource]);
+    await scenario.shareAllAgents();
+
+    const modifiers = {
+      network_seed: "admin lobby",
+      properties: { name: "Admin lobby", creator: alice.agentPubKey, admin_mode: true },
+    };
+    const aliceLobby = await alice.appAgentWs.createCloneCell({ role_name: "lobby", modifiers, name: "Admin lobby" });
+    const bobLobby = await bob.appAgentWs.createCloneCell({ role_name: "lobby", modifiers, name: "Admin lobby" });
+    const call = (player, cell, fn_name, payload) => player.appAgentWs.callZome({
+      cell_id: cell.cell_id,
+      zome_name: "cravings",
+      fn_name,
+      payload,
+    });
+
+    const lobbyInfo = {
+      description: "A lobby with admins",
+      unenforced_rules: null,
+      logo_src: null,
+      network_seed: "admin lobby",
+    };
+    const original = await call(alice, aliceLobby, "create_lobby_info", lobbyInfo);
+    await pause(1200);
+
+    const update = (player, cell) => call(player, cell, "update_lobby_info", {
+      original_lobby_info_hash: original.signed_action.hashed.hash,
+      previous_lobby_info_hash: original.signed_action.hashed.hash,
+      updated_lobby_info: { ...lobbyInfo, description: "Changed" },
+    });
+
+    // Bob is not an admin yet
+    await assert.rejects(update(bob, bobLobby), /NotAuthor/);
+
+    await call(alice, aliceLobby, "grant_lobby_admin", bob.agentPubKey);
+    await pause(1200);
+
+    let admins = await call(bob, bobLobby, "get_lobby_admins", null);
+    assert.equal(admins.length, 2);
+    assert.ok(await update(bob, bobLobby));
+
+    // The creator has no role to step down from, but bob can step down
+    await assert.rejects(call(alice, aliceLobby, "step_down_as_lobby_admin", null), /NotFound/);
+    await call(bob, bobLobby, "step_down_as_lobby_admin", null);
+    await pause(1200);
+
+    admins = await call(bob, bobLobby, "get_lobby_admins", null);
+    assert.deepEqual(admins, [alice.agentPubKey]);
+    await assert.rejects(update(bob, bobLobby), /NotAuthor/);
+
+    // The creator can revoke the roles they granted and holds no role that could be revoked
+    await call(alice, aliceLobby, "grant_lobby_admin", bob.agentPubKey);
+    await pause(1200);
+    assert.equal((await call(bob, bobLobby, "get_lobby_admins", null)).length, 2);
+    await assert.rejects(call(bob, bobLobby, "revoke_lobby_admin", alice.agentPubKey), /NotFound/);
+    await call(alice, aliceLobby, "revoke_lobby_admin", bob.agentPubKey);
+    await pause(1200);
+
+    admins = await call(bob, bobLobby, "get_lobby_admins", null);
+    assert.deepEqual(admins, [alice.agentPubKey]);
+    await assert.rejects(update(bob, bobLobby), /NotAuthor/);
+  });
+});
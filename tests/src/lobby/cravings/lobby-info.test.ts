@@ -160,3 +160,63 @@ test('create and update LobbyInfo', { concurrency: 1 }, async t => {
   });
 });
 
+
+test('LobbyInfo history and outdated updates', { concurrency: 1 }, async t => {
+  await runScenario(async scenario => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + '/../workdir/word-condenser.happ';
+
+    // Set up the app to be installed 
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    const [alice, bob] = await scenario.addPlayersWithApps([appSource, appSource]);
+    await scenario.shareAllAgents();
+
+    const record: Record = await createLobbyInfo(alice.cells[0]);
+    const originalActionHash = record.signed_action.hashed.hash;
+
+    const updatedRecord: Record = await alice.cells[0].callZome({
+      zome_name: "cravings",
+      fn_name: "update_lobby_info",
+      payload: {
+        original_lobby_info_hash: originalActionHash,
+        previous_lobby_info_hash: originalActionHash,
+        updated_lobby_info: await sampleLobbyInfo(alice.cells[0], { description: "Alice's edit" }),
+      },
+    });
+
+    await pause(1200);
+
+    // Bob edits based on the original without having seen Alice's edit
+    await assert.rejects(
+      bob.cells[0].callZome({
+        zome_name: "cravings",
+        fn_name: "update_lobby_info",
+        payload: {
+          original_lobby_info_hash: originalActionHash,
+          previous_lobby_info_hash: originalActionHash,
+          updated_lobby_info: await sampleLobbyInfo(bob.cells[0], { description: "Bob's edit" }),
+        },
+      }),
+      /Outdated/,
+    );
+
+    const history: any[] = await bob.cells[0].callZome({
+      zome_name: "cravings",
+      fn_name: "get_lobby_info_history",
+      payload: null,
+    });
+    assert.equal(history.length, 2);
+    assert.equal(history[0].previous_lobby_info_hash, null);
+    assert.deepEqual(history[1].previous_lobby_info_hash, originalActionHash);
+    assert.deepEqual(history[1].record.signed_action.hashed.hash, updatedRecord.signed_action.hashed.hash);
+
+    const conflicts: any[] = await bob.cells[0].callZome({
+      zome_name: "cravings",
+      fn_name: "get_lobby_info_conflicts",
+      payload: null,
+    });
+    assert.equal(conflicts.length, 0);
+  });
+});
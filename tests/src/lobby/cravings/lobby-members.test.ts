@@ -0,0 +1,41 @@
+import test from 'node:test';
+import assert from 'node:assert';
+
+import { runScenario, pause } from '@holochain/tryorama';
+
+test('agents register themselves in the lobby member directory', { concurrency: 1 }, async t => {
+  await runScenario(async scenario => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + '/../workdir/word-condenser.happ';
+
+    // Set up the app to be installed 
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    const [alice, bob] = await scenario.addPlayersWithApps([appSource, appSource]);
+    await scenario.shareAllAgents();
+
+    // The first zome call runs init, which registers the agent
+    await alice.namedCells.get("lobby")!.callZome({ zome_name: "cravings", fn_name: "get_member_count", payload: null });
+    await bob.namedCells.get("lobby")!.callZome({ zome_name: "cravings", fn_name: "get_member_count", payload: null });
+
+    await pause(1200);
+
+    const count: number = await alice.namedCells.get("lobby")!.callZome({
+      zome_name: "cravings",
+      fn_name: "get_member_count",
+      payload: null,
+    });
+    assert.equal(count, 2);
+
+    const members: any[] = await alice.namedCells.get("lobby")!.callZome({
+      zome_name: "cravings",
+      fn_name: "get_lobby_members",
+      payload: null,
+    });
+    assert.equal(members.length, 2);
+    const agents = members.map(member => member.agent.toString());
+    assert.ok(agents.includes(alice.agentPubKey.toString()));
+    assert.ok(agents.includes(bob.agentPubKey.toString()));
+  });
+});
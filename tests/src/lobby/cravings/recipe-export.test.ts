@@ -0,0 +1,79 @@
+import test from 'node:test';
+import assert from 'node:assert';
+
+import { runScenario, pause } from '@holochain/tryorama';
+import { Record, fakeDnaHash } from '@holochain/client';
+import { decode, encode } from '@msgpack/msgpack';
+
+test('share a recipe between lobbies with a signed export', { concurrency: 1 }, async t => {
+  await runScenario(async scenario => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + '/../workdir/word-condenser.happ';
+
+    // Set up the app to be installed 
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    const [alice] = await scenario.addPlayersWithApps([appSource]);
+
+    const cloneLobby = (name: string) => alice.appAgentWs.createCloneCell({
+      role_name: "lobby",
+      modifiers: { network_seed: name, properties: { name } },
+      name,
+    });
+    const origin = await cloneLobby("Origin lobby");
+    const other = await cloneLobby("Other lobby");
+    const third = await cloneLobby("Third lobby");
+    const call = (lobby, fn_name, payload) => alice.appAgentWs.callZome({ cell_id: lobby.cell_id, zome_name: "cravings", fn_name, payload });
+
+    const dnaRecipe = {
+      title: "Portable craving",
+      network_seed: "portable craving",
+      properties: {
+        title: "Portable craving",
+        description: "A craving that travels between lobbies",
+        max_anecdote_chars: null,
+        max_association_chars: 70,
+        max_offer_chars: 300,
+        max_reflection_chars: null,
+      },
+      origin_time: 1674053334548000,
+      membrane_proof: null,
+      resulting_dna_hash: await fakeDnaHash(),
+    };
+    const originRecord: Record = await call(origin, "create_dna_recipe", { dna_recipe: dnaRecipe });
+    assert.equal(await call(origin, "get_recipe_origin", originRecord.signed_action.hashed.hash), null);
+
+    const recipeExport: Uint8Array = await call(origin, "export_recipe", originRecord.signed_action.hashed.hash);
+
+    // A modified export does not match its signature
+    const signedExport: any = decode(recipeExport);
+    signedExport.export.dna_recipe.title = "Tampered craving";
+    await assert.rejects(
+      call(other, "import_recipe", { recipe_export: encode(signedExport) }),
+      /InvalidRecipeExport/,
+    );
+    await assert.rejects(call(origin, "import_recipe", { recipe_export: recipeExport }), /NotAllowed/);
+
+    const imported: Record = await call(other, "import_recipe", { recipe_export: recipeExport, tags: ["portable"] });
+    const importedRecipe: any = decode((imported.entry as any).Present.entry);
+    assert.deepEqual(importedRecipe, dnaRecipe);
+    await pause(1200);
+    await assert.rejects(call(other, "import_recipe", { recipe_export: recipeExport }), /AlreadyExists/);
+
+    const recipeOrigin: any = await call(other, "get_recipe_origin", imported.signed_action.hashed.hash);
+    assert.deepEqual(recipeOrigin.origin_lobby_dna_hash, origin.cell_id[0]);
+    assert.equal(recipeOrigin.origin_lobby_name, "Origin lobby");
+    assert.deepEqual(recipeOrigin.exported_by, alice.agentPubKey);
+
+    // Passing the recipe on keeps its original lobby
+    const secondExport = await call(other, "export_recipe", imported.signed_action.hashed.hash);
+    const reimported: Record = await call(third, "import_recipe", { recipe_export: secondExport });
+    const secondOrigin: any = await call(third, "get_recipe_origin", reimported.signed_action.hashed.hash);
+    assert.deepEqual(secondOrigin.origin_lobby_dna_hash, origin.cell_id[0]);
+    await pause(1200);
+
+    // A recipe that came in from another lobby first isn't imported again
+    await assert.rejects(call(third, "import_recipe", { recipe_export: recipeExport }), /AlreadyExists/);
+  });
+});
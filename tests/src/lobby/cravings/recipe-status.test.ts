@@ -0,0 +1,77 @@
+import test from 'node:test';
+import assert from 'node:assert';
+
+import { runScenario, pause } from '@holochain/tryorama';
+import { Record } from '@holochain/client';
+
+import { createDnaRecipe } from './dna-recipe.test.js';
+
+test('archived craving recipes are left out of all craving recipes', { concurrency: 1 }, async t => {
+  await runScenario(async scenario => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + '/../workdir/word-condenser.happ';
+
+    // Set up the app to be installed 
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    const [alice, bob] = await scenario.addPlayersWithApps([appSource, appSource]);
+    await scenario.shareAllAgents();
+
+    const aliceLobby = alice.namedCells.get("lobby")!;
+    const bobLobby = bob.namedCells.get("lobby")!;
+
+    const record: Record = await createDnaRecipe(aliceLobby);
+    const dnaRecipeHash = record.signed_action.hashed.hash;
+    await pause(1200);
+
+    // Bob is neither the author nor an admin
+    await assert.rejects(
+      bobLobby.callZome({
+        zome_name: "cravings",
+        fn_name: "set_recipe_status",
+        payload: { dna_recipe_hash: dnaRecipeHash, state: "Archived" },
+      }),
+      /NotAuthor/,
+    );
+    // superseded_by only goes with Superseded
+    await assert.rejects(
+      aliceLobby.callZome({
+        zome_name: "cravings",
+        fn_name: "set_recipe_status",
+        payload: { dna_recipe_hash: dnaRecipeHash, state: "Closed", superseded_by: dnaRecipeHash },
+      }),
+      /MalformedOp/,
+    );
+
+    await aliceLobby.callZome({
+      zome_name: "cravings",
+      fn_name: "set_recipe_status",
+      payload: { dna_recipe_hash: dnaRecipeHash, state: "Archived" },
+    });
+    await pause(1200);
+
+    const listed: Record[] = await bobLobby.callZome({
+      zome_name: "cravings",
+      fn_name: "get_all_craving_recipes",
+      payload: ["Active", "Closed"],
+    });
+    assert.equal(listed.length, 0);
+
+    // Without states, archived recipes are included
+    const all: Record[] = await bobLobby.callZome({
+      zome_name: "cravings",
+      fn_name: "get_all_craving_recipes",
+      payload: null,
+    });
+    assert.equal(all.length, 1);
+
+    const archived: Record[] = await bobLobby.callZome({
+      zome_name: "cravings",
+      fn_name: "get_all_craving_recipes",
+      payload: ["Archived"],
+    });
+    assert.equal(archived.length, 1);
+    assert.deepEqual(archived[0].signed_action.hashed.hash, dnaRecipeHash);
+  });
+});
@@ -0,0 +1,50 @@
+import test from 'node:test';
+import assert from 'node:assert';
+
+import { runScenario, pause } from '@holochain/tryorama';
+import { Record } from '@holochain/client';
+import { decode } from '@msgpack/msgpack';
+
+test('create, update, get and search profiles', { concurrency: 1 }, async t => {
+  await runScenario(async scenario => {
+    // Construct proper paths for your app.
+    // This assumes app bundle created by the `hc app pack` command.
+    const testAppPath = process.cwd() + '/../workdir/word-condenser.happ';
+
+    // Set up the app to be installed 
+    const appSource = { appBundleSource: { path: testAppPath } };
+
+    const [alice, bob] = await scenario.addPlayersWithApps([appSource, appSource]);
+    await scenario.shareAllAgents();
+
+    const call = (player, fn_name, payload) => player.namedCells.get("lobby")!.callZome({
+      zome_name: "profiles",
+      fn_name,
+      payload,
+    });
+
+    await call(alice, "create_profile", { nickname: "Alice", fields: {} });
+    await assert.rejects(call(alice, "create_profile", { nickname: "Alice", fields: {} }), /AlreadyExists/);
+    await assert.rejects(call(bob, "create_profile", { nickname: "Bo", fields: {} }), /TooShort/);
+    await assert.rejects(
+      call(bob, "create_profile", { nickname: "Bobby", fields: { avatar: "x".repeat(300001) } }),
+      /TooLong/,
+    );
+    await call(bob, "create_profile", { nickname: "Bobby", fields: {} });
+
+    await call(alice, "update_profile", { nickname: "Alicia", fields: { bio: "hi" } });
+
+    await pause(1200);
+
+    const record: Record = await call(bob, "get_agent_profile", alice.agentPubKey);
+    assert.equal((decode((record.entry as any).Present.entry) as any).nickname, "Alicia");
+
+    const found = await call(bob, "search_agents", "ali");
+    assert.deepEqual(found, [alice.agentPubKey]);
+    assert.deepEqual(await call(bob, "search_agents", "bob"), [bob.agentPubKey]);
+    await assert.rejects(call(bob, "search_agents", "al"), /TooShort/);
+
+    const all: any[] = await call(alice, "get_agents_with_profile", null);
+    assert.equal(all.length, 2);
+  });
+});
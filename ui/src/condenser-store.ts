@@ -9,6 +9,7 @@ import {
 } from '@holochain-open-dev/stores';
 import { decodeEntry, DnaHashMap } from '@holochain-open-dev/utils';
 import {
+  AgentPubKey,
   DnaModifiers,
   AppAgentWebsocket,
   CellId,
@@ -17,7 +18,7 @@ import {
   DnaHash,
   encodeHashToBase64,
 } from '@holochain/client';
-import { decode } from '@msgpack/msgpack';
+import { decode, encode } from '@msgpack/msgpack';
 import md5 from 'md5';
 
 import { CravingService } from './craving-service';
@@ -27,8 +28,8 @@ import { LobbyService } from './lobby-service';
 import { LobbyStore } from './lobby-store';
 import { ProfilesClient } from './lobby/profiles/profiles-client';
 import { ProfilesStore } from './lobby/profiles/profiles-store';
-import { DnaRecipe, LobbyInfo } from './types';
-import { getLocalStorageItem, notifyOS } from './utils';
+import { DnaRecipe, LobbyInfo, LobbyInvitation, LobbyMode } from './types';
+import { getLocalStorageItem, lobbyProperties, notifyOS } from './utils';
 
 export interface CravingData {
   title: string; // in case it is not installed, we have info about the title from the DnaRecipe
@@ -427,6 +428,7 @@ export class CondenserStore {
         properties,
         origin_time: originTime,
       },
+      membrane_proof: await this.cravingMembraneProof(properties),
       name: properties.title,
     };
 
@@ -497,6 +499,7 @@ export class CondenserStore {
         properties: dnaRecipe.properties,
         origin_time: dnaRecipe.origin_time,
       },
+      membrane_proof: await this.cravingMembraneProof(dnaRecipe.properties),
       name: dnaRecipe.title,
     };
 
@@ -525,9 +528,66 @@ export class CondenserStore {
 
     this.reSubscribeToPolling();
 
+    await this.updateJoinedCraving(cellId[0], true);
+
     return cellInfo;
   }
 
+  /**
+   * Lets the lobbies that list the craving know whether we have it installed, so that
+   * they can count its participants. Lobbies that don't list it are skipped.
+   *
+   * @param cravingDnaHash
+   * @param joined
+   */
+  async updateJoinedCraving(cravingDnaHash: DnaHash, joined: boolean) {
+    await Promise.all(
+      Array.from(get(this._lobbies).values()).map(async ([lobbyStore]) => {
+        try {
+          if (joined) {
+            await lobbyStore.service.joinCraving(cravingDnaHash);
+          } else {
+            await lobbyStore.service.leaveCraving(cravingDnaHash);
+          }
+        } catch (e) {
+          // the lobby does not list this craving
+        }
+      }),
+    );
+  }
+
+  /**
+   * Cravings of invitation-only lobbies are joined with the same invitation proof
+   * as the lobby itself
+   *
+   * @param properties
+   * @returns
+   */
+  async cravingMembraneProof(
+    properties: CravingDnaProperties,
+  ): Promise<Uint8Array | undefined> {
+    if (!properties.lobby_dna_hash || !properties.lobby_creator) return undefined;
+    const lobby = this.lobbyStore(properties.lobby_dna_hash);
+    if (!lobby) {
+      throw new Error('This craving is only open to members of a lobby you have not joined.');
+    }
+    const [lobbyStore, _profilesStore] = lobby;
+    return encode(await lobbyStore.service.getMyInvitationProof());
+  }
+
+  /**
+   * Returns the creator of the lobby if it is invitation-only
+   *
+   * @param dnaHash
+   * @returns
+   */
+  invitationOnlyLobbyCreator(dnaHash: DnaHash): AgentPubKey | undefined {
+    const lobby = this.lobbyStore(dnaHash);
+    if (!lobby) return undefined;
+    const properties = decode(lobby[2].properties) as any;
+    return properties.invitation_only ? properties.creator : undefined;
+  }
+
   /**
    * Disables the craving
    *
@@ -539,6 +599,8 @@ export class CondenserStore {
       clone_cell_id: cellId,
     });
 
+    await this.updateJoinedCraving(cellId[0], false);
+
     alert(
       `Disabled Craving. To delete it permanently, delete the corresponding cloned cell with the DNA hash\n\n"${encodeHashToBase64(
         cellId[0],
@@ -557,6 +619,8 @@ export class CondenserStore {
       clone_cell_id: cellId,
     });
 
+    await this.updateJoinedCraving(cellId[0], true);
+
     alert(`Enabled Craving.`);
   }
 
@@ -703,15 +767,15 @@ export class CondenserStore {
     description: string,
     unenforcedRules: string | undefined,
     logoSrc: string,
+    mode: LobbyMode = {},
   ): Promise<CellId> {
     const cellInfo = await this.appAgentWebsocket.createCloneCell({
       role_name: 'lobby',
       modifiers: {
         network_seed: networkSeed,
-        properties: {
-          name,
-          // creator: encodeHashToBase64(this.appAgentWebsocket.myPubKey), // not neeted in Word Condenser 0.1.X to reduce invitation friction
-        }, // lobby name will be fixed and part of the properties
+        // lobby name will be fixed and part of the properties. The creator is only
+        // added for the opt-in modes to reduce invitation friction otherwise
+        properties: lobbyProperties(name, this.appAgentWebsocket.myPubKey, mode),
       },
       name,
     });
@@ -773,7 +837,16 @@ export class CondenserStore {
     alert(`Enabled Group.`);
   }
 
-  async joinLobby(networkSeed: string, name: string): Promise<CellId> {
+  /**
+   * Joins a lobby. Lobbies in one of the opt-in modes additionally need the creator
+   * and, if invitation-only, the invitation that was minted for us by a member with
+   * `create_invitation`.
+   */
+  async joinLobby(
+    networkSeed: string,
+    name: string,
+    invitation?: LobbyInvitation,
+  ): Promise<CellId> {
     // Check that the same lobby does not already exist
     const existingLobbies = Array.from(get(this._lobbies).values());
 
@@ -793,11 +866,12 @@ export class CondenserStore {
       role_name: 'lobby',
       modifiers: {
         network_seed: networkSeed,
-        properties: {
-          name,
-          // creator: encodeHashToBase64(this.appAgentWebsocket.myPubKey), // not needed in Word Condenser 0.1.X to reduce invitation friction
-        }, // lobby name will be fixed and part of the properties
+        // lobby name will be fixed and part of the properties
+        properties: invitation
+          ? lobbyProperties(name, invitation.creator, invitation.mode)
+          : { name },
       },
+      membrane_proof: invitation?.proof ? encode(invitation.proof) : undefined,
       name,
     });
 
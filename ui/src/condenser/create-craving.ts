@@ -105,6 +105,16 @@ export class CreateCraving extends LitElement {
       max_reflection_chars: this._max_reflection_chars,
     };
 
+    // cravings created in a single invitation-only lobby are private to that lobby
+    if (this._selectedLobbies.length === 1) {
+      const lobbyDnaHash = decodeHashFromBase64(this._selectedLobbies[0]);
+      const lobbyCreator = this.store.invitationOnlyLobbyCreator(lobbyDnaHash);
+      if (lobbyCreator) {
+        cravingDnaProperties.lobby_dna_hash = lobbyDnaHash;
+        cravingDnaProperties.lobby_creator = lobbyCreator;
+      }
+    }
+
     // console.log("@create-craving: cravingDnaProperties: ", cravingDnaProperties);
 
     try {
@@ -1,6 +1,8 @@
 import {
   Record,
   ActionHash,
+  AgentPubKey,
+  DnaHash,
   SignedActionHashed,
   Create,
   Delete,
@@ -17,6 +19,9 @@ export interface CravingDnaProperties {
   max_association_chars: number | null;
   max_offer_chars: number | null;
   max_reflection_chars: number | null;
+  /** Only set for cravings of invitation-only lobbies, must come last to keep DNA hashes */
+  lobby_dna_hash?: DnaHash;
+  lobby_creator?: AgentPubKey;
 }
 
 export interface Offer {
@@ -65,6 +70,28 @@ export interface UpdateCommentOnReflectionInput {
   updated_comment_on_reflection: CommentOnReflection;
 }
 
+export interface AuthorProfile {
+  nickname: string;
+  avatar: string | undefined;
+}
+
+export interface RecordWithAuthor {
+  record: Record;
+  author_profile: AuthorProfile | undefined;
+}
+
+export interface LobbyRegistration {
+  lobby_dna_hash: DnaHash;
+  lobby_name: string;
+  network_seed: string;
+  /** msgpack encoded DNA properties of the lobby */
+  properties: Uint8Array;
+}
+
+export interface RegisteredLobby {
+  registration: LobbyRegistration;
+}
+
 export type CravingSignal =
   | {
       type: 'EntryCreated';
@@ -94,6 +121,42 @@ export type CravingSignal =
       type: 'LinkDeleted';
       action: SignedActionHashed<DeleteLink>;
       link_type: Object;
+    }
+  | {
+      type: 'Remote';
+      provenance: AgentPubKey;
+      signal: CravingRemoteSignal;
+    };
+
+// Compact signals sent by the other participants of a craving
+export type CravingRemoteSignal =
+  | {
+      type: 'EntryCreated';
+      action: SignedActionHashed<Create>;
+      app_entry: EntryTypes;
+    }
+  | {
+      type: 'EntryUpdated';
+      action: SignedActionHashed<Update>;
+      app_entry: EntryTypes;
+    }
+  | {
+      type: 'EntryDeleted';
+      action: SignedActionHashed<Delete>;
+    }
+  | {
+      type: 'LinkCreated';
+      action: SignedActionHashed<CreateLink>;
+      link_type: Object;
+    }
+  | {
+      type: 'LinkDeleted';
+      action: SignedActionHashed<DeleteLink>;
+      link_type: Object;
+    }
+  | {
+      type: 'Presence';
+      expires_at: number;
     };
 
 export type EntryTypes =
@@ -102,4 +165,5 @@ export type EntryTypes =
   | ({ type: 'Association' } & Association)
   | ({ type: 'Anecdote' } & Anecdote)
   | ({ type: 'CommentOnOffer' } & CommentOnOffer)
-  | ({ type: 'CommentOnReflection' } & CommentOnReflection);
+  | ({ type: 'CommentOnReflection' } & CommentOnReflection)
+  | ({ type: 'RegisteredLobby' } & RegisteredLobby);
@@ -6,6 +6,7 @@ import {
   AppAgentClient,
   CellId,
   CellType,
+  DnaHash,
   EntryHash,
   Record,
   ClonedCell,
@@ -24,6 +25,8 @@ import {
   UpdateReflectionInput,
   CravingSignal,
   CravingDnaProperties,
+  LobbyRegistration,
+  RecordWithAuthor,
 } from './condenser/types';
 
 export interface CravingEvents {
@@ -79,6 +82,20 @@ export class CravingService {
     return craving;
   }
 
+  /**
+   * Sends a presence heartbeat to the other participants, valid for intervalSecs seconds
+   */
+  async pingPresence(intervalSecs?: number): Promise<void> {
+    return this.callZome('ping_presence', intervalSecs ?? null);
+  }
+
+  /**
+   * The participants whose craving cell currently responds, ourselves included
+   */
+  async getOnlineAgents(intervalSecs?: number): Promise<AgentPubKey[]> {
+    return this.callZome('get_online_agents', intervalSecs ?? null);
+  }
+
   async getInitTime(): Promise<number> {
     const timestamp_microseconds: number = await this.callZome(
       'get_init_time',
@@ -291,6 +308,63 @@ export class CravingService {
     return this.callZome('get_resonators_for_action', actionHash);
   }
 
+  async getAllReflectionsWithAuthors(): Promise<RecordWithAuthor[]> {
+    return this.callZome('get_all_reflections_with_authors', null);
+  }
+
+  async getAllOffersWithAuthors(): Promise<RecordWithAuthor[]> {
+    return this.callZome('get_all_offers_with_authors', null);
+  }
+
+  async getAllCommentsOnOfferWithAuthors(
+    originalOfferHash: ActionHash,
+  ): Promise<RecordWithAuthor[]> {
+    return this.callZome(
+      'get_comment_on_offers_for_offer_with_authors',
+      originalOfferHash,
+    );
+  }
+
+  async getAllCommentsOnReflectionWithAuthors(
+    originalReflectionHash: ActionHash,
+  ): Promise<RecordWithAuthor[]> {
+    return this.callZome(
+      'get_comment_on_reflections_for_reflection_with_authors',
+      originalReflectionHash,
+    );
+  }
+
+  /**
+   * Gets the lobbies this craving is listed in, with what is needed to join them
+   */
+  async getRegisteredLobbies(): Promise<LobbyRegistration[]> {
+    return this.callZome('get_registered_lobbies', null);
+  }
+
+  /**
+   * Publishes the word of an offer to the glossary of a lobby, by default the lobby the
+   * craving was created in
+   */
+  async publishOfferToGlossary(
+    offerHash: ActionHash,
+    definition: string,
+    lobbyDnaHash?: DnaHash,
+  ): Promise<Record> {
+    return this.callZome('publish_offer_to_glossary', {
+      offer_hash: offerHash,
+      definition,
+      lobby_dna_hash: lobbyDnaHash,
+    });
+  }
+
+  /**
+   * Publishes a preview of this craving to the lobbies it is listed in right away instead
+   * of waiting for the hourly schedule
+   */
+  async publishCravingPreviewNow(): Promise<void> {
+    return this.callZome('publish_craving_preview_now', null);
+  }
+
   private callZome(fn_name: string, payload: any) {
     const req: AppAgentCallZomeRequest = {
       cell_id: this.cellId,
@@ -1,5 +1,10 @@
-import { AsyncReadable, lazyLoadAndPoll } from '@holochain-open-dev/stores';
-import { LazyHoloHashMap } from '@holochain-open-dev/utils';
+import {
+  AsyncReadable,
+  lazyLoadAndPoll,
+  readable,
+  Readable,
+} from '@holochain-open-dev/stores';
+import { AgentPubKeyMap, LazyHoloHashMap } from '@holochain-open-dev/utils';
 import {
   ActionHash,
   AgentPubKey,
@@ -45,6 +50,11 @@ export interface OfferData {
   timestamp: number;
 }
 
+/**
+ * Seconds after which a presence heartbeat expires, we send ours twice per interval
+ */
+export const PRESENCE_INTERVAL_SECS = 30;
+
 export class CravingStore {
   // public networkSeed: string;
 
@@ -489,4 +499,42 @@ export class CravingStore {
       }),
     );
   }, 1000);
+
+  /**
+   * The other participants that are currently looking at this craving, tracked from the
+   * presence heartbeats they send us. While subscribed, we send our own heartbeats.
+   * A heartbeat counts for at most one interval after we received it, so that clocks
+   * running ahead don't keep agents online forever.
+   */
+  onlineAgents: Readable<AgentPubKey[]> = readable<AgentPubKey[]>([], set => {
+    const expiries = new AgentPubKeyMap<number>();
+    const update = () => {
+      const now = Date.now();
+      expiries.keys().forEach(agent => {
+        if (expiries.get(agent)! <= now) expiries.delete(agent);
+      });
+      set(expiries.keys());
+    };
+    const unsubscribe = this.service.on('signal', signal => {
+      if (signal.type !== 'Remote' || signal.signal.type !== 'Presence') return;
+      const expiresAt = Math.min(
+        signal.signal.expires_at / 1000,
+        Date.now() + PRESENCE_INTERVAL_SECS * 1000,
+      );
+      expiries.set(signal.provenance, expiresAt);
+      update();
+    });
+    const ping = () => {
+      this.service.pingPresence(PRESENCE_INTERVAL_SECS).catch(e => {
+        console.warn('Could not send presence heartbeat: ', e);
+      });
+      update();
+    };
+    ping();
+    const interval = setInterval(ping, (PRESENCE_INTERVAL_SECS * 1000) / 2);
+    return () => {
+      clearInterval(interval);
+      unsubscribe();
+    };
+  });
 }
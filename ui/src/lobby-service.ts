@@ -1,11 +1,31 @@
 import {
+  ActionHash,
   AppAgentCallZomeRequest,
+  AgentPubKey,
   AppAgentClient,
   CellId,
+  DnaHash,
   Record,
 } from '@holochain/client';
 import { UnsubscribeFunction } from 'emittery';
-import { DnaRecipe, LobbyName, LobbySignal } from './types';
+import {
+  Announcement,
+  CravingProposal,
+  CravingProposalWithResonance,
+  CravingRecipesPage,
+  CravingRecipeWithPreview,
+  DnaRecipe,
+  ExportedGlossaryEntry,
+  InvitationProof,
+  LobbyInfoConflict,
+  LobbyInfoRevision,
+  LobbyMember,
+  LobbyName,
+  LobbySignal,
+  PageInput,
+  RecipeOrigin,
+  RecipeState,
+} from './types';
 
 export interface LobbyEvents {
   ['signal']: LobbySignal;
@@ -33,14 +53,15 @@ export class LobbyService {
   }
 
   /**
-   * Gets the Records of all associations (deduplicated)
+   * Gets the Records of all craving recipes (deduplicated)
    *
+   * @param states only recipes in these states, all recipes by default
    * @returns
    */
-  async getAllCravingRecipes(): Promise<Array<Record>> {
+  async getAllCravingRecipes(states?: RecipeState[]): Promise<Array<Record>> {
     const recipes: Array<Record> = await this.callZome(
       'get_all_craving_recipes',
-      null,
+      states ?? null,
     );
 
     return recipes;
@@ -77,10 +98,202 @@ export class LobbyService {
     return lobbyName;
   }
 
-  async registerCraving(dnaRecipe: DnaRecipe): Promise<Record> {
+  async registerCraving(dnaRecipe: DnaRecipe, tags: string[] = []): Promise<Record> {
     // console.log(`@lobbyService: registering craving with dnaRecipe: ${JSON.stringify(dnaRecipe)}}`);
     // console.log(`@lobbyService: regisetring craving with dna hash: ${encodeHashToBase64(dnaRecipe.resulting_dna_hash)}`)
-    return this.callZome('create_dna_recipe', dnaRecipe);
+    return this.callZome('create_dna_recipe', { dna_recipe: dnaRecipe, tags });
+  }
+
+  /**
+   * Signed and serialized recipe that can be imported into another lobby
+   */
+  async exportRecipe(dnaRecipeHash: ActionHash): Promise<Uint8Array> {
+    return this.callZome('export_recipe', dnaRecipeHash);
+  }
+
+  async importRecipe(recipeExport: Uint8Array, tags: string[] = []): Promise<Record> {
+    return this.callZome('import_recipe', { recipe_export: recipeExport, tags });
+  }
+
+  async getRecipeOrigin(dnaRecipeHash: ActionHash): Promise<RecipeOrigin | undefined> {
+    return this.callZome('get_recipe_origin', dnaRecipeHash);
+  }
+
+  async getAllCravingTags(): Promise<string[]> {
+    return this.callZome('get_all_craving_tags', null);
+  }
+
+  async getCravingRecipesByTag(
+    tag: string,
+    page: PageInput = {}
+  ): Promise<CravingRecipesPage> {
+    return this.callZome('get_craving_recipes_by_tag', { tag, page });
+  }
+
+  async searchCravingRecipes(
+    query: string,
+    tag?: string,
+    page: PageInput = {}
+  ): Promise<CravingRecipesPage> {
+    return this.callZome('search_craving_recipes', { query, tag, page });
+  }
+
+  async createInvitation(invitee: AgentPubKey): Promise<InvitationProof> {
+    return this.callZome('create_invitation', invitee);
+  }
+
+  async getMyInvitationProof(): Promise<InvitationProof> {
+    return this.callZome('get_my_invitation_proof', null);
+  }
+
+  async getLobbyInfoHistory(): Promise<LobbyInfoRevision[]> {
+    return this.callZome('get_lobby_info_history', null);
+  }
+
+  async getLobbyInfoConflicts(): Promise<LobbyInfoConflict[]> {
+    return this.callZome('get_lobby_info_conflicts', null);
+  }
+
+  async setRecipeStatus(
+    dnaRecipeHash: ActionHash,
+    state: RecipeState,
+    supersededBy?: ActionHash,
+  ): Promise<Record> {
+    return this.callZome('set_recipe_status', {
+      dna_recipe_hash: dnaRecipeHash,
+      state,
+      superseded_by: supersededBy,
+    });
+  }
+
+  async getRecipeStatus(dnaRecipeHash: ActionHash): Promise<Record | undefined> {
+    return this.callZome('get_recipe_status', dnaRecipeHash);
+  }
+
+  async createAnnouncement(announcement: Announcement): Promise<Record> {
+    return this.callZome('create_announcement', announcement);
+  }
+
+  async getAllAnnouncements(): Promise<Record[]> {
+    return this.callZome('get_all_announcements', null);
+  }
+
+  async updateAnnouncement(
+    originalAnnouncementHash: ActionHash,
+    previousAnnouncementHash: ActionHash,
+    updatedAnnouncement: Announcement,
+  ): Promise<Record> {
+    return this.callZome('update_announcement', {
+      original_announcement_hash: originalAnnouncementHash,
+      previous_announcement_hash: previousAnnouncementHash,
+      updated_announcement: updatedAnnouncement,
+    });
+  }
+
+  async deleteAnnouncement(originalAnnouncementHash: ActionHash): Promise<ActionHash> {
+    return this.callZome('delete_announcement', originalAnnouncementHash);
+  }
+
+  async createCravingProposal(cravingProposal: CravingProposal): Promise<Record> {
+    return this.callZome('create_craving_proposal', cravingProposal);
+  }
+
+  async getAllCravingProposals(): Promise<CravingProposalWithResonance[]> {
+    return this.callZome('get_all_craving_proposals', null);
+  }
+
+  async resonateWithCravingProposal(cravingProposalHash: ActionHash): Promise<void> {
+    return this.callZome('resonate_with_craving_proposal', cravingProposalHash);
+  }
+
+  async unresonateWithCravingProposal(cravingProposalHash: ActionHash): Promise<void> {
+    return this.callZome('unresonate_with_craving_proposal', cravingProposalHash);
+  }
+
+  async promoteCravingProposal(
+    cravingProposalHash: ActionHash,
+    dnaRecipe: DnaRecipe,
+    tags: string[] = [],
+  ): Promise<Record> {
+    return this.callZome('promote_craving_proposal', {
+      craving_proposal_hash: cravingProposalHash,
+      dna_recipe: dnaRecipe,
+      tags,
+    });
+  }
+
+  async getCravingPreview(dnaRecipeHash: ActionHash): Promise<Record | undefined> {
+    return this.callZome('get_craving_preview', dnaRecipeHash);
+  }
+
+  async getAllCravingRecipesWithPreviews(
+    states?: RecipeState[],
+  ): Promise<CravingRecipeWithPreview[]> {
+    return this.callZome('get_all_craving_recipes_with_previews', states ?? null);
+  }
+
+  async joinCraving(cravingDnaHash: DnaHash): Promise<void> {
+    return this.callZome('join_craving', cravingDnaHash);
+  }
+
+  async leaveCraving(cravingDnaHash: DnaHash): Promise<void> {
+    return this.callZome('leave_craving', cravingDnaHash);
+  }
+
+  async getRecipeJoiners(dnaRecipeHash: ActionHash): Promise<AgentPubKey[]> {
+    return this.callZome('get_recipe_joiners', dnaRecipeHash);
+  }
+
+  async getRecipeJoinCount(dnaRecipeHash: ActionHash): Promise<number> {
+    return this.callZome('get_recipe_join_count', dnaRecipeHash);
+  }
+
+  async getMyJoinedRecipes(): Promise<Record[]> {
+    return this.callZome('get_my_joined_recipes', null);
+  }
+
+  async getGlossary(): Promise<Record[]> {
+    return this.callZome('get_glossary', null);
+  }
+
+  async searchGlossary(query: string): Promise<Record[]> {
+    return this.callZome('search_glossary', query);
+  }
+
+  async getGlossaryForDnaRecipe(dnaRecipeHash: ActionHash): Promise<Record[]> {
+    return this.callZome('get_glossary_for_dna_recipe', dnaRecipeHash);
+  }
+
+  async exportGlossary(): Promise<ExportedGlossaryEntry[]> {
+    return this.callZome('export_glossary', null);
+  }
+
+  async deleteGlossaryEntry(glossaryEntryHash: ActionHash): Promise<ActionHash> {
+    return this.callZome('delete_glossary_entry', glossaryEntryHash);
+  }
+
+  async getLobbyAdmins(): Promise<AgentPubKey[]> {
+    return this.callZome('get_lobby_admins', null);
+  }
+
+  async grantLobbyAdmin(agent: AgentPubKey): Promise<Record> {
+    return this.callZome('grant_lobby_admin', agent);
+  }
+
+  async stepDownAsLobbyAdmin(): Promise<void> {
+    return this.callZome('step_down_as_lobby_admin', null);
+  }
+
+  async revokeLobbyAdmin(agent: AgentPubKey): Promise<void> {
+    return this.callZome('revoke_lobby_admin', agent);
+  }
+
+  async getLobbyMembers(): Promise<LobbyMember[]> {
+    return this.callZome('get_lobby_members', null);
+  }
+
+  async getMemberCount(): Promise<number> {
+    return this.callZome('get_member_count', null);
   }
 
   private callZome(fn_name: string, payload: any) {
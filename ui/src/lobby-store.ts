@@ -33,7 +33,7 @@ export class LobbyStore {
 
   // create instead a data structure here that also contains the info about resonances and iResonated
   allCravingRecipes = asyncReadable<Array<Record>>(async set => {
-    const cravingRecipes = await this.service.getAllCravingRecipes();
+    const cravingRecipes = await this.service.getAllCravingRecipes(['Active', 'Closed']);
 
     set(cravingRecipes);
 
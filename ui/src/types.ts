@@ -1,5 +1,6 @@
 /* eslint-disable no-shadow */
 import {
+  AgentPubKey,
   Create,
   DnaHash,
   SignedActionHashed,
@@ -7,6 +8,7 @@ import {
   MembraneProof,
   DnaHashB64,
   ActionHashB64,
+  ActionHash,
 } from '@holochain/client';
 import { CravingDnaProperties } from './condenser/types';
 
@@ -31,25 +33,187 @@ export interface DnaRecipe {
   resulting_dna_hash: DnaHash;
 }
 
+export interface LobbyInfoRevision {
+  record: HolochainRecord;
+  previous_lobby_info_hash: ActionHash | undefined;
+}
+
+export interface LobbyInfoConflict {
+  previous_lobby_info_hash: ActionHash;
+  branches: HolochainRecord[];
+}
+
+export interface LobbyMember {
+  agent: AgentPubKey;
+  profile: HolochainRecord | undefined;
+  joined_at: number;
+}
+
+export type RecipeState = 'Active' | 'Closed' | 'Archived' | 'Superseded';
+
+export interface RecipeStatus {
+  dna_recipe_hash: ActionHash;
+  state: RecipeState;
+  superseded_by: ActionHash | undefined;
+  admin_role_hash: ActionHash | undefined;
+}
+
+export type RecipeSort = 'Newest' | 'Oldest' | 'MostJoined';
+
+export interface PageInput {
+  sort?: RecipeSort;
+  offset?: number;
+  limit?: number;
+}
+
+export interface CravingRecipesPage {
+  records: HolochainRecord[];
+  total: number;
+}
+
+export interface Invitation {
+  dna_hash: DnaHash;
+  inviter: AgentPubKey;
+  invitee: AgentPubKey;
+  created_at: number;
+}
+
+export interface InvitationProof {
+  chain: Array<{ invitation: Invitation; signature: Uint8Array }>;
+}
+
+/**
+ * Opt-in modes of a lobby, part of its DNA properties
+ */
+export interface LobbyMode {
+  invitationOnly?: boolean;
+  adminMode?: boolean;
+}
+
+/**
+ * Everything needed to join a lobby in one of the opt-in modes
+ */
+export interface LobbyInvitation {
+  creator: AgentPubKey;
+  mode: LobbyMode;
+  /** Required for invitation-only lobbies */
+  proof?: InvitationProof;
+}
+
 export interface LobbyInfo {
   description: string;
   unenforced_rules: string;
   logo_src: string;
   network_seed: string;
+  /** Set by the zome in lobbies in admin mode */
+  admin_role_hash?: ActionHash;
 }
 
 export type LobbyName = string;
 
-export type LobbySignal = {
-  type: 'EntryCreated';
-  action: SignedActionHashed<Create>;
+export interface Announcement {
+  title: string;
+  body: string;
+  dna_recipe_hash: ActionHash | undefined;
+  expires_at: number | undefined;
+}
+
+export interface CravingProposal {
+  title: string;
+  description: string;
+  max_anecdote_chars: number | undefined;
+  max_association_chars: number | undefined;
+  max_offer_chars: number | undefined;
+  max_reflection_chars: number | undefined;
+}
+
+export interface CravingProposalWithResonance {
+  record: HolochainRecord;
+  resonators: AgentPubKey[];
+  /** The DnaRecipe the proposal has been promoted to, if any */
+  dna_recipe_hash: ActionHash | undefined;
+}
+
+export interface GlossaryEntry {
+  word: string;
+  definition: string;
+  dna_recipe_hash: ActionHash;
+  offer_hash: ActionHash;
+}
+
+export interface ExportedGlossaryEntry {
+  word: string;
+  definition: string;
+  craving_title: string;
+  craving_dna_hash: DnaHash;
+  offer_hash: ActionHash;
+  author: AgentPubKey;
+  published_at: number;
+}
+
+export interface PreviewAssociation {
+  association: string;
+  resonators: number;
+}
+
+export interface CravingPreview {
+  dna_recipe_hash: ActionHash;
+  top_associations: PreviewAssociation[];
+  association_count: number;
+  offer_count: number;
+  reflection_count: number;
+  anecdote_count: number;
+  participant_count: number;
+  last_activity: number | undefined;
+}
+
+export interface CravingRecipeWithPreview {
+  record: HolochainRecord;
+  /** Undefined if no participant of the craving has published a preview yet. The counts are
+   * self-reported by the author of the preview, not verified. */
+  preview: HolochainRecord | undefined;
+}
+
+/**
+ * Where a recipe that was imported from another lobby comes from, as claimed by
+ * `exported_by`. The origin lobby can't be verified from this lobby.
+ */
+export interface RecipeOrigin {
+  dna_recipe_hash: ActionHash;
+  version: number;
+  origin_lobby_dna_hash: DnaHash;
+  origin_lobby_name: string;
+  exported_at: number;
+  exported_by: AgentPubKey;
+  signature: Uint8Array;
+}
+
+export type LobbyRemoteSignal = {
+  type: 'AnnouncementPosted';
   record: HolochainRecord;
-  app_entry: EntryTypes;
 };
 
+export type LobbySignal =
+  | {
+      type: 'EntryCreated';
+      action: SignedActionHashed<Create>;
+      record: HolochainRecord;
+      app_entry: EntryTypes;
+    }
+  | {
+      type: 'Remote';
+      provenance: AgentPubKey;
+      signal: LobbyRemoteSignal;
+    };
+
 export type EntryTypes =
   | ({ type: 'DnaRecipe' } & DnaRecipe)
-  | ({ type: 'LobbyInfo' } & LobbyInfo);
+  | ({ type: 'LobbyInfo' } & LobbyInfo)
+  | ({ type: 'Announcement' } & Announcement)
+  | ({ type: 'CravingProposal' } & CravingProposal)
+  | ({ type: 'GlossaryEntry' } & GlossaryEntry)
+  | ({ type: 'CravingPreview' } & CravingPreview)
+  | ({ type: 'RecipeOrigin' } & RecipeOrigin);
 
 export interface NotificationPayload {
   title: string;
@@ -117,3 +281,26 @@ export type CravingMessageStore = {
     }
   >;
 };
+
+/**
+ * Errors returned by the craving and lobby zomes, see crates/condenser_common
+ */
+export type CondenserError =
+  | { code: 'TooLong'; field: string; max: number; actual: number }
+  | { code: 'TooShort'; field: string; min: number; actual: number }
+  | { code: 'OutOfRange'; field: string; min: number; max: number; actual: number }
+  | { code: 'NotAuthor'; what: string }
+  | { code: 'AlreadyExists'; what: string }
+  | { code: 'Outdated'; what: string; latest: string }
+  | { code: 'NotFound'; what: string }
+  | { code: 'NotUpdatable'; what: string }
+  | { code: 'NotDeletable'; what: string }
+  | { code: 'MissingEntry' }
+  | { code: 'WrongType'; expected: string }
+  | { code: 'InvalidLinkTag'; expected: string }
+  | { code: 'InvalidDnaProperties'; reason: string }
+  | { code: 'InvalidMembraneProof'; reason: string }
+  | { code: 'InvalidRecipeExport'; reason: string }
+  | { code: 'NotAllowed'; reason: string }
+  | { code: 'MalformedOp'; reason: string }
+  | { code: 'Unexpected'; reason: string };
@@ -14,6 +14,8 @@ import {
   Config,
 } from 'unique-names-generator';
 import {
+  CondenserError,
+  LobbyMode,
   CravingMessageStore,
   CravingNotificationSettings,
   NotificationPayload,
@@ -379,3 +381,37 @@ export async function notifyOS(
   );
   return invoke('notify_os', { notification, os, systray });
 }
+
+/**
+ * Extracts the CondenserError from an error thrown by a zome call, if there is one.
+ * Both guest errors and failed validations carry the JSON encoded error somewhere in
+ * their (possibly escaped) message.
+ */
+export function parseCondenserError(e: any): CondenserError | undefined {
+  const message: string =
+    typeof e === 'string' ? e : e?.data?.data ?? e?.data ?? e?.message ?? '';
+  const unescaped = String(message).replace(/\\"/g, '"');
+  const match = unescaped.match(/\{"code":"[A-Za-z]+"[^{}]*\}/);
+  if (!match) return undefined;
+  try {
+    return JSON.parse(match[0]);
+  } catch (_) {
+    return undefined;
+  }
+}
+
+/**
+ * DNA properties of a lobby. The order of the attributes matters to get the same
+ * DNA hash, and lobbies without opt-in modes only have a name.
+ */
+export function lobbyProperties(
+  name: string,
+  creator: AgentPubKey,
+  mode: LobbyMode,
+) {
+  if (!mode.invitationOnly && !mode.adminMode) return { name };
+  const properties: any = { name, creator };
+  if (mode.invitationOnly) properties.invitation_only = true;
+  if (mode.adminMode) properties.admin_mode = true;
+  return properties;
+}